@@ -0,0 +1,97 @@
+#ifndef SIMILAR_STRING_H
+#define SIMILAR_STRING_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Opaque handle of a [`Corpus`]
+typedef struct SsCorpus SsCorpus;
+
+// Option that matched the target string
+typedef struct SsMatch {
+  // Position of the option amongst the options, or its id in a corpus
+  size_t index;
+  // Similarity rating of the option
+  double score;
+} SsMatch;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Get score of similarity of two strings, see [`compare_similarity`](crate::compare_similarity)
+//
+// Returns -1.0 if any of the strings is null or not valid UTF-8.
+// # Safety
+// The strings have to be null or point to NUL-terminated strings.
+double ss_compare_similarity(const char *left, const char *right);
+
+// Get the Levenshtein edit distance of two strings, see [`levenshtein_distance`](crate::levenshtein_distance)
+//
+// Returns -1 if any of the strings is null or not valid UTF-8.
+// # Safety
+// The strings have to be null or point to NUL-terminated strings.
+ptrdiff_t ss_levenshtein_distance(const char *left,
+                                  const char *right);
+
+// Find the option that is the most similar to the target string, see [`find_best_match`](crate::find_best_match)
+//
+// Writes the match to `out` and returns `true`, or returns `false` if there are no options
+// or any of the strings is null or not valid UTF-8.
+// # Safety
+// `options` has to point to `len` strings, all the strings have to be null or NUL-terminated
+// and `out` has to be null or point to a writable [`SsMatch`].
+bool ss_find_best_match(const char *target,
+                        const char *const *options,
+                        size_t len,
+                        struct SsMatch *out);
+
+// Create a corpus of the options, see [`Corpus::new`]
+//
+// Returns null if any of the strings is null or not valid UTF-8.
+// The corpus has to be destroyed with [`ss_corpus_free`].
+// # Safety
+// `options` has to point to `len` strings that are null or NUL-terminated.
+struct SsCorpus *ss_corpus_new(const char *const *options, size_t len);
+
+// Destroy a corpus created with [`ss_corpus_new`], null is ignored
+// # Safety
+// The corpus has to be null or created with [`ss_corpus_new`] and not destroyed yet.
+void ss_corpus_free(struct SsCorpus *corpus);
+
+// Get the number of options of the corpus, zero for null
+// # Safety
+// The corpus has to be null or a live corpus.
+size_t ss_corpus_len(const struct SsCorpus *corpus);
+
+// Add an option to the corpus and get its id, see [`Corpus::insert`]
+//
+// Returns -1 if the corpus or the option is null or the option is not valid UTF-8.
+// # Safety
+// The corpus has to be null or a live corpus and the option null or NUL-terminated.
+ptrdiff_t ss_corpus_insert(struct SsCorpus *corpus, const char *option);
+
+// Remove the option with the id from the corpus, see [`Corpus::remove`]
+//
+// Returns `false` if there is no such option.
+// # Safety
+// The corpus has to be null or a live corpus.
+bool ss_corpus_remove(struct SsCorpus *corpus, size_t id);
+
+// Find the option of the corpus that is the most similar to the query, see [`Corpus::best_match`]
+//
+// Writes the match with the id of the option to `out` and returns `true`, or returns `false`
+// if the corpus is empty or any of the pointers is null.
+// # Safety
+// The corpus has to be null or a live corpus, the query null or NUL-terminated and `out`
+// null or pointing to a writable [`SsMatch`].
+bool ss_corpus_best_match(const struct SsCorpus *corpus, const char *query, struct SsMatch *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SIMILAR_STRING_H */
@@ -0,0 +1,98 @@
+//! Derive macros of the `similar-string` crate
+//!
+//! The macros are re-exported by `similar-string` with its `derive` feature, use them from there.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, LitStr, Path};
+
+/// Derive `FromStr` and `FuzzyEnum` for an enum of unit variants
+///
+/// See `similar_string::FuzzyEnum` for the attributes and an example.
+#[proc_macro_derive(FuzzyFromStr, attributes(fuzzy))]
+pub fn derive_fuzzy_from_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    fuzzy_from_str(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn fuzzy_from_str(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "FuzzyFromStr can only be derived for enums"));
+    };
+
+    // Options of the enum
+    let mut min_score: Option<Expr> = None;
+    let mut warn: Option<Path> = None;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("fuzzy")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("min_score") {
+                min_score = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("warn") {
+                warn = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `min_score` or `warn`"))
+            }
+        })?;
+    }
+
+    // Names of the variants
+    let mut names = vec![];
+    let mut variants = vec![];
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(variant, "FuzzyFromStr only supports unit variants"));
+        }
+        let mut name = variant.ident.to_string();
+        for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("fuzzy")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `rename`"))
+                }
+            })?;
+        }
+        names.push(name);
+        variants.push(&variant.ident);
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let indices = 0..variants.len();
+    let min_score = match min_score {
+        Some(min_score) => quote!(const MIN_SCORE: f64 = #min_score;),
+        None => quote!()
+    };
+    let warn = match warn {
+        Some(warn) => quote!(#warn(&warning)),
+        None => quote!(::std::eprintln!("warning: {}", warning))
+    };
+    Ok(quote! {
+        impl #impl_generics ::similar_string::FuzzyEnum for #ident #type_generics #where_clause {
+            const NAMES: &'static [&'static str] = &[#(#names),*];
+            #min_score
+
+            fn from_name_index(index: usize) -> Self {
+                match index {
+                    #(#indices => #ident::#variants,)*
+                    _ => ::std::panic!("no variant with the index {}", index)
+                }
+            }
+        }
+
+        impl #impl_generics ::std::str::FromStr for #ident #type_generics #where_clause {
+            type Err = ::similar_string::UnknownVariantError;
+
+            fn from_str(input: &str) -> ::std::result::Result<Self, Self::Err> {
+                let resolved = <Self as ::similar_string::FuzzyEnum>::fuzzy_parse(input)?;
+                if let ::std::option::Option::Some(warning) = resolved.warning() {
+                    #warn;
+                }
+                ::std::result::Result::Ok(resolved.value)
+            }
+        }
+    })
+}
@@ -0,0 +1,544 @@
+use std::cmp::max;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Character used to mark gaps in aligned strings
+pub const GAP: char = '-';
+
+/// Scores of aligning pairs of characters, such as BLOSUM tables for protein sequences
+///
+/// Pairs that are not in the matrix fall back to the match and mismatch scores of [`Scoring`].
+/// # Example
+/// ```
+/// use similar_string::{Scoring, SubstitutionMatrix, needleman_wunsch_score};
+///
+/// // Transitions between purines or pyrimidines are more likely than transversions
+/// let dna = SubstitutionMatrix::from_table("ACGT", [
+///     [ 2, -2, -1, -2],
+///     [-2,  2, -2, -1],
+///     [-1, -2,  2, -2],
+///     [-2, -1, -2,  2]
+/// ]);
+/// let scoring = Scoring::new(2, -2, -3).with_matrix(dna);
+///
+/// needleman_wunsch_score("GATC", "AATC", &scoring); // 5
+/// needleman_wunsch_score("GATC", "CATC", &scoring); // 4
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubstitutionMatrix {
+    scores: HashMap<(char, char), i32>
+}
+
+impl SubstitutionMatrix {
+    /// Create a matrix without any scores
+    pub fn new() -> Self {
+        SubstitutionMatrix::default()
+    }
+
+    /// Create a matrix from a square table of scores
+    ///
+    /// The row is selected by the character of the left string and the column
+    /// by the character of the right one, both in the order of `alphabet`.
+    ///
+    /// # Panics
+    /// Panics if the table isn't a square with a row and a column for every character of the alphabet.
+    pub fn from_table<R: AsRef<[i32]>>(alphabet: &str, rows: impl IntoIterator<Item = R>) -> Self {
+        let alphabet: Vec<char> = alphabet.chars().collect();
+        let mut scores = HashMap::new();
+        let mut rows = rows.into_iter();
+        for left in &alphabet {
+            let row = rows.next().expect("there must be a row for every character of the alphabet");
+            let row = row.as_ref();
+            assert_eq!(row.len(), alphabet.len(), "every row must have a score for every character of the alphabet");
+            for (right, score) in alphabet.iter().zip(row) {
+                scores.insert((*left, *right), *score);
+            }
+        }
+        assert!(rows.next().is_none(), "there must be no more rows than characters of the alphabet");
+        SubstitutionMatrix { scores }
+    }
+
+    /// Set the score of aligning the two characters with each other, in both orders
+    pub fn with(mut self, left: char, right: char, score: i32) -> Self {
+        self.scores.insert((left, right), score);
+        self.scores.insert((right, left), score);
+        self
+    }
+
+    /// Get the score of aligning a character of the left string with one of the right string
+    pub fn get(&self, left: char, right: char) -> Option<i32> {
+        self.scores.get(&(left, right)).copied()
+    }
+}
+
+/// Scores used by the alignment functions
+///
+/// Positive scores reward and negative scores penalize the alignment.
+/// The default scoring awards 1 for a match and -1 for both a mismatch and a gap.
+/// A [`SubstitutionMatrix`] can override the scores of specific pairs of characters.
+///
+/// A gap of length `k` scores `gap_open_score + k * gap_score`. The gap opening score
+/// is 0 by default, which makes the cost of gaps linear. Setting it to a negative
+/// value makes one long gap score better than several short ones (affine gaps).
+/// # Example
+/// ```
+/// use similar_string::Scoring;
+///
+/// // Gaps are twice as bad as substitutions
+/// let scoring = Scoring::new(1, -1, -2);
+/// // Opening a gap costs 5 and every gapped character costs 1 more
+/// let affine = Scoring::new(1, -1, -1).with_gap_open(-5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    match_score: i32,
+    mismatch_score: i32,
+    gap_score: i32,
+    gap_open_score: i32,
+    matrix: Option<Arc<SubstitutionMatrix>>
+}
+
+impl Scoring {
+    /// Create scoring with given match, mismatch and gap scores
+    pub fn new(match_score: i32, mismatch_score: i32, gap_score: i32) -> Self {
+        Scoring { match_score, mismatch_score, gap_score, gap_open_score: 0, matrix: None }
+    }
+
+    /// Set the additional score of opening a gap
+    pub fn with_gap_open(mut self, gap_open_score: i32) -> Self {
+        self.gap_open_score = gap_open_score;
+        self
+    }
+
+    /// Use the matrix for the scores of aligning two characters
+    pub fn with_matrix(mut self, matrix: SubstitutionMatrix) -> Self {
+        self.matrix = Some(Arc::new(matrix));
+        self
+    }
+
+    /// Score of aligning two equal characters
+    pub fn match_score(&self) -> i32 {
+        self.match_score
+    }
+
+    /// Score of aligning two different characters
+    pub fn mismatch_score(&self) -> i32 {
+        self.mismatch_score
+    }
+
+    /// Score of aligning a character with a gap
+    pub fn gap_score(&self) -> i32 {
+        self.gap_score
+    }
+
+    /// Additional score of opening a gap
+    pub fn gap_open_score(&self) -> i32 {
+        self.gap_open_score
+    }
+
+    /// Matrix of the scores of aligning two characters
+    pub fn matrix(&self) -> Option<&SubstitutionMatrix> {
+        self.matrix.as_deref()
+    }
+
+    #[inline]
+    pub(crate) fn substitution(&self, left: char, right: char) -> i32 {
+        if let Some(score) = self.matrix.as_ref().and_then(|matrix| matrix.get(left, right)) {
+            return score;
+        }
+        if left == right { self.match_score } else { self.mismatch_score }
+    }
+}
+
+impl Default for Scoring {
+    fn default() -> Self {
+        Scoring::new(1, -1, -1)
+    }
+}
+
+/// Result of aligning two strings
+///
+/// Both aligned strings have the same number of characters,
+/// with [`GAP`] inserted wherever one string has no counterpart in the other.
+/// Local alignments cover only a region of the inputs which starts at
+/// the character offsets given by `left_start` and `right_start`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alignment {
+    /// Total score of the alignment
+    pub score: i32,
+    /// The left string with gaps inserted
+    pub left: String,
+    /// The right string with gaps inserted
+    pub right: String,
+    /// Character offset of the aligned region in the left string
+    pub left_start: usize,
+    /// Character offset of the aligned region in the right string
+    pub right_start: usize
+}
+
+/// Score used in place of negative infinity that cannot overflow when added to
+const NONE: i32 = i32::MIN / 2;
+
+/// Dynamic programming tables of Gotoh's algorithm
+///
+/// `best` holds the best score of aligning the prefixes, while `left_gaps` and `right_gaps`
+/// hold the best score of alignments that end with a gap in the left or right string respectively.
+struct Tables {
+    best: Vec<Vec<i32>>,
+    left_gaps: Vec<Vec<i32>>,
+    right_gaps: Vec<Vec<i32>>
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Best,
+    LeftGap,
+    RightGap
+}
+
+/// Compute one row of Gotoh's algorithm
+#[inline]
+fn fill_row(
+    left: char, right: &[char], scoring: &Scoring, local: bool,
+    above: (&[i32], &[i32]), row: (&mut [i32], &mut [i32], &mut [i32])
+) {
+    let (above_best, above_right_gaps) = above;
+    let (best, left_gaps, right_gaps) = row;
+    let open = scoring.gap_open_score + scoring.gap_score;
+    for col in 1..=right.len() {
+        left_gaps[col] = max(left_gaps[col - 1] + scoring.gap_score, best[col - 1] + open);
+        right_gaps[col] = max(above_right_gaps[col] + scoring.gap_score, above_best[col] + open);
+        let diagonal = above_best[col - 1] + scoring.substitution(left, right[col - 1]);
+        best[col] = max(diagonal, max(left_gaps[col], right_gaps[col]));
+        if local {
+            best[col] = max(best[col], 0);
+        }
+    }
+}
+
+/// Initial values of the first column in the given row
+#[inline]
+fn border(index: usize, scoring: &Scoring, local: bool) -> (i32, i32) {
+    match (index, local) {
+        (_, true) => (0, NONE),
+        (0, false) => (0, NONE),
+        (_, false) => {
+            let score = scoring.gap_open_score + index as i32 * scoring.gap_score;
+            (score, score)
+        }
+    }
+}
+
+fn tables(left: &[char], right: &[char], scoring: &Scoring, local: bool) -> Tables {
+    let mut best = vec![vec![0; right.len() + 1]; left.len() + 1];
+    let mut left_gaps = vec![vec![NONE; right.len() + 1]; left.len() + 1];
+    let mut right_gaps = vec![vec![NONE; right.len() + 1]; left.len() + 1];
+    for col in 0..=right.len() {
+        (best[0][col], left_gaps[0][col]) = border(col, scoring, local);
+    }
+    for row in 1..=left.len() {
+        (best[row][0], right_gaps[row][0]) = border(row, scoring, local);
+        let (above, current) = best.split_at_mut(row);
+        let (above_gaps, current_gaps) = right_gaps.split_at_mut(row);
+        fill_row(
+            left[row - 1], right, scoring, local,
+            (&above[row - 1], &above_gaps[row - 1]),
+            (&mut current[0], &mut left_gaps[row], &mut current_gaps[0])
+        );
+    }
+    Tables { best, left_gaps, right_gaps }
+}
+
+/// Compute the best score of either global or local alignment in linear memory
+fn score(left: impl AsRef<str>, right: impl AsRef<str>, scoring: &Scoring, local: bool) -> i32 {
+    let (left, right) = (left.as_ref(), right.as_ref());
+    // Keep the rows as short as possible, unless a matrix may score the characters asymmetrically
+    let swap = scoring.matrix.is_none() && left.chars().count() < right.chars().count();
+    let (left, right) = if swap { (right, left) } else { (left, right) };
+    let right: Vec<char> = right.chars().collect();
+    let mut best = vec![0; right.len() + 1];
+    let mut left_gaps = vec![NONE; right.len() + 1];
+    let mut right_gaps = vec![NONE; right.len() + 1];
+    let mut above_best: Vec<i32> = (0..=right.len()).map(|col| border(col, scoring, local).0).collect();
+    let mut above_right_gaps = vec![NONE; right.len() + 1];
+    let mut result = if local { 0 } else { above_best[right.len()] };
+
+    for (row, lletter) in left.chars().enumerate() {
+        (best[0], right_gaps[0]) = border(row + 1, scoring, local);
+        left_gaps[0] = NONE;
+        fill_row(
+            lletter, &right, scoring, local,
+            (&above_best, &above_right_gaps),
+            (&mut best, &mut left_gaps, &mut right_gaps)
+        );
+        if local {
+            result = max(result, best.iter().copied().max().unwrap_or(0));
+        } else {
+            result = best[right.len()];
+        }
+        std::mem::swap(&mut above_best, &mut best);
+        std::mem::swap(&mut above_right_gaps, &mut right_gaps);
+    }
+    result
+}
+
+/// Walk back through the tables from the given cell and build the alignment
+fn traceback(left: &[char], right: &[char], scoring: &Scoring, tables: &Tables, local: bool, end: (usize, usize)) -> Alignment {
+    let Tables { best, left_gaps, right_gaps } = tables;
+    let (mut aligned_left, mut aligned_right) = (vec![], vec![]);
+    let (mut row, mut col) = end;
+    let mut state = State::Best;
+    loop {
+        if state == State::Best {
+            if (row == 0 && col == 0) || (local && best[row][col] == 0) {
+                break;
+            }
+            if row > 0 && col > 0 && best[row][col] == best[row - 1][col - 1] + scoring.substitution(left[row - 1], right[col - 1]) {
+                row -= 1;
+                col -= 1;
+                aligned_left.push(left[row]);
+                aligned_right.push(right[col]);
+                continue;
+            }
+            state = if row > 0 && best[row][col] == right_gaps[row][col] { State::RightGap } else { State::LeftGap };
+            if local && (row == 0 || col == 0) {
+                break;
+            }
+        }
+        match state {
+            State::RightGap => {
+                // Either keep extending the gap or go back to where it was opened
+                if row == 1 || right_gaps[row][col] != right_gaps[row - 1][col] + scoring.gap_score {
+                    state = State::Best;
+                }
+                row -= 1;
+                aligned_left.push(left[row]);
+                aligned_right.push(GAP);
+            }
+            State::LeftGap => {
+                if col == 1 || left_gaps[row][col] != left_gaps[row][col - 1] + scoring.gap_score {
+                    state = State::Best;
+                }
+                col -= 1;
+                aligned_left.push(GAP);
+                aligned_right.push(right[col]);
+            }
+            State::Best => {}
+        }
+    }
+    Alignment {
+        score: best[end.0][end.1],
+        left: aligned_left.into_iter().rev().collect(),
+        right: aligned_right.into_iter().rev().collect(),
+        left_start: row,
+        right_start: col
+    }
+}
+
+/// Get the score of the optimal global alignment of two strings
+///
+/// This is the Needleman-Wunsch algorithm computed in O(n * m) time and O(min(n, m)) memory
+/// (using Gotoh's variant when the gap opening score is set). With a substitution matrix
+/// the strings keep their order, so the memory is proportional to the length of the right one.
+/// With a match score of 1 and other scores of 0 the result equals [`lcs_length`](crate::lcs_length).
+/// # Example
+/// ```
+/// use similar_string::*;
+///
+/// needleman_wunsch_score("GATTACA", "GCATGCU", &Scoring::default()); // 0
+/// ```
+pub fn needleman_wunsch_score(left: impl AsRef<str>, right: impl AsRef<str>, scoring: &Scoring) -> i32 {
+    score(left, right, scoring, false)
+}
+
+/// Find the optimal global alignment of two strings
+///
+/// This is the Needleman-Wunsch algorithm computed in O(n * m) time and memory.
+/// If only the score is needed, use [`needleman_wunsch_score`] instead.
+/// # Example
+/// ```
+/// use similar_string::*;
+///
+/// let alignment = needleman_wunsch("GATTACA", "GCATGCU", &Scoring::default());
+/// alignment.score; // 0
+/// alignment.left; // "G-ATTACA"
+/// alignment.right; // "GCA-TGCU"
+/// ```
+pub fn needleman_wunsch(left: impl AsRef<str>, right: impl AsRef<str>, scoring: &Scoring) -> Alignment {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    let tables = tables(&left, &right, scoring, false);
+    traceback(&left, &right, scoring, &tables, false, (left.len(), right.len()))
+}
+
+/// Get the score of the optimal local alignment of two strings
+///
+/// This is the Smith-Waterman algorithm computed in O(n * m) time and O(min(n, m)) memory
+/// (using Gotoh's variant when the gap opening score is set). With a substitution matrix
+/// the strings keep their order, so the memory is proportional to the length of the right one.
+/// The score is never negative, as an empty alignment scores 0.
+/// # Example
+/// ```
+/// use similar_string::*;
+///
+/// smith_waterman_score("connection reset by peer", "xxresetxx", &Scoring::default()); // 5
+/// ```
+pub fn smith_waterman_score(left: impl AsRef<str>, right: impl AsRef<str>, scoring: &Scoring) -> i32 {
+    score(left, right, scoring, true)
+}
+
+/// Find the optimal local alignment of two strings
+///
+/// This is the Smith-Waterman algorithm computed in O(n * m) time and memory.
+/// It finds the pair of regions of both strings that align best with each other,
+/// which is useful for matching a short query against a long text.
+/// When several regions score equally, the one that ends first is returned.
+/// # Example
+/// ```
+/// use similar_string::*;
+///
+/// let alignment = smith_waterman("connection reset by peer", "xxresetxx", &Scoring::default());
+/// alignment.score; // 5
+/// alignment.left; // "reset"
+/// alignment.left_start; // 11
+/// alignment.right_start; // 2
+/// ```
+pub fn smith_waterman(left: impl AsRef<str>, right: impl AsRef<str>, scoring: &Scoring) -> Alignment {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    let tables = tables(&left, &right, scoring, true);
+    let (mut best, mut end) = (0, (0, 0));
+    for (row, cells) in tables.best.iter().enumerate() {
+        for (col, cell) in cells.iter().enumerate() {
+            if *cell > best {
+                (best, end) = (*cell, (row, col));
+            }
+        }
+    }
+    traceback(&left, &right, scoring, &tables, true, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn needleman_wunsch_works() {
+        let alignment = needleman_wunsch("GATTACA", "GCATGCU", &Scoring::default());
+        assert_eq!(alignment.score, 0);
+        assert_eq!(alignment.left.chars().count(), alignment.right.chars().count());
+        assert_eq!(alignment.left.replace(GAP, ""), "GATTACA");
+        assert_eq!(alignment.right.replace(GAP, ""), "GCATGCU");
+        assert_eq!(needleman_wunsch_score("GATTACA", "GCATGCU", &Scoring::default()), 0);
+    }
+
+    #[test]
+    fn needleman_wunsch_generalizes_lcs() {
+        let scoring = Scoring::new(1, 0, 0);
+        let score = needleman_wunsch_score("longest", "stone", &scoring);
+        assert_eq!(score as usize, lcs_length("longest", "stone"));
+    }
+
+    #[test]
+    fn needleman_wunsch_empty() {
+        let alignment = needleman_wunsch("", "abc", &Scoring::default());
+        assert_eq!(alignment.score, -3);
+        assert_eq!(alignment.left, "---");
+        assert_eq!(alignment.right, "abc");
+        assert_eq!(needleman_wunsch_score("abc", "", &Scoring::default()), -3);
+    }
+
+    #[test]
+    fn smith_waterman_works() {
+        let text = "connection reset by peer";
+        let alignment = smith_waterman(text, "xxresetxx", &Scoring::default());
+        assert_eq!(alignment.score, 5);
+        assert_eq!(alignment.left, "reset");
+        assert_eq!(alignment.right, "reset");
+        assert_eq!((alignment.left_start, alignment.right_start), (11, 2));
+        assert_eq!(smith_waterman_score(text, "xxresetxx", &Scoring::default()), 5);
+    }
+
+    #[test]
+    fn smith_waterman_with_gaps() {
+        let scoring = Scoring::new(3, -3, -2);
+        let alignment = smith_waterman("TGTTACGG", "GGTTGACTA", &scoring);
+        assert_eq!(alignment.score, 13);
+        assert_eq!(alignment.left, "GTT-AC");
+        assert_eq!(alignment.right, "GTTGAC");
+        assert_eq!((alignment.left_start, alignment.right_start), (1, 1));
+    }
+
+    #[test]
+    fn affine_gaps_prefer_single_gap() {
+        let scoring = Scoring::new(2, -2, -1).with_gap_open(-4);
+        let alignment = needleman_wunsch("John Smith", "John Paul Smith", &scoring);
+        assert_eq!(alignment.left, "John----- Smith");
+        assert_eq!(alignment.score, 2 * 10 - 4 - 5);
+        assert_eq!(needleman_wunsch_score("John Smith", "John Paul Smith", &scoring), 11);
+        assert_eq!(needleman_wunsch_score("John Paul Smith", "John Smith", &scoring), 11);
+    }
+
+    #[test]
+    fn affine_gaps_local() {
+        let scoring = Scoring::new(2, -2, -1).with_gap_open(-2);
+        let alignment = smith_waterman("xxabcdefxx", "yyabcZZZdefyy", &scoring);
+        assert_eq!(alignment.left, "abc---def");
+        assert_eq!(alignment.right, "abcZZZdef");
+        assert_eq!(alignment.score, 12 - 2 - 3);
+        assert_eq!((alignment.left_start, alignment.right_start), (2, 2));
+        assert_eq!(smith_waterman_score("xxabcdefxx", "yyabcZZZdefyy", &scoring), 7);
+    }
+
+    #[test]
+    fn substitution_matrix_scores() {
+        let matrix = SubstitutionMatrix::new().with('a', 'e', 1).with('x', 'x', 5);
+        let scoring = Scoring::default().with_matrix(matrix);
+        assert_eq!(needleman_wunsch_score("cat", "cet", &scoring), 3);
+        assert_eq!(needleman_wunsch_score("cet", "cat", &scoring), 3);
+        assert_eq!(needleman_wunsch_score("xyz", "xyz", &scoring), 7);
+        let alignment = smith_waterman("aaxbb", "ccxdd", &scoring);
+        assert_eq!((alignment.score, alignment.left.as_str()), (5, "x"));
+        assert_eq!(scoring.matrix().and_then(|matrix| matrix.get('e', 'a')), Some(1));
+    }
+
+    #[test]
+    fn substitution_matrix_from_table() {
+        let matrix = SubstitutionMatrix::from_table("AB", [[1, -3], [-2, 4]]);
+        assert_eq!(matrix.get('A', 'B'), Some(-3));
+        assert_eq!(matrix.get('B', 'A'), Some(-2));
+        assert_eq!(matrix.get('B', 'C'), None);
+    }
+
+    #[test]
+    fn asymmetric_matrix_scores_match_alignments() {
+        let scoring = Scoring::default().with_matrix(SubstitutionMatrix::from_table("AB", [[1, -3], [2, 4]]));
+        for (left, right) in [("A", "BB"), ("BB", "A"), ("AAB", "BABBA")] {
+            assert_eq!(needleman_wunsch_score(left, right, &scoring), needleman_wunsch(left, right, &scoring).score);
+            assert_eq!(smith_waterman_score(left, right, &scoring), smith_waterman(left, right, &scoring).score);
+        }
+        assert_eq!(needleman_wunsch_score("A", "BB", &scoring), -3);
+        assert_eq!(smith_waterman_score("A", "BB", &scoring), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn substitution_table_must_be_square() {
+        let _ = SubstitutionMatrix::from_table("ABC", [[1, 2, 3], [1, 2, 3]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn substitution_table_without_extra_rows() {
+        let _ = SubstitutionMatrix::from_table("AB", [[1, 2], [1, 2], [1, 2]]);
+    }
+
+    #[test]
+    fn smith_waterman_no_match() {
+        let alignment = smith_waterman("abc", "xyz", &Scoring::default());
+        assert_eq!(alignment.score, 0);
+        assert_eq!(alignment.left, "");
+        assert_eq!(smith_waterman_score("", "xyz", &Scoring::default()), 0);
+    }
+}
@@ -0,0 +1,165 @@
+/// State of a [`LevenshteinAutomaton`] after reading some characters
+///
+/// This is the row of the Levenshtein table of the query against the characters read so far,
+/// with values above the maximal distance clamped to one more than it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LevenshteinState(Vec<usize>);
+
+/// Automaton that accepts the words within a Levenshtein distance of a query
+///
+/// The automaton reads a word one character at a time and tells as soon as possible when no
+/// word with the prefix read so far can be accepted. Its states can be stepped along the edges
+/// of a trie or a finite state transducer, so that whole branches of a dictionary are skipped.
+/// [`find_in_sorted`](LevenshteinAutomaton::find_in_sorted) does the same for a sorted list of words.
+/// # Example
+/// ```
+/// use similar_string::LevenshteinAutomaton;
+///
+/// let automaton = LevenshteinAutomaton::new("bool", 1);
+///
+/// automaton.distance("book"); // Some(1)
+/// automaton.distance("cook"); // None
+/// automaton.find_in_sorted(&["boo", "book", "books", "cook"]); // [("boo", 1), ("book", 1)]
+/// ```
+#[derive(Debug, Clone)]
+pub struct LevenshteinAutomaton {
+    query: Vec<char>,
+    max_dist: usize
+}
+
+impl LevenshteinAutomaton {
+    /// Create an automaton that accepts the words within `max_dist` edits of the query
+    pub fn new(query: impl AsRef<str>, max_dist: usize) -> Self {
+        LevenshteinAutomaton { query: query.as_ref().chars().collect(), max_dist }
+    }
+
+    /// Get the state before reading any characters
+    pub fn start(&self) -> LevenshteinState {
+        LevenshteinState((0..=self.query.len()).map(|value| value.min(self.max_dist.saturating_add(1))).collect())
+    }
+
+    /// Get the state after reading one more character
+    pub fn step(&self, state: &LevenshteinState, letter: char) -> LevenshteinState {
+        let row = &state.0;
+        let limit = self.max_dist.saturating_add(1);
+        let mut next = Vec::with_capacity(row.len());
+        next.push((row[0] + 1).min(limit));
+        for (col, qletter) in self.query.iter().enumerate() {
+            let cost = if *qletter == letter { 0 } else { 1 };
+            next.push((row[col] + cost).min(row[col + 1] + 1).min(next[col] + 1).min(limit));
+        }
+        LevenshteinState(next)
+    }
+
+    /// Check whether the characters read so far form an accepted word
+    pub fn is_match(&self, state: &LevenshteinState) -> bool {
+        self.state_distance(state).is_some()
+    }
+
+    /// Check whether any word starting with the characters read so far can be accepted
+    pub fn can_match(&self, state: &LevenshteinState) -> bool {
+        state.0.iter().any(|value| *value <= self.max_dist)
+    }
+
+    /// Get the distance of the characters read so far from the query if they are accepted
+    pub fn state_distance(&self, state: &LevenshteinState) -> Option<usize> {
+        let distance = state.0[self.query.len()];
+        (distance <= self.max_dist).then_some(distance)
+    }
+
+    /// Get the distance of the word from the query if it is at most the maximal distance
+    ///
+    /// Stops reading the word as soon as it can no longer be accepted.
+    pub fn distance(&self, word: impl AsRef<str>) -> Option<usize> {
+        let mut state = self.start();
+        for letter in word.as_ref().chars() {
+            state = self.step(&state, letter);
+            if !self.can_match(&state) {
+                return None;
+            }
+        }
+        self.state_distance(&state)
+    }
+
+    /// Get the accepted words of a list together with their distances from the query
+    ///
+    /// The states of the prefix shared with the previous word are reused and words that start
+    /// with a rejected prefix are skipped without reading the rest of them, so the search is
+    /// the fastest when the words are sorted. Any list gives correct results.
+    /// The results keep the order of the words.
+    pub fn find_in_sorted<'a, S: AsRef<str> + ?Sized + 'a>(&self, words: impl IntoIterator<Item = &'a S>) -> Vec<(&'a str, usize)> {
+        let mut found = vec![];
+        // States after every character of the previous word, until its prefix got rejected
+        let mut states = vec![self.start()];
+        let mut previous: Vec<char> = vec![];
+        for word in words {
+            let word = word.as_ref();
+            let chars: Vec<char> = word.chars().collect();
+            let common = chars.iter().zip(&previous).take_while(|(left, right)| left == right).count();
+            states.truncate(common + 1);
+            // The shared prefix is already rejected
+            if states.len() <= chars.len() && !self.can_match(&states[states.len() - 1]) {
+                previous = chars;
+                continue;
+            }
+            while states.len() <= chars.len() {
+                let state = self.step(&states[states.len() - 1], chars[states.len() - 1]);
+                let alive = self.can_match(&state);
+                states.push(state);
+                if !alive {
+                    break;
+                }
+            }
+            if states.len() == chars.len() + 1 {
+                if let Some(distance) = self.state_distance(&states[chars.len()]) {
+                    found.push((word, distance));
+                }
+            }
+            previous = chars;
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn automaton_matches_levenshtein() {
+        let words = ["", "a", "boo", "book", "books", "boon", "bool", "cake", "cook", "kitten", "sitting", "żółw"];
+        for query in ["bool", "", "kiten", "zolw", "cooks"] {
+            for max_dist in 0..4 {
+                let automaton = LevenshteinAutomaton::new(query, max_dist);
+                let expected: Vec<(&str, usize)> = words.into_iter()
+                    .map(|word| (word, levenshtein_distance(query, word)))
+                    .filter(|(_, distance)| *distance <= max_dist)
+                    .collect();
+                let checked: Vec<(&str, usize)> = words.into_iter()
+                    .filter_map(|word| automaton.distance(word).map(|distance| (word, distance)))
+                    .collect();
+                assert_eq!(checked, expected, "{} {}", query, max_dist);
+                assert_eq!(automaton.find_in_sorted(&words), expected, "{} {}", query, max_dist);
+            }
+        }
+    }
+
+    #[test]
+    fn automaton_states() {
+        let automaton = LevenshteinAutomaton::new("ab", 1);
+        let start = automaton.start();
+        assert!(!automaton.is_match(&start) && automaton.can_match(&start));
+        let state = automaton.step(&start, 'a');
+        assert_eq!(automaton.state_distance(&state), Some(1));
+        let state = automaton.step(&automaton.step(&state, 'x'), 'y');
+        assert!(!automaton.can_match(&state));
+        // Unsorted words give the same results
+        assert_eq!(automaton.find_in_sorted(&["xy", "b", "ab", "a"]), vec![("b", 1), ("ab", 0), ("a", 1)]);
+    }
+
+    #[test]
+    fn automaton_unbounded() {
+        let automaton = LevenshteinAutomaton::new("bool", usize::MAX);
+        assert_eq!(automaton.find_in_sorted(&["book", "zzzzzzzz"]), vec![("book", 1), ("zzzzzzzz", 8)]);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::metric::{DistanceMetric, Levenshtein};
+
+/// Word of the tree with its children keyed by their distance from it
+///
+/// Removed words stay in the tree to route the searches of their children.
+struct Node {
+    word: String,
+    removed: bool,
+    children: Vec<(f64, usize)>
+}
+
+/// Burkhard-Keller tree of words that finds all the words within a distance of a query
+///
+/// Every child of a word is keyed by its distance from the word, so the triangle inequality
+/// rules out whole subtrees that can't hold a close enough word. A lookup in a large
+/// dictionary visits only a small part of the words instead of comparing all of them.
+/// The metric has to satisfy the triangle inequality, which holds for [`Levenshtein`]
+/// and [`DamerauLevenshtein`](crate::DamerauLevenshtein) but not for [`Osa`](crate::Osa).
+///
+/// Removed words are only marked as such, so the memory they take is freed when the tree is rebuilt
+/// with [`BkTree::compact`].
+/// # Example
+/// ```
+/// use similar_string::BkTree;
+///
+/// let tree: BkTree = ["book", "books", "cake", "boo", "cape", "cart"].into_iter().collect();
+///
+/// tree.find_within("bool", 1.0); // [("book", 1.0), ("boo", 1.0)]
+/// ```
+pub struct BkTree<D = Levenshtein> {
+    metric: D,
+    nodes: Vec<Node>,
+    len: usize
+}
+
+impl BkTree {
+    /// Create an empty tree that uses the Levenshtein distance
+    pub fn new() -> Self {
+        BkTree::with_metric(Levenshtein)
+    }
+}
+
+impl Default for BkTree {
+    fn default() -> Self {
+        BkTree::new()
+    }
+}
+
+impl<D: DistanceMetric> BkTree<D> {
+    /// Create an empty tree that uses the given distance metric
+    pub fn with_metric(metric: D) -> Self {
+        BkTree { metric, nodes: vec![], len: 0 }
+    }
+
+    /// Get the number of words in the tree
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the tree has no words
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Find the node of the word, or the node and the distance under which it would be a child
+    fn locate(&self, word: &str) -> Result<usize, (usize, f64)> {
+        let mut current = 0;
+        loop {
+            let distance = self.metric.distance(word, &self.nodes[current].word);
+            if distance == 0.0 {
+                return Ok(current);
+            }
+            let child = self.nodes[current].children.iter()
+                .find(|(key, _)| *key == distance)
+                .map(|(_, child)| *child);
+            match child {
+                Some(child) => current = child,
+                None => return Err((current, distance))
+            }
+        }
+    }
+
+    /// Add a word to the tree
+    ///
+    /// Returns `false` and leaves the tree unchanged if the word is already there.
+    pub fn insert(&mut self, word: impl AsRef<str>) -> bool {
+        let word = word.as_ref();
+        let next = self.nodes.len();
+        if next > 0 {
+            match self.locate(word) {
+                Ok(node) => {
+                    // A removed word comes back in its old place
+                    let node = &mut self.nodes[node];
+                    if !node.removed {
+                        return false;
+                    }
+                    node.removed = false;
+                    self.len += 1;
+                    return true;
+                }
+                Err((parent, distance)) => self.nodes[parent].children.push((distance, next))
+            }
+        }
+        self.nodes.push(Node { word: word.to_string(), removed: false, children: vec![] });
+        self.len += 1;
+        true
+    }
+
+    /// Remove a word from the tree
+    ///
+    /// Returns `false` if the word is not in the tree.
+    pub fn remove(&mut self, word: impl AsRef<str>) -> bool {
+        if self.nodes.is_empty() {
+            return false;
+        }
+        let Ok(node) = self.locate(word.as_ref()) else {
+            return false;
+        };
+        let node = &mut self.nodes[node];
+        if node.removed {
+            return false;
+        }
+        node.removed = true;
+        self.len -= 1;
+        true
+    }
+
+    /// Rebuild the tree without the removed words and free the memory they take
+    ///
+    /// The words keep the order in which they were inserted.
+    pub fn compact(&mut self) {
+        let nodes = std::mem::take(&mut self.nodes);
+        self.len = 0;
+        for node in nodes.into_iter().filter(|node| !node.removed) {
+            self.insert(node.word);
+        }
+    }
+
+    /// Get all the words within `max_dist` of the query together with their distances
+    ///
+    /// The results are sorted from the closest word and words with equal distances
+    /// keep the order in which they were inserted.
+    pub fn find_within(&self, query: impl AsRef<str>, max_dist: f64) -> Vec<(&str, f64)> {
+        let query = query.as_ref();
+        let mut found: Vec<(usize, f64)> = vec![];
+        let mut pending = if self.nodes.is_empty() { vec![] } else { vec![0] };
+        while let Some(current) = pending.pop() {
+            let node = &self.nodes[current];
+            let distance = self.metric.distance(query, &node.word);
+            if distance <= max_dist && !node.removed {
+                found.push((current, distance));
+            }
+            // Only children in this range can be close enough to the query
+            pending.extend(node.children.iter()
+                .filter(|(key, _)| (key - distance).abs() <= max_dist)
+                .map(|(_, child)| *child));
+        }
+        found.sort_by(|(left_index, left), (right_index, right)| left.total_cmp(right).then(left_index.cmp(right_index)));
+        found.into_iter()
+            .map(|(index, distance)| (self.nodes[index].word.as_str(), distance))
+            .collect()
+    }
+
+    /// Iterate over the words of the tree in the order in which they were inserted
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.nodes.iter().filter(|node| !node.removed).map(|node| node.word.as_str())
+    }
+}
+
+impl<D: DistanceMetric, S: AsRef<str>> Extend<S> for BkTree<D> {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, words: I) {
+        for word in words {
+            self.insert(word);
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for BkTree {
+    fn from_iter<I: IntoIterator<Item = S>>(words: I) -> Self {
+        let mut tree = BkTree::new();
+        tree.extend(words);
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn bk_tree_finds_close_words() {
+        let words = ["book", "books", "cake", "boo", "cape", "cart", "boon", "cook"];
+        let tree: BkTree = words.into_iter().collect();
+        assert_eq!(tree.len(), 8);
+        assert_eq!(tree.find_within("bool", 1.0), vec![("book", 1.0), ("boo", 1.0), ("boon", 1.0)]);
+        assert_eq!(tree.find_within("cape", 0.0), vec![("cape", 0.0)]);
+        // Every word within the distance is found, just like with a linear scan
+        for query in ["bake", "cooks", "", "carts"] {
+            for max_dist in 0..4 {
+                let found: Vec<&str> = tree.find_within(query, max_dist as f64).into_iter().map(|(word, _)| word).collect();
+                let mut expected: Vec<&str> = words.into_iter()
+                    .filter(|word| levenshtein_distance(query, word) <= max_dist)
+                    .collect();
+                expected.sort_by_key(|word| levenshtein_distance(query, word));
+                assert_eq!(found, expected, "{} {}", query, max_dist);
+            }
+        }
+    }
+
+    #[test]
+    fn bk_tree_insert() {
+        let mut tree = BkTree::with_metric(DamerauLevenshtein);
+        assert!(tree.is_empty());
+        assert!(tree.find_within("ab", 2.0).is_empty());
+        assert!(tree.insert("ab"));
+        assert!(!tree.insert("ab"));
+        tree.extend(["ba", "abc"]);
+        assert_eq!(tree.words().collect::<Vec<_>>(), vec!["ab", "ba", "abc"]);
+        assert_eq!(tree.find_within("ab", 1.0), vec![("ab", 0.0), ("ba", 1.0), ("abc", 1.0)]);
+    }
+
+    #[test]
+    fn bk_tree_remove() {
+        let mut tree: BkTree = ["book", "books", "boo", "boon", "cook"].into_iter().collect();
+        assert!(tree.remove("book"));
+        assert!(!tree.remove("book"));
+        assert!(!tree.remove("cake"));
+        assert_eq!(tree.len(), 4);
+        // Words below the removed one are still found
+        assert_eq!(tree.find_within("bool", 1.0), vec![("boo", 1.0), ("boon", 1.0)]);
+        assert_eq!(tree.words().collect::<Vec<_>>(), vec!["books", "boo", "boon", "cook"]);
+        assert!(tree.insert("book"));
+        assert!(!tree.insert("book"));
+        assert_eq!(tree.find_within("bool", 1.0), vec![("book", 1.0), ("boo", 1.0), ("boon", 1.0)]);
+        assert!(!BkTree::new().remove("book"));
+        // Compacting drops the removed words and keeps the others
+        assert!(tree.remove("boo") && tree.remove("cook"));
+        tree.compact();
+        assert_eq!((tree.len(), tree.nodes.len()), (3, 3));
+        assert_eq!(tree.words().collect::<Vec<_>>(), vec!["book", "books", "boon"]);
+        assert_eq!(tree.find_within("bool", 1.0), vec![("book", 1.0), ("boon", 1.0)]);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::error::BudgetExceededError;
+use crate::matrix::Matrix;
+use crate::search::{best_rated, Match};
+use crate::{compare_similarity, levenshtein_distance, similarity_matrix};
+
+/// Work of comparing two strings with the given character counts
+fn pair_work(left: usize, right: usize) -> usize {
+    left.saturating_mul(right)
+}
+
+/// Check the work of comparing two strings against the budget
+fn check_pair(left: &str, right: &str, budget: usize) -> Result<(), BudgetExceededError> {
+    let work = pair_work(left.chars().count(), right.chars().count());
+    if work > budget {
+        return Err(BudgetExceededError { work, budget });
+    }
+    Ok(())
+}
+
+/// Get score of similarity of two strings if it fits in the budget
+///
+/// Works like [`compare_similarity`], but returns [`BudgetExceededError`] without comparing
+/// the strings when the product of their character counts is greater than the budget.
+/// This caps the quadratic work on huge inputs that would otherwise stall a service.
+/// # Example
+/// ```
+/// use similar_string::try_compare_similarity;
+///
+/// try_compare_similarity("age", "page", 100); // Ok(0.75)
+/// try_compare_similarity("age", "page", 10).is_err(); // true
+/// ```
+pub fn try_compare_similarity(left: impl AsRef<str>, right: impl AsRef<str>, budget: usize) -> Result<f64, BudgetExceededError> {
+    check_pair(left.as_ref(), right.as_ref(), budget)?;
+    Ok(compare_similarity(left, right))
+}
+
+/// Get the Levenshtein edit distance of two strings if it fits in the budget
+///
+/// Works like [`levenshtein_distance`] with the budget of [`try_compare_similarity`].
+pub fn try_levenshtein_distance(left: impl AsRef<str>, right: impl AsRef<str>, budget: usize) -> Result<usize, BudgetExceededError> {
+    check_pair(left.as_ref(), right.as_ref(), budget)?;
+    Ok(levenshtein_distance(left, right))
+}
+
+/// Find the option that is the most similar to the target string if it fits in the budget
+///
+/// Works like [`find_best_match`](crate::find_best_match), the budget is shared by all the
+/// comparisons. The work of every option is counted before it is compared, so the search
+/// stops with [`BudgetExceededError`] as soon as the next option doesn't fit.
+/// # Example
+/// ```
+/// use similar_string::try_find_best_match;
+///
+/// let options = vec!["fill", "night", "ride"];
+///
+/// try_find_best_match("fight", &options, 100).unwrap().unwrap().value; // "night"
+/// try_find_best_match("fight", &options, 40).is_err(); // true
+/// ```
+pub fn try_find_best_match<'a, S: AsRef<str> + ?Sized + 'a>(target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>, budget: usize) -> Result<Option<Match<'a>>, BudgetExceededError> {
+    let target = target.as_ref();
+    let chars = target.chars().count();
+    let mut work = 0usize;
+    let mut exceeded = false;
+    let options = options.into_iter().map_while(|option| {
+        work = work.saturating_add(pair_work(chars, option.as_ref().chars().count()));
+        exceeded = work > budget;
+        (!exceeded).then_some(option)
+    });
+    let best = best_rated(target, options).map(|(index, value, score)| Match { index, value: value.as_ref(), score });
+    if exceeded {
+        return Err(BudgetExceededError { work, budget });
+    }
+    Ok(best)
+}
+
+/// Get similarity ratings of all pairs of the items if they fit in the budget
+///
+/// Works like [`similarity_matrix`], the work of all the pairs is counted before any of them
+/// is compared.
+pub fn try_similarity_matrix(items: &[impl AsRef<str>], budget: usize) -> Result<Matrix<f64>, BudgetExceededError> {
+    let mut work = 0usize;
+    let mut later = items.iter().map(|item| item.as_ref().chars().count()).fold(0usize, usize::saturating_add);
+    for item in items {
+        let chars = item.as_ref().chars().count();
+        later = later.saturating_sub(chars);
+        work = work.saturating_add(pair_work(chars, later));
+        if work > budget {
+            return Err(BudgetExceededError { work, budget });
+        }
+    }
+    Ok(similarity_matrix(items))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn budget_of_pairs() {
+        assert_eq!(try_compare_similarity("age", "page", 12), Ok(0.75));
+        assert_eq!(try_compare_similarity("age", "page", 11), Err(BudgetExceededError { work: 12, budget: 11 }));
+        assert_eq!(try_levenshtein_distance("kitten", "", 0), Ok(6));
+        assert_eq!(try_levenshtein_distance("żółw", "żółw", 15).unwrap_err().work, 16);
+    }
+
+    #[test]
+    fn budget_of_batches() {
+        let options = vec!["fill", "night", "ride"];
+        // 20 + 25 + 20 character pairs
+        assert_eq!(try_find_best_match("fight", &options, 65).unwrap().unwrap().value, "night");
+        assert_eq!(try_find_best_match("fight", &options, 64), Err(BudgetExceededError { work: 65, budget: 64 }));
+        assert_eq!(try_find_best_match("fight", &[] as &[&str], 0), Ok(None));
+        let items = ["fight", "night", "ride"];
+        // 25 + 20 + 20 character pairs
+        assert_eq!(try_similarity_matrix(&items, 65), Ok(similarity_matrix(&items)));
+        assert_eq!(try_similarity_matrix(&items, 64), Err(BudgetExceededError { work: 65, budget: 64 }));
+    }
+}
@@ -0,0 +1,234 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::lcs::PatternMasks;
+use crate::lcs_rating;
+use crate::matrix::Matrix;
+use crate::search::{best_rated, rated_options, Match};
+
+/// Signal that stops a long running comparison
+///
+/// It is checked before every comparison. An [`AtomicBool`] is cancelled once it is set
+/// to `true`, for example from a Ctrl-C handler, and a closure is cancelled once it returns `true`,
+/// which suits timeouts.
+/// # Example
+/// ```
+/// use std::time::{Duration, Instant};
+/// use similar_string::CancelToken;
+///
+/// let deadline = Instant::now() + Duration::from_secs(5);
+/// let timeout = || Instant::now() > deadline;
+///
+/// timeout.is_cancelled(); // false
+/// ```
+pub trait CancelToken {
+    /// Check whether the work should stop
+    fn is_cancelled(&self) -> bool;
+}
+
+impl CancelToken for AtomicBool {
+    fn is_cancelled(&self) -> bool {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+impl<F: Fn() -> bool> CancelToken for F {
+    fn is_cancelled(&self) -> bool {
+        self()
+    }
+}
+
+/// Result of work that may have been cancelled before it was done
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Partial<T> {
+    /// What was computed before the work stopped
+    pub value: T,
+    /// Whether the work was done without being cancelled
+    pub complete: bool
+}
+
+/// Iterator that ends early once the token is cancelled
+struct UntilCancelled<'c, I, C: ?Sized> {
+    items: I,
+    cancel: &'c C,
+    cancelled: bool
+}
+
+impl<'c, I, C: CancelToken + ?Sized> UntilCancelled<'c, I, C> {
+    fn new(items: impl IntoIterator<IntoIter = I>, cancel: &'c C) -> Self {
+        UntilCancelled { items: items.into_iter(), cancel, cancelled: false }
+    }
+}
+
+impl<I: Iterator, C: CancelToken + ?Sized> Iterator for UntilCancelled<'_, I, C> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.cancelled {
+            return None;
+        }
+        // The work is only cancelled if there is an item left to process
+        let item = self.items.next()?;
+        if self.cancel.is_cancelled() {
+            self.cancelled = true;
+            return None;
+        }
+        Some(item)
+    }
+}
+
+/// Get similarity scores against the target string until the token is cancelled
+///
+/// Works like [`get_similarity_ratings`](crate::get_similarity_ratings), but the value holds
+/// the ratings of the options that were rated before the work stopped, in their order.
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicBool;
+/// use similar_string::get_similarity_ratings_cancellable;
+///
+/// let options = vec!["fill", "night", "ride"];
+/// let cancel = AtomicBool::new(false);
+///
+/// let ratings = get_similarity_ratings_cancellable("fight", &options, &cancel);
+/// ratings.value; // [0.4, 0.8, 0.2]
+/// ratings.complete; // true
+/// ```
+pub fn get_similarity_ratings_cancellable(target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>, cancel: &(impl CancelToken + ?Sized)) -> Partial<Vec<f64>> {
+    let mut options = UntilCancelled::new(options, cancel);
+    let value = rated_options(target.as_ref(), &mut options).map(|(_, _, score)| score).collect();
+    Partial { value, complete: !options.cancelled }
+}
+
+/// Find the option that is the most similar to the target string until the token is cancelled
+///
+/// Works like [`find_best_match`](crate::find_best_match), but only the options that were
+/// compared before the work stopped are taken into account.
+pub fn find_best_match_cancellable<'a, S: AsRef<str> + ?Sized + 'a>(target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>, cancel: &(impl CancelToken + ?Sized)) -> Partial<Option<Match<'a>>> {
+    let mut options = UntilCancelled::new(options, cancel);
+    let value = best_rated(target.as_ref(), &mut options).map(|(index, value, score)| Match { index, value: value.as_ref(), score });
+    Partial { value, complete: !options.cancelled }
+}
+
+/// Get similarity ratings of all pairs of the items until the token is cancelled
+///
+/// Works like [`similarity_matrix`](crate::similarity_matrix), but the items are added one by one,
+/// so when the work stops the value is the full matrix of the items that were added before.
+/// A token that is already cancelled gives an empty matrix.
+/// # Example
+/// ```
+/// use similar_string::similarity_matrix_cancellable;
+///
+/// let matrix = similarity_matrix_cancellable(&["fight", "night", "ride"], &|| true);
+/// matrix.value.rows(); // 0
+/// matrix.complete; // false
+/// ```
+pub fn similarity_matrix_cancellable(items: &[impl AsRef<str>], cancel: &(impl CancelToken + ?Sized)) -> Partial<Matrix<f64>> {
+    // Every row holds the ratings of an item against the items before it
+    let mut lower: Vec<Vec<f64>> = vec![];
+    if !items.is_empty() && cancel.is_cancelled() {
+        return Partial { value: Matrix::from_vec(0, 0, vec![]), complete: false };
+    }
+    let mut complete = true;
+    'items: for (row, item) in items.iter().enumerate() {
+        let item = item.as_ref();
+        let chars: Vec<char> = item.chars().collect();
+        let masks = PatternMasks::new(&chars);
+        let mut ratings = Vec::with_capacity(row);
+        for other in &items[..row] {
+            if cancel.is_cancelled() {
+                complete = false;
+                break 'items;
+            }
+            ratings.push(lcs_rating(masks.lcs_length(other.as_ref().chars()), item, other.as_ref()));
+        }
+        lower.push(ratings);
+    }
+    let size = lower.len();
+    let mut data = vec![1.0; size * size];
+    for (row, ratings) in lower.into_iter().enumerate() {
+        for (col, rating) in ratings.into_iter().enumerate() {
+            data[row * size + col] = rating;
+            data[col * size + row] = rating;
+        }
+    }
+    Partial { value: Matrix::from_vec(size, size, data), complete }
+}
+
+/// Add the words to an index until the token is cancelled
+///
+/// Works with any index that can be extended, such as [`BkTree`](crate::BkTree), [`Trie`](crate::Trie)
+/// or [`FuzzySet`](crate::FuzzySet). The value is the number of words that were added
+/// before the work stopped, the index keeps them and can be extended later with the rest.
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicBool;
+/// use similar_string::{extend_cancellable, BkTree};
+///
+/// let mut tree = BkTree::new();
+/// let cancel = AtomicBool::new(false);
+///
+/// extend_cancellable(&mut tree, ["book", "cake"], &cancel).value; // 2
+/// ```
+pub fn extend_cancellable<S>(index: &mut impl Extend<S>, items: impl IntoIterator<Item = S>, cancel: &(impl CancelToken + ?Sized)) -> Partial<usize> {
+    let mut items = UntilCancelled::new(items, cancel);
+    let mut count = 0;
+    index.extend((&mut items).inspect(|_| count += 1));
+    Partial { value: count, complete: !items.cancelled }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::sync::atomic::AtomicBool;
+    use crate::*;
+
+    /// Token that is cancelled after the given number of checks
+    fn after(checks: usize) -> impl Fn() -> bool {
+        let count = Cell::new(0);
+        move || {
+            count.set(count.get() + 1);
+            count.get() > checks
+        }
+    }
+
+    #[test]
+    fn cancel_ratings() {
+        let options = vec!["fill", "night", "ride"];
+        let ratings = get_similarity_ratings_cancellable("fight", &options, &AtomicBool::new(false));
+        assert_eq!(ratings, Partial { value: get_similarity_ratings("fight", &options).unwrap(), complete: true });
+        let ratings = get_similarity_ratings_cancellable("fight", &options, &after(2));
+        assert_eq!(ratings, Partial { value: vec![0.4, 0.8], complete: false });
+        let best = find_best_match_cancellable("fight", &options, &after(1));
+        assert_eq!(best, Partial { value: Some(Match { index: 0, value: "fill", score: 0.4 }), complete: false });
+        let best = find_best_match_cancellable("fight", &options, &AtomicBool::new(true));
+        assert_eq!(best, Partial { value: None, complete: false });
+        // A token that fires after the last item doesn't cancel finished work
+        let ratings = get_similarity_ratings_cancellable("fight", &options, &after(3));
+        assert_eq!(ratings, Partial { value: vec![0.4, 0.8, 0.2], complete: true });
+        let ratings = get_similarity_ratings_cancellable("fight", &[] as &[&str], &AtomicBool::new(true));
+        assert_eq!(ratings, Partial { value: vec![], complete: true });
+    }
+
+    #[test]
+    fn cancel_matrix() {
+        let items = ["fight", "night", "ride", "light"];
+        let matrix = similarity_matrix_cancellable(&items, &after(usize::MAX));
+        assert_eq!(matrix, Partial { value: similarity_matrix(&items), complete: true });
+        // The token is checked before the first item and the third item is cancelled before its first comparison
+        let matrix = similarity_matrix_cancellable(&items, &after(2));
+        assert_eq!(matrix, Partial { value: similarity_matrix(&items[..2]), complete: false });
+        let matrix = similarity_matrix_cancellable(&items, &|| true);
+        assert_eq!((matrix.value.rows(), matrix.complete), (0, false));
+        assert!(similarity_matrix_cancellable(&[] as &[&str], &|| true).complete);
+    }
+
+    #[test]
+    fn cancel_extend() {
+        let mut tree = BkTree::new();
+        let added = extend_cancellable(&mut tree, ["book", "cake", "cape"], &after(2));
+        assert_eq!(added, Partial { value: 2, complete: false });
+        assert_eq!(tree.words().collect::<Vec<_>>(), vec!["book", "cake"]);
+        let added = extend_cancellable(&mut tree, ["cape"], &AtomicBool::new(false));
+        assert_eq!(added, Partial { value: 1, complete: true });
+    }
+}
@@ -0,0 +1,96 @@
+use std::cmp::max;
+
+use crate::lcs::PatternMasks;
+
+/// Language rules of case-insensitive comparison
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaseLocale {
+    /// Rules shared by most languages
+    #[default]
+    Default,
+    /// Turkish and Azerbaijani rules, where "I" is the capital of dotless "ı"
+    /// and "İ" is the capital of "i"
+    Turkish
+}
+
+/// Characters of the text with the letter case folded
+///
+/// Besides lowercasing, "ß" is spelled as "ss" and the final sigma matches the regular one.
+/// The characters are produced one at a time, so no copy of the text is made.
+pub(crate) fn fold_case(text: &str, locale: CaseLocale) -> impl Iterator<Item = char> + '_ {
+    text.chars().flat_map(move |letter| {
+        let mut folded = ['\0'; 3];
+        let len = match (letter, locale) {
+            ('I', CaseLocale::Turkish) => { folded[0] = 'ı'; 1 }
+            ('İ', CaseLocale::Turkish) => { folded[0] = 'i'; 1 }
+            ('ß' | 'ẞ', _) => { folded = ['s', 's', '\0']; 2 }
+            ('ς', _) => { folded[0] = 'σ'; 1 }
+            _ => {
+                let mut len = 0;
+                for lower in letter.to_lowercase() {
+                    folded[len] = lower;
+                    len += 1;
+                }
+                len
+            }
+        };
+        folded.into_iter().take(len)
+    })
+}
+
+/// Get score of similarity of two strings ignoring the letter case
+///
+/// Works like [`compare_similarity`](crate::compare_similarity), but the letter case
+/// is folded into the characters that are compared, without allocating lowercase `String`s.
+/// Letters such as "ß" that fold to several characters count as all of them.
+/// # Example
+/// ```
+/// use similar_string::compare_similarity_ignore_case;
+///
+/// compare_similarity_ignore_case("AGE", "page"); // 0.75
+/// compare_similarity_ignore_case("STRASSE", "straße"); // 1.0
+/// ```
+pub fn compare_similarity_ignore_case(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    compare_similarity_ignore_case_with_locale(left, right, CaseLocale::Default)
+}
+
+/// Get score of similarity of two strings ignoring the letter case with the rules of the given locale
+/// # Example
+/// ```
+/// use similar_string::{compare_similarity_ignore_case_with_locale, CaseLocale};
+///
+/// compare_similarity_ignore_case_with_locale("DIYARBAKIR", "diyarbakır", CaseLocale::Turkish); // 0.9
+/// compare_similarity_ignore_case_with_locale("İSTANBUL", "istanbul", CaseLocale::Turkish); // 1.0
+/// ```
+pub fn compare_similarity_ignore_case_with_locale(left: impl AsRef<str>, right: impl AsRef<str>, locale: CaseLocale) -> f64 {
+    let (left, right) = (left.as_ref(), right.as_ref());
+    let right_len = fold_case(right, locale).count();
+    // Only the pattern of the bit-parallel algorithm has to be stored
+    let pattern: Vec<char> = fold_case(left, locale).collect();
+    let lcs_len = PatternMasks::new(&pattern).lcs_length(fold_case(right, locale));
+    let size = max(pattern.len(), right_len);
+    // Empty strings should match
+    if size == 0 { 1.0 } else { lcs_len as f64 / size as f64 }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn ignore_case_works() {
+        assert_eq!(compare_similarity_ignore_case("AGE", "page"), 0.75);
+        assert_eq!(compare_similarity_ignore_case("STRASSE", "straße"), 1.0);
+        assert_eq!(compare_similarity_ignore_case("ΣΟΦΟΣ", "σοφος"), 1.0);
+        assert_eq!(compare_similarity_ignore_case("ŻÓŁW", "żółw"), 1.0);
+        assert_eq!(compare_similarity_ignore_case("", ""), 1.0);
+    }
+
+    #[test]
+    fn turkish_locale() {
+        let turkish = |left, right| compare_similarity_ignore_case_with_locale(left, right, CaseLocale::Turkish);
+        assert_eq!(turkish("KIŞ", "kış"), 1.0);
+        assert_eq!(turkish("İNCİ", "inci"), 1.0);
+        assert!(compare_similarity_ignore_case("KIŞ", "kış") < 1.0);
+    }
+}
@@ -0,0 +1,153 @@
+use std::ffi::OsString;
+
+use clap::builder::StyledStr;
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+use clap::{ArgMatches, Command, Error, Parser};
+
+use crate::suggest::DidYouMean;
+
+/// Subcommand names and aliases of the command
+fn subcommand_names(command: &Command) -> Vec<String> {
+    command.get_subcommands()
+        .filter(|subcommand| !subcommand.is_hide_set())
+        .flat_map(|subcommand| std::iter::once(subcommand.get_name()).chain(subcommand.get_visible_aliases()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Long flags of the command with their dashes
+fn long_flags(command: &Command) -> Vec<String> {
+    command.get_arguments()
+        .filter(|arg| !arg.is_hide_set())
+        .filter_map(|arg| arg.get_long())
+        .map(|long| format!("--{}", long))
+        .collect()
+}
+
+/// Replace the suggestions of a clap error with the ones of the suggester
+///
+/// Handles unknown subcommands, unknown flags and invalid values, other errors are returned as they are.
+/// The suggestions of clap's own suggester are removed and the message of the suggester is added as a tip.
+/// The command has to be the (sub)command that reported the error, [`try_get_matches_with_suggestions`]
+/// finds it on its own.
+pub fn replace_clap_suggestions(mut error: Error, command: &Command, suggester: &DidYouMean) -> Error {
+    let (input, candidates) = match error.kind() {
+        ErrorKind::InvalidSubcommand | ErrorKind::UnknownArgument => {
+            let input = match (error.get(ContextKind::InvalidSubcommand), error.get(ContextKind::InvalidArg)) {
+                (Some(ContextValue::String(input)), _) | (None, Some(ContextValue::String(input))) => input.clone(),
+                _ => return error
+            };
+            // Flags are only compared with flags and everything else with subcommands
+            let candidates = if input.starts_with("--") { long_flags(command) } else { subcommand_names(command) };
+            (input, candidates)
+        }
+        ErrorKind::InvalidValue => match (error.get(ContextKind::InvalidValue), error.get(ContextKind::ValidValue)) {
+            (Some(ContextValue::String(input)), Some(ContextValue::Strings(valid))) => (input.clone(), valid.clone()),
+            _ => return error
+        },
+        _ => return error
+    };
+    for kind in [ContextKind::SuggestedSubcommand, ContextKind::SuggestedArg, ContextKind::SuggestedValue] {
+        error.remove(kind);
+    }
+    if let Some(message) = suggester.message(input, candidates) {
+        let mut tips = match error.remove(ContextKind::Suggested) {
+            Some(ContextValue::StyledStrs(tips)) => tips,
+            _ => vec![]
+        };
+        tips.insert(0, StyledStr::from(message));
+        error.insert(ContextKind::Suggested, ContextValue::StyledStrs(tips));
+    }
+    error
+}
+
+/// Parse the arguments like [`Command::try_get_matches_from`] with the suggestions of the suggester
+///
+/// The first argument is the name of the binary. Errors about unknown subcommands, flags
+/// and values suggest the candidates of the suggester instead of the ones of clap.
+/// # Example
+/// ```
+/// use clap::Command;
+/// use similar_string::{DidYouMean, try_get_matches_with_suggestions};
+///
+/// let command = Command::new("cargo")
+///     .subcommand(Command::new("build"))
+///     .subcommand(Command::new("check"));
+///
+/// let error = try_get_matches_with_suggestions(command, ["cargo", "biuld"], &DidYouMean::new()).unwrap_err();
+/// // error: unrecognized subcommand 'biuld'
+/// //
+/// //   tip: Did you mean `build`?
+/// ```
+pub fn try_get_matches_with_suggestions<I, T>(command: Command, args: I, suggester: &DidYouMean) -> Result<ArgMatches, Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone
+{
+    let args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+    let mut command = command;
+    command.build();
+    command.clone().try_get_matches_from(&args).map_err(|error| {
+        // Follow the subcommands of the arguments to the one that reported the error
+        let mut current = &command;
+        for arg in args.iter().skip(1).take_while(|arg| *arg != "--") {
+            if let Some(subcommand) = arg.to_str().and_then(|name| current.find_subcommand(name)) {
+                current = subcommand;
+            }
+        }
+        replace_clap_suggestions(error, current, suggester)
+    })
+}
+
+/// Parse the arguments into a clap parser with the suggestions of the suggester
+///
+/// Works like [`Parser::try_parse_from`] and suggests like [`try_get_matches_with_suggestions`].
+pub fn try_parse_with_suggestions<P, I, T>(args: I, suggester: &DidYouMean) -> Result<P, Error>
+where
+    P: Parser,
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone
+{
+    let mut command = P::command();
+    let matches = try_get_matches_with_suggestions(command.clone(), args, suggester)?;
+    P::from_arg_matches(&matches).map_err(|error| error.format(&mut command))
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::error::{ContextKind, ContextValue, ErrorKind};
+    use clap::{Arg, Command};
+
+    use crate::*;
+
+    fn command() -> Command {
+        Command::new("app")
+            .arg(Arg::new("verbose").long("verbose").num_args(0))
+            .arg(Arg::new("color").long("color").value_parser(["always", "auto", "never"]))
+            .subcommand(Command::new("build").subcommand(Command::new("release")))
+            .subcommand(Command::new("check").visible_alias("lint"))
+    }
+
+    fn tips(args: &[&str], suggester: &DidYouMean) -> (ErrorKind, Vec<String>) {
+        let error = try_get_matches_with_suggestions(command(), args, suggester).unwrap_err();
+        let tips = match error.get(ContextKind::Suggested) {
+            Some(ContextValue::StyledStrs(tips)) => tips.iter().map(ToString::to_string).collect(),
+            _ => vec![]
+        };
+        assert!(error.get(ContextKind::SuggestedSubcommand).is_none() && error.get(ContextKind::SuggestedArg).is_none());
+        (error.kind(), tips)
+    }
+
+    #[test]
+    fn clap_suggestions() {
+        let suggester = DidYouMean::new();
+        assert_eq!(tips(&["app", "biuld"], &suggester), (ErrorKind::InvalidSubcommand, vec!["Did you mean `build`?".to_string()]));
+        assert_eq!(tips(&["app", "lnt"], &suggester).1, vec!["Did you mean `lint`?".to_string()]);
+        assert_eq!(tips(&["app", "--verbos"], &suggester).1, vec!["Did you mean `--verbose`?".to_string()]);
+        assert_eq!(tips(&["app", "--color", "alwys"], &suggester), (ErrorKind::InvalidValue, vec!["Did you mean `always`?".to_string()]));
+        assert_eq!(tips(&["app", "build", "relase"], &suggester).1, vec!["Did you mean `release`?".to_string()]);
+        // A stricter suggester drops the suggestions that clap would make
+        assert!(tips(&["app", "buil"], &suggester.min_score(0.95)).1.is_empty());
+        assert!(try_get_matches_with_suggestions(command(), ["app", "build", "release"], &suggester).is_ok());
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::weighted::EditCosts;
+
+/// Table of substitution costs of characters and sequences that are easy to mistake
+///
+/// Substitutions that are not in the table cost 1.0, just like insertions and deletions.
+/// Keys can be longer than a single character, which allows misreads such as "rn" read as "m".
+/// A typical use is ranking OCR correction candidates by plausible misreads.
+/// # Example
+/// ```
+/// use similar_string::{ConfusionMatrix, weighted_levenshtein};
+///
+/// let ocr = ConfusionMatrix::new()
+///     .symmetric("rn", "m", 0.1)
+///     .symmetric("1", "l", 0.2)
+///     .substitution("0", "O", 0.3);
+///
+/// weighted_levenshtein("rnodel", "model", &ocr); // 0.1
+/// weighted_levenshtein("he1lo", "hello", &ocr); // 0.2
+/// weighted_levenshtein("0K", "OK", &ocr); // 0.3
+/// weighted_levenshtein("OK", "0K", &ocr); // 1.0
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfusionMatrix {
+    costs: HashMap<Vec<char>, HashMap<Vec<char>, f64>>,
+    longest: usize
+}
+
+impl ConfusionMatrix {
+    /// Create a table without any substitutions
+    pub fn new() -> Self {
+        ConfusionMatrix::default()
+    }
+
+    /// Set the cost of replacing `from` in the left string with `to` in the right one
+    ///
+    /// # Panics
+    /// Panics if either of the strings is empty.
+    pub fn substitution(mut self, from: &str, to: &str, cost: f64) -> Self {
+        assert!(!from.is_empty() && !to.is_empty(), "substituted strings must not be empty");
+        let (from, to): (Vec<char>, Vec<char>) = (from.chars().collect(), to.chars().collect());
+        self.longest = self.longest.max(from.len()).max(to.len());
+        self.costs.entry(from).or_default().insert(to, cost);
+        self
+    }
+
+    /// Set the cost of replacing `left` with `right` and the other way around
+    ///
+    /// # Panics
+    /// Panics if either of the strings is empty.
+    pub fn symmetric(self, left: &str, right: &str, cost: f64) -> Self {
+        self.substitution(left, right, cost).substitution(right, left, cost)
+    }
+
+    /// Get the cost of the substitution if the table has it
+    pub fn get(&self, from: &str, to: &str) -> Option<f64> {
+        let from: Vec<char> = from.chars().collect();
+        let to: Vec<char> = to.chars().collect();
+        self.lookup(&from, &to)
+    }
+
+    fn lookup(&self, from: &[char], to: &[char]) -> Option<f64> {
+        self.costs.get(from)?.get(to).copied()
+    }
+}
+
+impl EditCosts for ConfusionMatrix {
+    fn substitution(&self, from: char, to: char) -> f64 {
+        self.lookup(&[from], &[to]).unwrap_or(1.0)
+    }
+
+    fn max_sequence_len(&self) -> usize {
+        self.longest
+    }
+
+    fn sequence_substitution(&self, from: &[char], to: &[char]) -> Option<f64> {
+        self.lookup(from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn misreads_are_cheap() {
+        let ocr = ConfusionMatrix::new().symmetric("rn", "m", 0.1).symmetric("1", "l", 0.2).substitution("0", "O", 0.3);
+        assert_eq!(weighted_levenshtein("rnodern", "modem", &ocr), 0.2);
+        assert_eq!(weighted_levenshtein("modem", "rnodern", &ocr), 0.2);
+        assert_eq!(weighted_levenshtein("0K", "OK", &ocr), 0.3);
+        assert_eq!(weighted_levenshtein("OK", "0K", &ocr), 1.0);
+        assert_eq!(weighted_levenshtein("kitten", "sitting", &ocr), 3.0);
+        assert_eq!(ocr.get("rn", "m"), Some(0.1));
+        assert_eq!(ocr.get("m", "n"), None);
+    }
+
+    #[test]
+    fn candidates_are_ranked_by_misreads() {
+        let ocr = ConfusionMatrix::new().symmetric("rn", "m", 0.1);
+        let read = "cornputer";
+        let candidates = ["commuter", "computer"];
+        let best = candidates.iter().min_by(|left, right| {
+            weighted_levenshtein(read, left, &ocr).total_cmp(&weighted_levenshtein(read, right, &ocr))
+        });
+        assert_eq!(best, Some(&"computer"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_substitution() {
+        let _ = ConfusionMatrix::new().substitution("", "a", 0.5);
+    }
+}
@@ -0,0 +1,396 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Read, Write};
+
+use crate::lcs::PatternMasks;
+use crate::ngram::{ngram_profile, profile_cosine};
+use crate::normalizer::Normalizer;
+use crate::persist::{Reader, Writer, invalid_data};
+use crate::search::Match;
+
+/// Magic bytes of a saved [`Corpus`]
+const CORPUS_MAGIC: &[u8; 4] = b"SSCO";
+
+/// Size of the n-grams of the cosine similarity of a [`Corpus`]
+const CORPUS_NGRAM_SIZE: usize = 2;
+
+/// Option of a corpus together with its precomputed state
+struct Entry {
+    value: String,
+    chars: Vec<char>,
+    profile: HashMap<String, usize>
+}
+
+/// Highest LCS rating that strings of the given character counts can have
+fn length_bound(left: usize, right: usize) -> f64 {
+    let size = left.max(right);
+    if size == 0 { 1.0 } else { left.min(right) as f64 / size as f64 }
+}
+
+/// Options to be searched many times, with the state that doesn't depend on the query precomputed
+///
+/// The lowercase characters, character counts and bigram profiles of the options are
+/// computed once, when the corpus is built. Options are also grouped by their character
+/// count, so that groups that can't beat the best match found so far are skipped.
+/// Letter case is ignored, while the matches refer to the original options.
+/// A corpus built with [`Corpus::with_normalizer`] applies the normalizer to the options
+/// and the queries instead of converting them to lowercase.
+///
+/// Options can be inserted and removed later without rebuilding the corpus. Every option
+/// keeps its id, which is the number of options inserted before it, also after others are removed.
+/// # Example
+/// ```
+/// use similar_string::Corpus;
+///
+/// let corpus = Corpus::new(["Fill", "Night", "Ride"]);
+///
+/// let best = corpus.best_match("FIGHT").unwrap();
+/// (best.index, best.value, best.score); // (1, "Night", 0.8)
+/// ```
+pub struct Corpus {
+    entries: Vec<Option<Entry>>,
+    buckets: BTreeMap<usize, Vec<usize>>,
+    len: usize,
+    normalizer: Option<Normalizer>
+}
+
+impl Corpus {
+    /// Build the corpus of the options
+    pub fn new(options: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Corpus::build(options, None)
+    }
+
+    /// Build the corpus of the options normalized with the normalizer
+    ///
+    /// The normalizer is applied to the options when they are inserted and to every query,
+    /// so both are always normalized identically. Letter case is only ignored
+    /// if the normalizer converts the strings to lowercase.
+    /// # Example
+    /// ```
+    /// use similar_string::{Corpus, Normalizer};
+    ///
+    /// let normalizer = Normalizer::new().lowercase().fold_accents();
+    /// let corpus = Corpus::with_normalizer(["Café", "Thé"], normalizer);
+    ///
+    /// corpus.best_match("CAFE").unwrap().score; // 1.0
+    /// ```
+    pub fn with_normalizer(options: impl IntoIterator<Item = impl AsRef<str>>, normalizer: Normalizer) -> Self {
+        Corpus::build(options, Some(normalizer))
+    }
+
+    fn build(options: impl IntoIterator<Item = impl AsRef<str>>, normalizer: Option<Normalizer>) -> Self {
+        let mut corpus = Corpus { entries: vec![], buckets: BTreeMap::new(), len: 0, normalizer };
+        for option in options {
+            corpus.insert(option);
+        }
+        corpus
+    }
+
+    /// Get the string that is compared in place of the option or the query
+    fn normalize(&self, text: &str) -> String {
+        match &self.normalizer {
+            Some(normalizer) => normalizer.apply(text),
+            None => text.to_lowercase()
+        }
+    }
+
+    /// Get the number of options
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the corpus has no options
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the option with the given id, if it wasn't removed
+    pub fn get(&self, id: usize) -> Option<&str> {
+        self.entries.get(id)?.as_ref().map(|entry| entry.value.as_str())
+    }
+
+    /// Add an option to the corpus and get its id
+    pub fn insert(&mut self, option: impl AsRef<str>) -> usize {
+        let value = option.as_ref().to_string();
+        let normalized = self.normalize(&value);
+        let profile = ngram_profile(&normalized, CORPUS_NGRAM_SIZE).into_iter()
+            .map(|(ngram, count)| (ngram.to_string(), count))
+            .collect();
+        let chars: Vec<char> = normalized.chars().collect();
+        let id = self.entries.len();
+        self.buckets.entry(chars.len()).or_default().push(id);
+        self.entries.push(Some(Entry { value, chars, profile }));
+        self.len += 1;
+        id
+    }
+
+    /// Remove the option with the given id and get it back
+    ///
+    /// Returns `None` if there is no such option or it was already removed.
+    pub fn remove(&mut self, id: usize) -> Option<String> {
+        let entry = self.entries.get_mut(id)?.take()?;
+        let count = entry.chars.len();
+        let bucket = self.buckets.get_mut(&count).expect("every option is in a bucket");
+        bucket.retain(|other| *other != id);
+        if bucket.is_empty() {
+            self.buckets.remove(&count);
+        }
+        self.len -= 1;
+        Some(entry.value)
+    }
+
+    /// Save the corpus with its precomputed state in a compact binary format
+    ///
+    /// Wrap files in a [`BufWriter`](std::io::BufWriter), the corpus is written in many small pieces.
+    /// The normalizer of a corpus built with [`Corpus::with_normalizer`] can't be saved, only the fact
+    /// that there was one. Such a corpus has to be loaded with [`Corpus::load_with_normalizer`]
+    /// and the same normalizer.
+    /// # Example
+    /// ```
+    /// use similar_string::Corpus;
+    ///
+    /// let mut bytes = vec![];
+    /// Corpus::new(["fill", "night", "ride"]).save(&mut bytes).unwrap();
+    ///
+    /// let corpus = Corpus::load(bytes.as_slice()).unwrap();
+    /// corpus.best_match("fight").unwrap().value; // "night"
+    /// ```
+    pub fn save(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = Writer::new(writer, CORPUS_MAGIC)?;
+        writer.u8(self.normalizer.is_some() as u8)?;
+        writer.usize(self.entries.len())?;
+        for entry in &self.entries {
+            let Some(entry) = entry else {
+                writer.u8(0)?;
+                continue;
+            };
+            writer.u8(1)?;
+            writer.str(&entry.value)?;
+            writer.str(&entry.chars.iter().collect::<String>())?;
+            writer.usize(entry.profile.len())?;
+            for (ngram, count) in &entry.profile {
+                writer.str(ngram)?;
+                writer.usize(*count)?;
+            }
+        }
+        writer.finish()
+    }
+
+    /// Load a corpus saved with [`save`](Corpus::save) without computing its state again
+    ///
+    /// Returns an error of the [`InvalidData`](std::io::ErrorKind::InvalidData) kind if the data
+    /// is not a saved corpus or the corpus was built with a normalizer.
+    /// Wrap files in a [`BufReader`](std::io::BufReader).
+    pub fn load(reader: impl Read) -> io::Result<Self> {
+        Corpus::load_normalized(reader, None)
+    }
+
+    /// Load a corpus built with [`Corpus::with_normalizer`] and saved with [`save`](Corpus::save)
+    ///
+    /// The normalizer has to be the one that the corpus was built with, it normalizes
+    /// the queries and the inserted options. Returns an error of the
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) kind if the data is not a saved corpus
+    /// or the corpus was built without a normalizer.
+    pub fn load_with_normalizer(reader: impl Read, normalizer: Normalizer) -> io::Result<Self> {
+        Corpus::load_normalized(reader, Some(normalizer))
+    }
+
+    fn load_normalized(reader: impl Read, normalizer: Option<Normalizer>) -> io::Result<Self> {
+        let mut reader = Reader::new(reader, CORPUS_MAGIC)?;
+        match (reader.u8()?, &normalizer) {
+            (0, None) | (1, Some(_)) => {}
+            (1, None) => return Err(invalid_data("the corpus was built with a normalizer")),
+            (0, Some(_)) => return Err(invalid_data("the corpus was built without a normalizer")),
+            _ => return Err(invalid_data("not a saved index of this kind"))
+        }
+        let mut corpus = Corpus { entries: vec![], buckets: BTreeMap::new(), len: 0, normalizer };
+        for id in 0..reader.usize()? {
+            if reader.u8()? == 0 {
+                corpus.entries.push(None);
+                continue;
+            }
+            let value = reader.string()?;
+            let chars: Vec<char> = reader.string()?.chars().collect();
+            let mut profile = HashMap::new();
+            for _ in 0..reader.usize()? {
+                let ngram = reader.string()?;
+                profile.insert(ngram, reader.usize()?);
+            }
+            corpus.buckets.entry(chars.len()).or_default().push(id);
+            corpus.entries.push(Some(Entry { value, chars, profile }));
+            corpus.len += 1;
+        }
+        Ok(corpus)
+    }
+
+    /// Entry of an option that is in the corpus
+    fn entry(&self, id: usize) -> &Entry {
+        self.entries[id].as_ref().expect("buckets only hold options of the corpus")
+    }
+
+    /// Groups of options ordered from the highest rating they could have against the query
+    fn buckets_by_bound(&self, length: usize) -> Vec<(f64, &[usize])> {
+        let mut buckets: Vec<(f64, &[usize])> = self.buckets.iter()
+            .map(|(count, ids)| (length_bound(*count, length), ids.as_slice()))
+            .collect();
+        buckets.sort_by(|(left, _), (right, _)| right.total_cmp(left));
+        buckets
+    }
+
+    /// Find the option that is the most similar to the query, ignoring the letter case
+    ///
+    /// The rating is the one of [`compare_similarity`](crate::compare_similarity) of the
+    /// lowercase strings, or of the normalized ones with a normalizer.
+    /// The first option wins a tie and `None` is returned if there are no options.
+    pub fn best_match(&self, query: impl AsRef<str>) -> Option<Match<'_>> {
+        let chars: Vec<char> = self.normalize(query.as_ref()).chars().collect();
+        let masks = PatternMasks::new(&chars);
+        let mut best: Option<(usize, f64)> = None;
+        for (bound, ids) in self.buckets_by_bound(chars.len()) {
+            if best.is_some_and(|(_, score)| bound < score) {
+                break;
+            }
+            for &id in ids {
+                let text = &self.entry(id).chars;
+                let size = text.len().max(chars.len());
+                let high_score = best.map_or(0.0, |(_, score)| score);
+                let min_required = (high_score * size as f64).floor() as usize;
+                let Some(lcs_len) = masks.lcs_length_bounded(text, min_required) else { continue };
+                // Empty strings should match
+                let score = if size == 0 { 1.0 } else { lcs_len as f64 / size as f64 };
+                if best.is_none_or(|(index, high_score)| score > high_score || (score == high_score && id < index)) {
+                    best = Some((id, score));
+                }
+            }
+        }
+        best.map(|(index, score)| Match { index, value: &self.entry(index).value, score })
+    }
+
+    /// Get all the options that rate at least `min_score` against the query, ignoring the letter case
+    ///
+    /// The ratings are the ones of [`best_match`](Corpus::best_match). Groups of options whose
+    /// character count rules out the rating are skipped. The matches keep the order of the options.
+    pub fn matches_above(&self, query: impl AsRef<str>, min_score: f64) -> Vec<Match<'_>> {
+        let chars: Vec<char> = self.normalize(query.as_ref()).chars().collect();
+        let masks = PatternMasks::new(&chars);
+        let mut result = vec![];
+        for (bound, ids) in self.buckets_by_bound(chars.len()) {
+            if bound < min_score {
+                break;
+            }
+            for &id in ids {
+                let text = &self.entry(id).chars;
+                let size = text.len().max(chars.len());
+                let score = if size == 0 { 1.0 } else { masks.lcs_length(text.iter().copied()) as f64 / size as f64 };
+                if score >= min_score {
+                    result.push(Match { index: id, value: &self.entry(id).value, score });
+                }
+            }
+        }
+        result.sort_by_key(|found| found.index);
+        result
+    }
+
+    /// Find the option with the most similar bigram profile, ignoring the letter case
+    ///
+    /// The rating is the one of [`cosine_similarity`](crate::cosine_similarity) of the lowercase
+    /// strings with bigrams, or of the normalized ones with a normalizer.
+    /// The first option wins a tie and `None` is returned if there are no options.
+    pub fn best_match_cosine(&self, query: impl AsRef<str>) -> Option<Match<'_>> {
+        let normalized = self.normalize(query.as_ref());
+        let profile = ngram_profile(&normalized, CORPUS_NGRAM_SIZE);
+        let mut best: Option<Match<'_>> = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            let Some(entry) = entry else { continue };
+            let score = profile_cosine(&profile, &entry.profile);
+            if best.is_none_or(|best| score > best.score) {
+                best = Some(Match { index, value: &entry.value, score });
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn corpus_agrees_with_search() {
+        let options = ["fill", "night", "ride", "light", "", "fight club", "sight", "f"];
+        let corpus = Corpus::new(options);
+        assert_eq!(corpus.len(), 8);
+        for query in ["fight", "", "ni", "FIGHT CLUB", "xyz"] {
+            let lowercase = query.to_lowercase();
+            assert_eq!(corpus.best_match(query), find_best_match(&lowercase, &options), "{}", query);
+            let above: Vec<(usize, f64)> = corpus.matches_above(query, 0.4).iter().map(|found| (found.index, found.score)).collect();
+            assert_eq!(above, find_matches_above(&lowercase, options, 0.4), "{}", query);
+            let cosine = find_best_with(&lowercase, &options, &Cosine(2));
+            assert_eq!(corpus.best_match_cosine(query), cosine, "{}", query);
+        }
+    }
+
+    #[test]
+    fn corpus_insert_and_remove() {
+        let mut corpus = Corpus::new(["fill", "night", "ride"]);
+        assert_eq!(corpus.insert("light"), 3);
+        assert_eq!(corpus.remove(1), Some("night".to_string()));
+        assert_eq!(corpus.remove(1), None);
+        assert_eq!(corpus.remove(10), None);
+        assert_eq!((corpus.len(), corpus.get(1), corpus.get(3)), (3, None, Some("light")));
+        assert_eq!(corpus.best_match("fight"), Some(Match { index: 3, value: "light", score: 0.8 }));
+        assert_eq!(corpus.matches_above("fight", 0.4).iter().map(|found| found.index).collect::<Vec<_>>(), vec![0, 3]);
+        assert_eq!(corpus.best_match_cosine("fight").unwrap().index, 3);
+        for id in [0, 2, 3] {
+            corpus.remove(id);
+        }
+        assert!(corpus.is_empty());
+        assert_eq!(corpus.best_match("fight"), None);
+        assert_eq!(corpus.best_match_cosine("fight"), None);
+        assert_eq!(corpus.insert("night"), 4);
+        assert_eq!(corpus.best_match("fight").unwrap().index, 4);
+    }
+
+    #[test]
+    fn corpus_save_and_load() {
+        let mut corpus = Corpus::new(["Fill", "Night", "ride", "żółw"]);
+        corpus.remove(2);
+        let mut bytes = vec![];
+        corpus.save(&mut bytes).unwrap();
+        let loaded = Corpus::load(bytes.as_slice()).unwrap();
+        assert_eq!((loaded.len(), loaded.get(1), loaded.get(2)), (3, Some("Night"), None));
+        for query in ["fight", "ŻÓŁW", "ride", ""] {
+            assert_eq!(loaded.best_match(query), corpus.best_match(query));
+            assert_eq!(loaded.best_match_cosine(query), corpus.best_match_cosine(query));
+        }
+        assert_eq!(Corpus::load(&bytes[..bytes.len() - 1]).err().unwrap().kind(), std::io::ErrorKind::UnexpectedEof);
+        assert_eq!(Corpus::load(&b"SSTR\x01"[..]).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn corpus_keeps_original_options() {
+        let corpus = Corpus::new(vec!["Fill".to_string(), "Night".to_string()]);
+        assert_eq!(corpus.best_match("NIGHT"), Some(Match { index: 1, value: "Night", score: 1.0 }));
+        assert_eq!(corpus.get(0), Some("Fill"));
+        assert!(Corpus::new(Vec::<String>::new()).best_match("night").is_none());
+        assert!(Corpus::new(Vec::<String>::new()).is_empty());
+    }
+
+    #[test]
+    fn corpus_with_normalizer() {
+        let normalizer = Normalizer::new().lowercase().fold_accents().custom(|text| text.replace("colour", "color"));
+        let options = ["Café Colour", "Thé", "cafe"];
+        let mut corpus = Corpus::with_normalizer(options, normalizer.clone());
+        assert_eq!(corpus.best_match("CAFE COLOR"), Some(Match { index: 0, value: "Café Colour", score: 1.0 }));
+        assert_eq!(corpus.best_match("the"), normalizer.find_best("the", &options));
+        assert_eq!(corpus.matches_above("cafe", 1.0).len(), 1);
+        assert_eq!(corpus.insert("Naïve"), 3);
+        assert_eq!(corpus.best_match("naive").unwrap().score, 1.0);
+        // Case is kept without a lowercase step
+        assert_eq!(Corpus::with_normalizer(["Night"], Normalizer::new()).best_match("night").unwrap().score, 0.8);
+        let mut bytes = vec![];
+        corpus.save(&mut bytes).unwrap();
+        let loaded = Corpus::load_with_normalizer(bytes.as_slice(), normalizer).unwrap();
+        assert_eq!(loaded.best_match("THE COLOUR"), corpus.best_match("THE COLOUR"));
+        assert_eq!(Corpus::load(bytes.as_slice()).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+    }
+}
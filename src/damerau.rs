@@ -0,0 +1,172 @@
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Optimal string alignment distance over arbitrary sequences
+pub(crate) fn osa_slices<T: PartialEq>(left: &[T], right: &[T]) -> usize {
+    let (left, right) = if left.len() < right.len() { (left, right) } else { (right, left) };
+    let mut before: Vec<usize> = vec![0; left.len() + 1];
+    let mut previous: Vec<usize> = (0..=left.len()).collect();
+    let mut current = vec![0; left.len() + 1];
+
+    for (row, rletter) in right.iter().enumerate() {
+        current[0] = row + 1;
+        for (col, lletter) in left.iter().enumerate() {
+            let cost = if rletter == lletter { 0 } else { 1 };
+            current[col + 1] = min(
+                previous[col] + cost,
+                min(previous[col + 1], current[col]) + 1
+            );
+            // Adjacent characters swapped places
+            if row > 0 && col > 0 && *rletter == left[col - 1] && right[row - 1] == *lletter {
+                current[col + 1] = min(current[col + 1], before[col - 1] + 1);
+            }
+        }
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[left.len()]
+}
+
+/// Unrestricted Damerau-Levenshtein distance over arbitrary sequences
+pub(crate) fn damerau_levenshtein_slices<T: Eq + Hash>(left: &[T], right: &[T]) -> usize {
+    let (rows, cols) = (left.len(), right.len());
+    let infinity = rows + cols;
+    // The table is shifted by one row and column to hold the "infinity" border
+    let mut table = vec![vec![0; cols + 2]; rows + 2];
+    let mut last_row: HashMap<&T, usize> = HashMap::new();
+
+    table[0][0] = infinity;
+    for row in 0..=rows {
+        table[row + 1][0] = infinity;
+        table[row + 1][1] = row;
+    }
+    for col in 0..=cols {
+        table[0][col + 1] = infinity;
+        table[1][col + 1] = col;
+    }
+
+    for row in 1..=rows {
+        let mut last_match_col = 0;
+        for col in 1..=cols {
+            let swap_row = *last_row.get(&right[col - 1]).unwrap_or(&0);
+            let swap_col = last_match_col;
+            let cost = if left[row - 1] == right[col - 1] {
+                last_match_col = col;
+                0
+            } else {
+                1
+            };
+            table[row + 1][col + 1] = min(
+                min(table[row][col] + cost, table[row + 1][col] + 1),
+                min(
+                    table[row][col + 1] + 1,
+                    table[swap_row][swap_col] + (row - swap_row - 1) + 1 + (col - swap_col - 1)
+                )
+            );
+        }
+        last_row.insert(&left[row - 1], row);
+    }
+    table[rows + 1][cols + 1]
+}
+
+#[inline]
+fn normalize(distance: usize, left: usize, right: usize) -> f64 {
+    let size = max(left, right);
+    // Empty strings should match
+    if size == 0 { 1.0 } else { 1.0 - distance as f64 / size as f64 }
+}
+
+/// Get the optimal string alignment distance of two strings
+///
+/// Works like the Levenshtein distance but also counts a swap of two adjacent
+/// characters as a single edit. Every substring can be edited at most once,
+/// which is why `"ca"` and `"abc"` are 3 edits apart rather than 2.
+/// # Example
+/// ```
+/// use similar_string::osa_distance;
+///
+/// // The letters "e" and "h" are swapped
+/// osa_distance("teh", "the"); // 1
+/// ```
+pub fn osa_distance(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    osa_slices(&left, &right)
+}
+
+/// Get score of similarity of two strings based on the optimal string alignment distance
+///
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// # Example
+/// ```
+/// use similar_string::normalized_osa;
+///
+/// normalized_osa("teh", "the"); // 0.6666666666666667
+/// ```
+pub fn normalized_osa(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    normalize(osa_slices(&left, &right), left.len(), right.len())
+}
+
+/// Get the Damerau-Levenshtein distance of two strings
+///
+/// Insertions, deletions, substitutions and swaps of two adjacent characters
+/// all count as a single edit. Unlike [`osa_distance`] a substring
+/// may be edited more than once, so `"ca"` and `"abc"` are 2 edits apart.
+/// # Example
+/// ```
+/// use similar_string::damerau_levenshtein_distance;
+///
+/// damerau_levenshtein_distance("ca", "abc"); // 2
+/// ```
+pub fn damerau_levenshtein_distance(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    damerau_levenshtein_slices(&left, &right)
+}
+
+/// Get score of similarity of two strings based on the Damerau-Levenshtein distance
+///
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// # Example
+/// ```
+/// use similar_string::normalized_damerau_levenshtein;
+///
+/// normalized_damerau_levenshtein("ca", "abc"); // 0.33333333333333337
+/// ```
+pub fn normalized_damerau_levenshtein(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    normalize(damerau_levenshtein_slices(&left, &right), left.len(), right.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn osa_counts_transpositions() {
+        assert_eq!(osa_distance("teh", "the"), 1);
+        assert_eq!(osa_distance("ca", "abc"), 3);
+        assert_eq!(osa_distance("", "abc"), 3);
+        assert_eq!(osa_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn damerau_levenshtein_counts_transpositions() {
+        assert_eq!(damerau_levenshtein_distance("teh", "the"), 1);
+        assert_eq!(damerau_levenshtein_distance("ca", "abc"), 2);
+        assert_eq!(damerau_levenshtein_distance("abc", ""), 3);
+        assert_eq!(damerau_levenshtein_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn damerau_normalized_ratings() {
+        assert_eq!(normalized_osa("", ""), 1.0);
+        assert_eq!(normalized_damerau_levenshtein("", ""), 1.0);
+        assert_eq!(normalized_osa("abcd", "abdc"), 0.75);
+        assert_eq!(normalized_damerau_levenshtein("abcd", "abdc"), 0.75);
+    }
+}
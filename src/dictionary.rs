@@ -0,0 +1,227 @@
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+use crate::automaton::LevenshteinAutomaton;
+use crate::persist::invalid_data;
+use crate::search::{best_rated, Match};
+
+/// Magic bytes of a dictionary file
+const DICTIONARY_MAGIC: &[u8; 4] = b"SSDI";
+
+/// Version of the dictionary file format
+const DICTIONARY_VERSION: u8 = 1;
+
+/// Size of the header with the magic bytes, the version and the word count
+const HEADER_SIZE: usize = 13;
+
+/// Read-only sorted dictionary that is searched right in the bytes of its file
+///
+/// The file holds a table of word offsets followed by the sorted words, so the dictionary
+/// doesn't copy the words to the heap. Any bytes can back it, such as a vector read from
+/// the file, and with the `mmap` feature `Dictionary::open` maps the file into memory,
+/// so that only the pages that a search touches are read. Files are written with
+/// [`Dictionary::build`]. Only the header and the bounds of the table of offsets are checked
+/// up front, while the offsets and the UTF-8 of every word are checked when it is read
+/// and broken words are skipped.
+/// # Example
+/// ```
+/// use similar_string::Dictionary;
+///
+/// let mut bytes = vec![];
+/// Dictionary::build(["night", "fill", "ride"], &mut bytes).unwrap();
+///
+/// let dictionary = Dictionary::new(bytes).unwrap();
+/// dictionary.get(0); // Some("fill")
+/// dictionary.find_within("fight", 1); // [("night", 1)]
+/// dictionary.find_best("fight").unwrap().value; // "night"
+/// ```
+pub struct Dictionary<B> {
+    bytes: B,
+    len: usize
+}
+
+/// Read a little-endian `u64` at the given position
+fn read_u64(bytes: &[u8], position: usize) -> u64 {
+    let mut value = [0; 8];
+    value.copy_from_slice(&bytes[position..position + 8]);
+    u64::from_le_bytes(value)
+}
+
+impl Dictionary<Vec<u8>> {
+    /// Write the dictionary file of the words
+    ///
+    /// The words are sorted and duplicates are removed.
+    pub fn build(words: impl IntoIterator<Item = impl AsRef<str>>, mut writer: impl Write) -> io::Result<()> {
+        let words: BTreeSet<String> = words.into_iter().map(|word| word.as_ref().to_string()).collect();
+        writer.write_all(DICTIONARY_MAGIC)?;
+        writer.write_all(&[DICTIONARY_VERSION])?;
+        writer.write_all(&(words.len() as u64).to_le_bytes())?;
+        let mut offset = 0u64;
+        writer.write_all(&offset.to_le_bytes())?;
+        for word in &words {
+            offset += word.len() as u64;
+            writer.write_all(&offset.to_le_bytes())?;
+        }
+        for word in &words {
+            writer.write_all(word.as_bytes())?;
+        }
+        writer.flush()
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl Dictionary<memmap2::Mmap> {
+    /// Map the dictionary file into memory, available with the `mmap` feature
+    ///
+    /// The file must not be changed while it is mapped.
+    pub fn open(path: impl AsRef<std::path::Path>) -> io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: dictionary files are written once and only read afterwards
+        let bytes = unsafe { memmap2::Mmap::map(&file)? };
+        Dictionary::new(bytes)
+    }
+}
+
+impl<B: AsRef<[u8]>> Dictionary<B> {
+    /// Use the bytes of a dictionary file
+    ///
+    /// Returns an error of the [`InvalidData`](std::io::ErrorKind::InvalidData) kind if the bytes
+    /// are not a dictionary file.
+    pub fn new(bytes: B) -> io::Result<Self> {
+        let data = bytes.as_ref();
+        if data.len() < HEADER_SIZE || &data[..4] != DICTIONARY_MAGIC {
+            return Err(invalid_data("not a dictionary file"));
+        }
+        if data[4] != DICTIONARY_VERSION {
+            return Err(invalid_data("unsupported version of the dictionary format"));
+        }
+        let len = usize::try_from(read_u64(data, 5)).map_err(|_| invalid_data("word count doesn't fit in usize"))?;
+        let words_start = len.checked_add(1)
+            .and_then(|count| count.checked_mul(8))
+            .and_then(|size| size.checked_add(HEADER_SIZE))
+            .filter(|start| *start <= data.len())
+            .ok_or_else(|| invalid_data("table of offsets is out of bounds"))?;
+        // The other offsets are checked when their words are read, so that opening touches few pages
+        let (first, last) = (read_u64(data, HEADER_SIZE), read_u64(data, HEADER_SIZE + len * 8));
+        if first != 0 || last > (data.len() - words_start) as u64 {
+            return Err(invalid_data("offset of a word is out of bounds"));
+        }
+        Ok(Dictionary { bytes, len })
+    }
+
+    /// Get the number of words
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the dictionary has no words
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the word at the given position in sorted order
+    ///
+    /// Returns `None` if there is no such word, its offsets are out of bounds or it isn't valid UTF-8.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        if index >= self.len {
+            return None;
+        }
+        let data = self.bytes.as_ref();
+        let words = &data[HEADER_SIZE + (self.len + 1) * 8..];
+        let start = usize::try_from(read_u64(data, HEADER_SIZE + index * 8)).ok()?;
+        let end = usize::try_from(read_u64(data, HEADER_SIZE + (index + 1) * 8)).ok()?;
+        std::str::from_utf8(words.get(start..end)?).ok()
+    }
+
+    /// Iterate over the words in sorted order
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        (0..self.len).filter_map(|index| self.get(index))
+    }
+
+    /// Check whether the dictionary has the word, with a binary search
+    pub fn contains(&self, word: impl AsRef<str>) -> bool {
+        let word = word.as_ref();
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.get(middle) {
+                Some(other) if other < word => low = middle + 1,
+                Some(other) if other == word => return true,
+                _ => high = middle
+            }
+        }
+        false
+    }
+
+    /// Get all the words within `max_dist` edits of the query together with their distances
+    ///
+    /// The words are searched with a [`LevenshteinAutomaton`], which skips the words that
+    /// share a rejected prefix. The results keep the sorted order.
+    pub fn find_within(&self, query: impl AsRef<str>, max_dist: usize) -> Vec<(&str, usize)> {
+        LevenshteinAutomaton::new(query, max_dist).find_in_sorted(self.iter())
+    }
+
+    /// Find the word that is the most similar to the query
+    ///
+    /// Works like [`find_best_match`](crate::find_best_match), the index of the match is
+    /// the position of the word amongst the valid words. Returns `None` if there are no words.
+    pub fn find_best(&self, query: impl AsRef<str>) -> Option<Match<'_>> {
+        best_rated(query.as_ref(), self.iter()).map(|(index, value, score)| Match { index, value, score })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn dictionary_works() {
+        let mut bytes = vec![];
+        Dictionary::build(["night", "fill", "ride", "light", "fill", "żółw"], &mut bytes).unwrap();
+        let dictionary = Dictionary::new(bytes.as_slice()).unwrap();
+        assert_eq!(dictionary.len(), 5);
+        assert_eq!(dictionary.iter().collect::<Vec<_>>(), vec!["fill", "light", "night", "ride", "żółw"]);
+        assert_eq!(dictionary.get(5), None);
+        assert!(dictionary.contains("night") && dictionary.contains("żółw"));
+        assert!(!dictionary.contains("fight") && !dictionary.contains(""));
+        assert_eq!(dictionary.find_within("fight", 1), vec![("light", 1), ("night", 1)]);
+        assert_eq!(dictionary.find_best("fight"), Some(Match { index: 1, value: "light", score: 0.8 }));
+    }
+
+    #[test]
+    fn dictionary_checks_bytes() {
+        let mut bytes = vec![];
+        Dictionary::build(Vec::<String>::new(), &mut bytes).unwrap();
+        let empty = Dictionary::new(bytes).unwrap();
+        assert!(empty.is_empty() && empty.find_best("fight").is_none());
+
+        let mut bytes = vec![];
+        Dictionary::build(["fill", "night"], &mut bytes).unwrap();
+        assert!(Dictionary::new(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Dictionary::new(&bytes[..12]).is_err());
+        assert!(Dictionary::new(&b"SSCO\x01"[..]).is_err());
+        // A word that isn't valid UTF-8 is skipped
+        let last = bytes.len() - 1;
+        bytes[last] = 0xff;
+        let dictionary = Dictionary::new(bytes).unwrap();
+        assert_eq!((dictionary.get(1), dictionary.iter().collect::<Vec<_>>()), (None, vec!["fill"]));
+        // So is a word whose offsets are out of bounds
+        let mut bytes = vec![];
+        Dictionary::build(["fill", "night", "ride"], &mut bytes).unwrap();
+        bytes[13 + 8..13 + 16].copy_from_slice(&100u64.to_le_bytes());
+        let dictionary = Dictionary::new(bytes).unwrap();
+        assert_eq!(dictionary.iter().collect::<Vec<_>>(), vec!["ride"]);
+        assert_eq!((dictionary.get(0), dictionary.get(1)), (None, None));
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn dictionary_from_file() {
+        let path = std::env::temp_dir().join(format!("similar-string-{}.dict", std::process::id()));
+        Dictionary::build(["fill", "night", "ride"], std::fs::File::create(&path).unwrap()).unwrap();
+        let dictionary = Dictionary::open(&path).unwrap();
+        assert_eq!(dictionary.find_best("fight").unwrap().value, "night");
+        drop(dictionary);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::lcs::lcs_pairs;
+
+/// A single operation of an edit script that turns the left string into the right one
+///
+/// All indices and lengths are counted in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EditOp {
+    /// Characters `left_index..left_index + len` of the left string
+    /// are equal to `right_index..right_index + len` of the right string
+    Equal {
+        /// Index of the first character in the left string
+        left_index: usize,
+        /// Index of the first character in the right string
+        right_index: usize,
+        /// Number of characters
+        len: usize
+    },
+    /// Characters `left_index..left_index + len` of the left string are removed
+    Delete {
+        /// Index of the first removed character in the left string
+        left_index: usize,
+        /// Position in the right string where the characters would have been
+        right_index: usize,
+        /// Number of characters
+        len: usize
+    },
+    /// Characters `right_index..right_index + len` of the right string are inserted
+    Insert {
+        /// Position in the left string where the characters are inserted
+        left_index: usize,
+        /// Index of the first inserted character in the right string
+        right_index: usize,
+        /// Number of characters
+        len: usize
+    }
+}
+
+impl EditOp {
+    /// Range of characters of the left string covered by this operation
+    ///
+    /// The range is empty for insertions.
+    pub fn left_range(&self) -> Range<usize> {
+        match *self {
+            EditOp::Equal { left_index, len, .. } | EditOp::Delete { left_index, len, .. } => left_index..left_index + len,
+            EditOp::Insert { left_index, .. } => left_index..left_index
+        }
+    }
+
+    /// Range of characters of the right string covered by this operation
+    ///
+    /// The range is empty for deletions.
+    pub fn right_range(&self) -> Range<usize> {
+        match *self {
+            EditOp::Equal { right_index, len, .. } | EditOp::Insert { right_index, len, .. } => right_index..right_index + len,
+            EditOp::Delete { right_index, .. } => right_index..right_index
+        }
+    }
+}
+
+/// Build an edit script over arbitrary sequences from their longest common subsequence
+pub(crate) fn edit_ops_slices<T: PartialEq>(left: &[T], right: &[T]) -> Vec<EditOp> {
+    let mut ops: Vec<EditOp> = vec![];
+    let (mut row, mut col) = (0, 0);
+    let sentinel = (left.len(), right.len());
+    for (next_row, next_col) in lcs_pairs(left, right).into_iter().chain(std::iter::once(sentinel)) {
+        if next_row > row {
+            ops.push(EditOp::Delete { left_index: row, right_index: col, len: next_row - row });
+        }
+        if next_col > col {
+            ops.push(EditOp::Insert { left_index: next_row, right_index: col, len: next_col - col });
+        }
+        if (next_row, next_col) == sentinel {
+            break;
+        }
+        match ops.last_mut() {
+            Some(EditOp::Equal { len, .. }) => *len += 1,
+            _ => ops.push(EditOp::Equal { left_index: next_row, right_index: next_col, len: 1 })
+        }
+        (row, col) = (next_row + 1, next_col + 1);
+    }
+    ops
+}
+
+/// Get the edit script that turns the left string into the right one
+///
+/// The script is derived from the longest common subsequence of both strings.
+/// Consecutive operations of the same kind are merged into ranges and
+/// within every changed region deletions come before insertions.
+/// # Example
+/// ```
+/// use similar_string::*;
+///
+/// edit_ops("fight", "night");
+/// // [
+/// //     Delete { left_index: 0, right_index: 0, len: 1 },
+/// //     Insert { left_index: 1, right_index: 0, len: 1 },
+/// //     Equal { left_index: 1, right_index: 1, len: 4 }
+/// // ]
+/// ```
+pub fn edit_ops(left: impl AsRef<str>, right: impl AsRef<str>) -> Vec<EditOp> {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    edit_ops_slices(&left, &right)
+}
+
+/// Format a hunk range the same way as GNU diff and Python's difflib
+fn hunk_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, len)
+    }
+}
+
+/// Get the differences of two multi-line texts in the unified diff format
+///
+/// Lines are compared with the longest common subsequence algorithm and the changes
+/// are grouped into `@@` hunks surrounded by up to `context` unchanged lines.
+/// The output contains no `---` and `+++` file headers, so that callers can name
+/// the compared texts themselves. Equal texts produce an empty string.
+/// # Example
+/// ```
+/// use similar_string::unified_diff;
+///
+/// let diff = unified_diff("one\ntwo\nthree\n", "one\n2\nthree\n", 1);
+/// // @@ -1,3 +1,3 @@
+/// //  one
+/// // -two
+/// // +2
+/// //  three
+/// ```
+pub fn unified_diff(left: impl AsRef<str>, right: impl AsRef<str>, context: usize) -> String {
+    let left: Vec<&str> = left.as_ref().split_inclusive('\n').collect();
+    let right: Vec<&str> = right.as_ref().split_inclusive('\n').collect();
+
+    // Every line of the diff with its kind and positions in both texts
+    let mut lines: Vec<(char, usize, usize)> = vec![];
+    for op in edit_ops_slices(&left, &right) {
+        match op {
+            EditOp::Equal { left_index, right_index, len } => {
+                lines.extend((0..len).map(|offset| (' ', left_index + offset, right_index + offset)));
+            }
+            EditOp::Delete { left_index, right_index, len } => {
+                lines.extend((0..len).map(|offset| ('-', left_index + offset, right_index)));
+            }
+            EditOp::Insert { left_index, right_index, len } => {
+                lines.extend((0..len).map(|offset| ('+', left_index, right_index + offset)));
+            }
+        }
+    }
+    let changes: Vec<usize> = lines.iter()
+        .enumerate()
+        .filter(|(_, (kind, _, _))| *kind != ' ')
+        .map(|(index, _)| index)
+        .collect();
+
+    // Changes separated by at most twice the context share a hunk
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for change in changes {
+        match hunks.last_mut() {
+            Some((_, last)) if change - *last <= 2 * context + 1 => *last = change,
+            _ => hunks.push((change, change))
+        }
+    }
+
+    let mut result = String::new();
+    for (first, last) in hunks {
+        let start = first.saturating_sub(context);
+        let end = (last + context + 1).min(lines.len());
+        let hunk = &lines[start..end];
+        let left_len = hunk.iter().filter(|(kind, _, _)| *kind != '+').count();
+        let right_len = hunk.iter().filter(|(kind, _, _)| *kind != '-').count();
+        let (_, left_start, right_start) = hunk[0];
+        writeln!(result, "@@ -{} +{} @@", hunk_range(left_start, left_len), hunk_range(right_start, right_len)).unwrap();
+        for (kind, left_index, right_index) in hunk {
+            let line = if *kind == '+' { right[*right_index] } else { left[*left_index] };
+            result.push(*kind);
+            result.push_str(line);
+            if !line.ends_with('\n') {
+                result.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn edit_ops_works() {
+        assert_eq!(edit_ops("fight", "night"), vec![
+            EditOp::Delete { left_index: 0, right_index: 0, len: 1 },
+            EditOp::Insert { left_index: 1, right_index: 0, len: 1 },
+            EditOp::Equal { left_index: 1, right_index: 1, len: 4 }
+        ]);
+        assert_eq!(edit_ops("abc", "abc"), vec![EditOp::Equal { left_index: 0, right_index: 0, len: 3 }]);
+        assert_eq!(edit_ops("", "ab"), vec![EditOp::Insert { left_index: 0, right_index: 0, len: 2 }]);
+        assert_eq!(edit_ops("ab", ""), vec![EditOp::Delete { left_index: 0, right_index: 0, len: 2 }]);
+        assert!(edit_ops("", "").is_empty());
+    }
+
+    #[test]
+    fn unified_diff_works() {
+        let diff = unified_diff("one\ntwo\nthree\n", "one\n2\nthree\n", 1);
+        assert_eq!(diff, "@@ -1,3 +1,3 @@\n one\n-two\n+2\n three\n");
+        assert_eq!(unified_diff("same\n", "same\n", 3), "");
+    }
+
+    #[test]
+    fn unified_diff_hunks() {
+        let left = "a\nb\nc\nd\ne\nf\ng\nh\ni\n";
+        let right = "A\nb\nc\nd\ne\nf\ng\nh\nI\n";
+        let diff = unified_diff(left, right, 2);
+        assert_eq!(diff, "@@ -1,3 +1,3 @@\n-a\n+A\n b\n c\n@@ -7,3 +7,3 @@\n g\n h\n-i\n+I\n");
+        // Close changes are merged into a single hunk
+        let diff = unified_diff(left, right, 4);
+        assert!(diff.starts_with("@@ -1,9 +1,9 @@\n"));
+    }
+
+    #[test]
+    fn unified_diff_edge_cases() {
+        assert_eq!(unified_diff("", "new\n", 3), "@@ -0,0 +1 @@\n+new\n");
+        assert_eq!(unified_diff("a\nb", "a\nc", 3), "@@ -1,2 +1,2 @@\n a\n-b\n\\ No newline at end of file\n+c\n\\ No newline at end of file\n");
+    }
+
+    #[test]
+    fn edit_ops_rebuild_strings() {
+        let (left, right) = ("the quick brown fox", "a quick brawn box!");
+        let left_chars: Vec<char> = left.chars().collect();
+        let right_chars: Vec<char> = right.chars().collect();
+        let (mut rebuilt_left, mut rebuilt_right) = (String::new(), String::new());
+        for op in edit_ops(left, right) {
+            rebuilt_left.extend(&left_chars[op.left_range()]);
+            rebuilt_right.extend(&right_chars[op.right_range()]);
+            if let EditOp::Equal { .. } = op {
+                assert_eq!(left_chars[op.left_range()], right_chars[op.right_range()]);
+            }
+        }
+        assert_eq!(rebuilt_left, left);
+        assert_eq!(rebuilt_right, right);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn edit_ops_serde() {
+        let ops = edit_ops("fight", "night");
+        let json = serde_json::to_string(&ops).unwrap();
+        assert!(json.starts_with(r#"[{"Delete":{"left_index":0,"right_index":0,"len":1}}"#));
+        assert_eq!(serde_json::from_str::<Vec<EditOp>>(&json).unwrap(), ops);
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+use crate::metric::SimilarityMetric;
+
+/// Rating of one metric of an [`Ensemble`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MetricScore<'a> {
+    /// Name the metric was registered with
+    pub name: &'a str,
+    /// Weight of the metric
+    pub weight: f64,
+    /// Rating of the metric before weighting
+    pub score: f64
+}
+
+/// Blended rating of an [`Ensemble`] together with the ratings of every metric
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EnsembleScore<'a> {
+    /// Weighted average of the ratings of all the metrics
+    pub score: f64,
+    /// Ratings of the metrics in the order they were added
+    pub metrics: Vec<MetricScore<'a>>
+}
+
+/// Weighted combination of several similarity metrics
+///
+/// The blended rating is the weighted average of the ratings of all the metrics,
+/// so it stays in range from 0.0 to 1.0. An ensemble without metrics or with zero total
+/// weight rates every pair of strings 0.0.
+/// # Example
+/// ```
+/// use similar_string::{Ensemble, JaroWinkler, Lcs, Levenshtein, SimilarityMetric};
+///
+/// let ensemble = Ensemble::new()
+///     .with("lcs", 2.0, Lcs)
+///     .with("levenshtein", 1.0, Levenshtein)
+///     .with("jaro-winkler", 1.0, JaroWinkler);
+///
+/// ensemble.similarity("age", "page"); // 0.7916666666666666
+/// let breakdown = ensemble.breakdown("age", "page");
+/// breakdown.metrics[0].score; // 0.75
+/// ```
+#[derive(Default)]
+pub struct Ensemble {
+    metrics: Vec<(String, f64, Box<dyn SimilarityMetric + Send + Sync>)>
+}
+
+impl Ensemble {
+    /// Create an ensemble without metrics
+    pub fn new() -> Self {
+        Ensemble::default()
+    }
+
+    /// Add a metric with the given name and weight
+    ///
+    /// # Panics
+    /// Panics if the weight is negative or not finite.
+    pub fn with(mut self, name: impl Into<String>, weight: f64, metric: impl SimilarityMetric + Send + Sync + 'static) -> Self {
+        assert!(weight.is_finite() && weight >= 0.0, "metric weight must be a non-negative number");
+        self.metrics.push((name.into(), weight, Box::new(metric)));
+        self
+    }
+
+    /// Number of metrics of the ensemble
+    pub fn len(&self) -> usize {
+        self.metrics.len()
+    }
+
+    /// Check whether the ensemble has no metrics
+    pub fn is_empty(&self) -> bool {
+        self.metrics.is_empty()
+    }
+
+    /// Get the blended rating together with the ratings of every metric
+    pub fn breakdown(&self, left: impl AsRef<str>, right: impl AsRef<str>) -> EnsembleScore<'_> {
+        let (left, right) = (left.as_ref(), right.as_ref());
+        let metrics: Vec<MetricScore> = self.metrics.iter()
+            .map(|(name, weight, metric)| MetricScore {
+                name,
+                weight: *weight,
+                score: metric.similarity(left, right)
+            })
+            .collect();
+        let total: f64 = metrics.iter().map(|metric| metric.weight).sum();
+        let score = if total > 0.0 {
+            metrics.iter().map(|metric| metric.weight * metric.score).sum::<f64>() / total
+        } else {
+            0.0
+        };
+        EnsembleScore { score, metrics }
+    }
+}
+
+impl SimilarityMetric for Ensemble {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        self.breakdown(left, right).score
+    }
+}
+
+impl fmt::Debug for Ensemble {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.metrics.iter().map(|(name, weight, _)| (name, weight)))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn ensemble_blends_metrics() {
+        let ensemble = Ensemble::new().with("lcs", 3.0, Lcs).with("levenshtein", 1.0, Levenshtein);
+        let breakdown = ensemble.breakdown("kitten", "sitting");
+        let lcs = compare_similarity("kitten", "sitting");
+        let levenshtein = normalized_levenshtein("kitten", "sitting");
+        assert_eq!(breakdown.score, (3.0 * lcs + levenshtein) / 4.0);
+        assert_eq!(breakdown.metrics[1], MetricScore { name: "levenshtein", weight: 1.0, score: levenshtein });
+        assert_eq!(ensemble.similarity("kitten", "sitting"), breakdown.score);
+        assert_eq!(ensemble.len(), 2);
+    }
+
+    #[test]
+    fn empty_ensemble() {
+        assert_eq!(Ensemble::new().similarity("a", "a"), 0.0);
+        assert_eq!(Ensemble::new().with("lcs", 0.0, Lcs).similarity("a", "a"), 0.0);
+        assert!(Ensemble::new().is_empty());
+    }
+
+    #[test]
+    fn ensemble_is_a_metric() {
+        let ensemble = Ensemble::new().with("custom", 1.0, |left: &str, right: &str| if left == right { 1.0 } else { 0.0 });
+        assert_eq!(find_best_with("night", ["fight", "night"], &ensemble).unwrap().index, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_weight() {
+        let _ = Ensemble::new().with("lcs", -1.0, Lcs);
+    }
+}
@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::normalizer::{Preprocess, preprocessed_best, preprocessed_ratings};
+use crate::search::Match;
+
+/// Classes of strings that are considered equal when comparing
+///
+/// Every member of a class is replaced with the first member of the class before
+/// the strings are compared, so the members match each other. Members can be longer
+/// than a single character, the longest member wins when several of them match.
+/// # Example
+/// ```
+/// use similar_string::Equivalences;
+///
+/// let equivalences = Equivalences::new()
+///     .class(["O", "0"])
+///     .class(["I", "1", "l"])
+///     .class(["ß", "ss"]);
+///
+/// equivalences.apply("B00K 1"); // "BOOK I"
+/// equivalences.compare("Straße", "Strasse"); // 1.0
+/// equivalences.compare("PL 1O0", "PL IOO"); // 1.0
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Equivalences {
+    // Members and their replacements by the first character, the longest members come first
+    members: HashMap<char, Vec<(String, String)>>
+}
+
+impl Equivalences {
+    /// Create an empty set of equivalence classes
+    pub fn new() -> Self {
+        Equivalences::default()
+    }
+
+    /// Add a class of strings that are all equal to each other
+    ///
+    /// A member that already belongs to another class is moved to this one.
+    /// Empty members are ignored.
+    pub fn class<S: AsRef<str>>(mut self, members: impl IntoIterator<Item = S>) -> Self {
+        let mut members = members.into_iter();
+        let Some(canonical) = members.next() else {
+            return self;
+        };
+        let canonical = canonical.as_ref().to_string();
+        for member in members {
+            let member = member.as_ref();
+            let Some(first) = member.chars().next() else {
+                continue;
+            };
+            let candidates = self.members.entry(first).or_default();
+            candidates.retain(|(existing, _)| existing != member);
+            candidates.push((member.to_string(), canonical.clone()));
+            candidates.sort_by_key(|(member, _)| std::cmp::Reverse(member.len()));
+        }
+        self
+    }
+
+    /// Check whether there are no classes
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Replace every member of a class with the first member of the class
+    pub fn apply(&self, text: &str) -> String {
+        self.preprocess(text).into_owned()
+    }
+
+    /// Get score of similarity of two strings with equal members, see [`compare_similarity`](crate::compare_similarity)
+    pub fn compare(&self, left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+        crate::compare_similarity(self.preprocess(left.as_ref()), self.preprocess(right.as_ref()))
+    }
+
+    /// Get similarity ratings of all the options with equal members
+    pub fn ratings(&self, target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<f64> {
+        preprocessed_ratings(self, target.as_ref(), options)
+    }
+
+    /// Find the option that is the most similar to the target string with equal members
+    ///
+    /// The match holds the original option. The first option wins a tie
+    /// and `None` is returned if there are no options.
+    pub fn find_best<'a, S: AsRef<str> + ?Sized + 'a>(&self, target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>) -> Option<Match<'a>> {
+        preprocessed_best(self, target.as_ref(), options)
+    }
+
+    /// Member that starts the text and its replacement
+    fn member_at(&self, text: &str) -> Option<&(String, String)> {
+        let first = text.chars().next()?;
+        self.members.get(&first)?
+            .iter()
+            .find(|(member, _)| text.starts_with(member.as_str()))
+    }
+}
+
+impl Preprocess for Equivalences {
+    fn preprocess<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !text.chars().any(|letter| self.members.contains_key(&letter)) {
+            return Cow::Borrowed(text);
+        }
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(letter) = rest.chars().next() {
+            match self.member_at(rest) {
+                Some((member, canonical)) => {
+                    result.push_str(canonical);
+                    rest = &rest[member.len()..];
+                }
+                None => {
+                    result.push(letter);
+                    rest = &rest[letter.len_utf8()..];
+                }
+            }
+        }
+        Cow::Owned(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn members_are_replaced() {
+        let equivalences = Equivalences::new().class(["O", "0"]).class(["I", "1", "l"]).class(["ß", "ss"]);
+        assert_eq!(equivalences.apply("B00K 1l"), "BOOK II");
+        assert_eq!(equivalences.apply("Strasse"), "Straße");
+        assert_eq!(equivalences.apply("plain"), "pIain");
+        assert!(Equivalences::new().is_empty());
+    }
+
+    #[test]
+    fn longest_member_wins() {
+        let equivalences = Equivalences::new().class(["m", "rn"]).class(["n", "r"]);
+        assert_eq!(equivalences.apply("rnr"), "mn");
+        let moved = Equivalences::new().class(["a", "x"]).class(["b", "x"]);
+        assert_eq!(moved.apply("x"), "b");
+    }
+
+    #[test]
+    fn equivalences_wrap_search() {
+        let equivalences = Equivalences::new().class(["O", "0"]);
+        assert_eq!(equivalences.compare("R0B0T", "ROBOT"), 1.0);
+        assert_eq!(equivalences.ratings("G0", ["GO", "NO"]), vec![1.0, 0.5]);
+        assert_eq!(equivalences.find_best("B00", &["BIN", "BOO"]).unwrap().index, 1);
+    }
+}
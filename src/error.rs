@@ -0,0 +1,76 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Error returned when a metric requires strings of equal length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthMismatchError {
+    /// Character count of the left string
+    pub left: usize,
+    /// Character count of the right string
+    pub right: usize
+}
+
+impl fmt::Display for LengthMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "strings have different lengths ({} and {} characters)", self.left, self.right)
+    }
+}
+
+impl core::error::Error for LengthMismatchError {}
+
+/// Error returned when there is no metric with the given name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownMetricError {
+    /// The name that was looked up
+    pub name: String
+}
+
+impl fmt::Display for UnknownMetricError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown metric \"{}\"", self.name)
+    }
+}
+
+impl core::error::Error for UnknownMetricError {}
+
+/// Error returned when comparing the inputs would take more work than the budget allows
+///
+/// The work is counted in pairs of characters that have to be compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetExceededError {
+    /// Work that the inputs need, counted up to the point where the budget ran out
+    pub work: usize,
+    /// The budget that was allowed
+    pub budget: usize
+}
+
+impl fmt::Display for BudgetExceededError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "comparison needs at least {} character pairs but the budget is {}", self.work, self.budget)
+    }
+}
+
+impl core::error::Error for BudgetExceededError {}
+
+/// Error returned when a string is not similar enough to any variant of an enum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVariantError {
+    /// The string that was parsed
+    pub input: String,
+    /// Names of all the variants
+    pub expected: Vec<&'static str>
+}
+
+impl fmt::Display for UnknownVariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown variant \"{}\", expected one of ", self.input)?;
+        for (index, name) in self.expected.iter().enumerate() {
+            let separator = if index == 0 { "" } else { ", " };
+            write!(f, "{}\"{}\"", separator, name)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for UnknownVariantError {}
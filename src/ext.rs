@@ -0,0 +1,92 @@
+use crate::{compare_similarity, lcs_length, find_best_match, find_matches_above, Match};
+use crate::search::rated_options;
+
+/// Method syntax for the similarity of strings
+///
+/// Implemented for everything that can be viewed as a string.
+/// # Example
+/// ```
+/// use similar_string::SimilarityExt;
+///
+/// "age".similarity("page"); // 0.75
+/// "age".lcs_len("page"); // 3
+/// ```
+pub trait SimilarityExt {
+    /// Get score of similarity with the other string, see [`compare_similarity`]
+    fn similarity(&self, other: impl AsRef<str>) -> f64;
+
+    /// Get length of the longest common subsequence with the other string, see [`lcs_length`]
+    fn lcs_len(&self, other: impl AsRef<str>) -> usize;
+}
+
+impl<T: AsRef<str> + ?Sized> SimilarityExt for T {
+    fn similarity(&self, other: impl AsRef<str>) -> f64 {
+        compare_similarity(self.as_ref(), other)
+    }
+
+    fn lcs_len(&self, other: impl AsRef<str>) -> usize {
+        lcs_length(self.as_ref(), other)
+    }
+}
+
+/// Method syntax for searching a slice of strings
+///
+/// Implemented for slices of everything that can be viewed as a string.
+/// # Example
+/// ```
+/// use similar_string::SliceSimilarityExt;
+///
+/// let options = vec!["fill", "night", "ride"];
+///
+/// options.best_match("fight").unwrap().value; // "night"
+/// options.ratings_against("fight"); // [0.4, 0.8, 0.2]
+/// options.matches_above("fight", 0.7); // [(1, 0.8)]
+/// ```
+pub trait SliceSimilarityExt {
+    /// Find the most similar option, see [`find_best_match`]
+    fn best_match(&self, target: impl AsRef<str>) -> Option<Match<'_>>;
+
+    /// Get similarity ratings of all the options in their order
+    fn ratings_against(&self, target: impl AsRef<str>) -> Vec<f64>;
+
+    /// Get indices and ratings of the options that score at least `min_score`, see [`find_matches_above`]
+    fn matches_above(&self, target: impl AsRef<str>, min_score: f64) -> Vec<(usize, f64)>;
+}
+
+impl<S: AsRef<str>> SliceSimilarityExt for [S] {
+    fn best_match(&self, target: impl AsRef<str>) -> Option<Match<'_>> {
+        find_best_match(target, self)
+    }
+
+    fn ratings_against(&self, target: impl AsRef<str>) -> Vec<f64> {
+        rated_options(target.as_ref(), self).map(|(_, _, score)| score).collect()
+    }
+
+    fn matches_above(&self, target: impl AsRef<str>, min_score: f64) -> Vec<(usize, f64)> {
+        find_matches_above(target, self, min_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn string_ext_works() {
+        assert_eq!("age".similarity("page"), 0.75);
+        assert_eq!("age".to_string().lcs_len("page"), 3);
+        let scores: Vec<f64> = ["fill", "night"].iter().map(|option| option.similarity("fight")).collect();
+        assert_eq!(scores, vec![0.4, 0.8]);
+    }
+
+    #[test]
+    fn slice_ext_works() {
+        let options: Vec<String> = ["fill", "night", "ride"].iter().map(|option| option.to_string()).collect();
+        assert_eq!(options.best_match("fight").unwrap().index, 1);
+        assert_eq!(options.ratings_against("fight"), vec![0.4, 0.8, 0.2]);
+        assert_eq!(options.matches_above("fight", 0.7), vec![(1, 0.8)]);
+        let empty: [&str; 0] = [];
+        assert_eq!(empty.best_match("fight"), None);
+        assert!(empty.ratings_against("fight").is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+//! C bindings of the ratings, the searches and the corpus
+//!
+//! Strings are NUL-terminated and UTF-8 encoded. Functions that get a null pointer or a string
+//! that is not valid UTF-8 return a negative rating or `false`. A [`Corpus`] is passed around
+//! as an opaque [`SsCorpus`] handle that is created with [`ss_corpus_new`] and destroyed with [`ss_corpus_free`].
+//!
+//! This module is available with the `ffi` feature. The library is built as a dynamic or static
+//! library and the header `include/similar_string.h` is generated with cbindgen:
+//! ```sh
+//! cargo rustc --lib --release --features ffi --crate-type cdylib
+//! cbindgen --config cbindgen.toml --output include/similar_string.h
+//! ```
+//! # Example
+//! ```c
+//! #include "similar_string.h"
+//!
+//! const char *options[] = {"fill", "night", "ride"};
+//! SsMatch best;
+//!
+//! ss_compare_similarity("age", "page"); // 0.75
+//! if (ss_find_best_match("fight", options, 3, &best)) {
+//!     // best.index == 1, best.score == 0.8
+//! }
+//! ```
+
+use std::ffi::{c_char, CStr};
+use std::slice;
+
+use crate::search::Match;
+use crate::Corpus;
+
+/// Opaque handle of a [`Corpus`]
+pub struct SsCorpus(Corpus);
+
+/// Option that matched the target string
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SsMatch {
+    /// Position of the option amongst the options, or its id in a corpus
+    pub index: usize,
+    /// Similarity rating of the option
+    pub score: f64
+}
+
+impl From<Match<'_>> for SsMatch {
+    fn from(found: Match<'_>) -> Self {
+        SsMatch { index: found.index, score: found.score }
+    }
+}
+
+/// Borrow a C string, `None` if it is null or not valid UTF-8
+unsafe fn to_str<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        return None;
+    }
+    CStr::from_ptr(string).to_str().ok()
+}
+
+/// Borrow an array of C strings, `None` if any of them is null or not valid UTF-8
+unsafe fn to_strs<'a>(strings: *const *const c_char, len: usize) -> Option<Vec<&'a str>> {
+    if len == 0 {
+        return Some(vec![]);
+    }
+    if strings.is_null() {
+        return None;
+    }
+    slice::from_raw_parts(strings, len).iter().map(|string| to_str(*string)).collect()
+}
+
+/// Write the match to `out` and tell whether there was one
+unsafe fn write_match(found: Option<Match<'_>>, out: *mut SsMatch) -> bool {
+    match found {
+        Some(found) if !out.is_null() => {
+            *out = found.into();
+            true
+        }
+        _ => false
+    }
+}
+
+/// Get score of similarity of two strings, see [`compare_similarity`](crate::compare_similarity)
+///
+/// Returns -1.0 if any of the strings is null or not valid UTF-8.
+/// # Safety
+/// The strings have to be null or point to NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn ss_compare_similarity(left: *const c_char, right: *const c_char) -> f64 {
+    match (to_str(left), to_str(right)) {
+        (Some(left), Some(right)) => crate::compare_similarity(left, right),
+        _ => -1.0
+    }
+}
+
+/// Get the Levenshtein edit distance of two strings, see [`levenshtein_distance`](crate::levenshtein_distance)
+///
+/// Returns -1 if any of the strings is null or not valid UTF-8.
+/// # Safety
+/// The strings have to be null or point to NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn ss_levenshtein_distance(left: *const c_char, right: *const c_char) -> isize {
+    match (to_str(left), to_str(right)) {
+        (Some(left), Some(right)) => crate::levenshtein_distance(left, right) as isize,
+        _ => -1
+    }
+}
+
+/// Find the option that is the most similar to the target string, see [`find_best_match`](crate::find_best_match)
+///
+/// Writes the match to `out` and returns `true`, or returns `false` if there are no options
+/// or any of the strings is null or not valid UTF-8.
+/// # Safety
+/// `options` has to point to `len` strings, all the strings have to be null or NUL-terminated
+/// and `out` has to be null or point to a writable [`SsMatch`].
+#[no_mangle]
+pub unsafe extern "C" fn ss_find_best_match(target: *const c_char, options: *const *const c_char, len: usize, out: *mut SsMatch) -> bool {
+    let (Some(target), Some(options)) = (to_str(target), to_strs(options, len)) else {
+        return false;
+    };
+    write_match(crate::find_best_match(target, options), out)
+}
+
+/// Create a corpus of the options, see [`Corpus::new`]
+///
+/// Returns null if any of the strings is null or not valid UTF-8.
+/// The corpus has to be destroyed with [`ss_corpus_free`].
+/// # Safety
+/// `options` has to point to `len` strings that are null or NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn ss_corpus_new(options: *const *const c_char, len: usize) -> *mut SsCorpus {
+    match to_strs(options, len) {
+        Some(options) => Box::into_raw(Box::new(SsCorpus(Corpus::new(options)))),
+        None => std::ptr::null_mut()
+    }
+}
+
+/// Destroy a corpus created with [`ss_corpus_new`], null is ignored
+/// # Safety
+/// The corpus has to be null or created with [`ss_corpus_new`] and not destroyed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ss_corpus_free(corpus: *mut SsCorpus) {
+    if !corpus.is_null() {
+        drop(Box::from_raw(corpus));
+    }
+}
+
+/// Get the number of options of the corpus, zero for null
+/// # Safety
+/// The corpus has to be null or a live corpus.
+#[no_mangle]
+pub unsafe extern "C" fn ss_corpus_len(corpus: *const SsCorpus) -> usize {
+    corpus.as_ref().map_or(0, |corpus| corpus.0.len())
+}
+
+/// Add an option to the corpus and get its id, see [`Corpus::insert`]
+///
+/// Returns -1 if the corpus or the option is null or the option is not valid UTF-8.
+/// # Safety
+/// The corpus has to be null or a live corpus and the option null or NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn ss_corpus_insert(corpus: *mut SsCorpus, option: *const c_char) -> isize {
+    match (corpus.as_mut(), to_str(option)) {
+        (Some(corpus), Some(option)) => corpus.0.insert(option) as isize,
+        _ => -1
+    }
+}
+
+/// Remove the option with the id from the corpus, see [`Corpus::remove`]
+///
+/// Returns `false` if there is no such option.
+/// # Safety
+/// The corpus has to be null or a live corpus.
+#[no_mangle]
+pub unsafe extern "C" fn ss_corpus_remove(corpus: *mut SsCorpus, id: usize) -> bool {
+    corpus.as_mut().and_then(|corpus| corpus.0.remove(id)).is_some()
+}
+
+/// Find the option of the corpus that is the most similar to the query, see [`Corpus::best_match`]
+///
+/// Writes the match with the id of the option to `out` and returns `true`, or returns `false`
+/// if the corpus is empty or any of the pointers is null.
+/// # Safety
+/// The corpus has to be null or a live corpus, the query null or NUL-terminated and `out`
+/// null or pointing to a writable [`SsMatch`].
+#[no_mangle]
+pub unsafe extern "C" fn ss_corpus_best_match(corpus: *const SsCorpus, query: *const c_char, out: *mut SsMatch) -> bool {
+    let (Some(corpus), Some(query)) = (corpus.as_ref(), to_str(query)) else {
+        return false;
+    };
+    write_match(corpus.0.best_match(query), out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::ptr;
+
+    use crate::ffi::*;
+
+    #[test]
+    fn ffi_functions() {
+        let (age, page) = (CString::new("age").unwrap(), CString::new("page").unwrap());
+        let options: Vec<CString> = ["fill", "night", "ride"].into_iter().map(|option| CString::new(option).unwrap()).collect();
+        let pointers: Vec<*const c_char> = options.iter().map(|option| option.as_ptr()).collect();
+        let target = CString::new("fight").unwrap();
+        let mut best = SsMatch { index: 0, score: 0.0 };
+        unsafe {
+            assert_eq!(ss_compare_similarity(age.as_ptr(), page.as_ptr()), 0.75);
+            assert_eq!(ss_compare_similarity(age.as_ptr(), ptr::null()), -1.0);
+            assert_eq!(ss_levenshtein_distance(age.as_ptr(), page.as_ptr()), 1);
+            assert!(ss_find_best_match(target.as_ptr(), pointers.as_ptr(), pointers.len(), &mut best));
+            assert_eq!(best, SsMatch { index: 1, score: 0.8 });
+            assert!(!ss_find_best_match(target.as_ptr(), ptr::null(), 0, &mut best));
+            let invalid = b"\xff\0";
+            assert_eq!(ss_compare_similarity(age.as_ptr(), invalid.as_ptr() as *const c_char), -1.0);
+        }
+    }
+
+    #[test]
+    fn ffi_corpus() {
+        let options: Vec<CString> = ["fill", "night", "ride"].into_iter().map(|option| CString::new(option).unwrap()).collect();
+        let pointers: Vec<*const c_char> = options.iter().map(|option| option.as_ptr()).collect();
+        let (target, light) = (CString::new("fight").unwrap(), CString::new("light").unwrap());
+        let mut best = SsMatch { index: 0, score: 0.0 };
+        unsafe {
+            let corpus = ss_corpus_new(pointers.as_ptr(), pointers.len());
+            assert!(!corpus.is_null());
+            assert_eq!(ss_corpus_insert(corpus, light.as_ptr()), 3);
+            assert!(ss_corpus_remove(corpus, 1) && !ss_corpus_remove(corpus, 1));
+            assert_eq!(ss_corpus_len(corpus), 3);
+            assert!(ss_corpus_best_match(corpus, target.as_ptr(), &mut best));
+            assert_eq!(best, SsMatch { index: 3, score: 0.8 });
+            ss_corpus_free(corpus);
+            assert!(!ss_corpus_best_match(ptr::null(), target.as_ptr(), &mut best));
+            ss_corpus_free(ptr::null_mut());
+        }
+    }
+}
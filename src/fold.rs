@@ -0,0 +1,388 @@
+use std::borrow::Cow;
+
+/// Replacement of a single character by a folding table
+pub(crate) enum Fold {
+    Keep,
+    Remove,
+    Char(char),
+    Str(&'static str)
+}
+
+/// Fold every character of the text, borrowing it when nothing changes
+pub(crate) fn fold_text(text: &str, fold: impl Fn(char) -> Fold) -> Cow<'_, str> {
+    let Some(start) = text.char_indices().find(|(_, letter)| !matches!(fold(*letter), Fold::Keep)).map(|(index, _)| index) else {
+        return Cow::Borrowed(text);
+    };
+    let mut result = String::with_capacity(text.len());
+    result.push_str(&text[..start]);
+    for letter in text[start..].chars() {
+        match fold(letter) {
+            Fold::Keep => result.push(letter),
+            Fold::Remove => {}
+            Fold::Char(replacement) => result.push(replacement),
+            Fold::Str(replacement) => result.push_str(replacement)
+        }
+    }
+    Cow::Owned(result)
+}
+
+/// Apply a transformation to a string that may already be a copy
+pub(crate) fn then<'a>(text: Cow<'a, str>, transform: impl for<'b> Fn(&'b str) -> Cow<'b, str>) -> Cow<'a, str> {
+    match text {
+        Cow::Borrowed(text) => transform(text),
+        Cow::Owned(text) => Cow::Owned(transform(&text).into_owned())
+    }
+}
+
+/// Latin letters with diacritics and their plain spelling, sorted by the letter
+const ACCENTS: &[(char, &str)] = &[
+    ('À', "A"), ('Á', "A"), ('Â', "A"), ('Ã', "A"), ('Ä', "A"), ('Å', "A"), ('Æ', "AE"),
+    ('Ç', "C"), ('È', "E"), ('É', "E"), ('Ê', "E"), ('Ë', "E"), ('Ì', "I"), ('Í', "I"), ('Î', "I"),
+    ('Ï', "I"), ('Ð', "D"), ('Ñ', "N"), ('Ò', "O"), ('Ó', "O"), ('Ô', "O"), ('Õ', "O"), ('Ö', "O"),
+    ('Ø', "O"), ('Ù', "U"), ('Ú', "U"), ('Û', "U"), ('Ü', "U"), ('Ý', "Y"), ('Þ', "TH"),
+    ('ß', "ss"), ('à', "a"), ('á', "a"), ('â', "a"), ('ã', "a"), ('ä', "a"), ('å', "a"),
+    ('æ', "ae"), ('ç', "c"), ('è', "e"), ('é', "e"), ('ê', "e"), ('ë', "e"), ('ì', "i"),
+    ('í', "i"), ('î', "i"), ('ï', "i"), ('ð', "d"), ('ñ', "n"), ('ò', "o"), ('ó', "o"), ('ô', "o"),
+    ('õ', "o"), ('ö', "o"), ('ø', "o"), ('ù', "u"), ('ú', "u"), ('û', "u"), ('ü', "u"), ('ý', "y"),
+    ('þ', "th"), ('ÿ', "y"), ('Ā', "A"), ('ā', "a"), ('Ă', "A"), ('ă', "a"), ('Ą', "A"),
+    ('ą', "a"), ('Ć', "C"), ('ć', "c"), ('Ĉ', "C"), ('ĉ', "c"), ('Ċ', "C"), ('ċ', "c"), ('Č', "C"),
+    ('č', "c"), ('Ď', "D"), ('ď', "d"), ('Đ', "D"), ('đ', "d"), ('Ē', "E"), ('ē', "e"), ('Ĕ', "E"),
+    ('ĕ', "e"), ('Ė', "E"), ('ė', "e"), ('Ę', "E"), ('ę', "e"), ('Ě', "E"), ('ě', "e"), ('Ĝ', "G"),
+    ('ĝ', "g"), ('Ğ', "G"), ('ğ', "g"), ('Ġ', "G"), ('ġ', "g"), ('Ģ', "G"), ('ģ', "g"), ('Ĥ', "H"),
+    ('ĥ', "h"), ('Ħ', "H"), ('ħ', "h"), ('Ĩ', "I"), ('ĩ', "i"), ('Ī', "I"), ('ī', "i"), ('Ĭ', "I"),
+    ('ĭ', "i"), ('Į', "I"), ('į', "i"), ('İ', "I"), ('ı', "i"), ('Ĵ', "J"), ('ĵ', "j"), ('Ķ', "K"),
+    ('ķ', "k"), ('Ĺ', "L"), ('ĺ', "l"), ('Ļ', "L"), ('ļ', "l"), ('Ľ', "L"), ('ľ', "l"), ('Ŀ', "L"),
+    ('ŀ', "l"), ('Ł', "L"), ('ł', "l"), ('Ń', "N"), ('ń', "n"), ('Ņ', "N"), ('ņ', "n"), ('Ň', "N"),
+    ('ň', "n"), ('Ō', "O"), ('ō', "o"), ('Ŏ', "O"), ('ŏ', "o"), ('Ő', "O"), ('ő', "o"),
+    ('Œ', "OE"), ('œ', "oe"), ('Ŕ', "R"), ('ŕ', "r"), ('Ŗ', "R"), ('ŗ', "r"), ('Ř', "R"),
+    ('ř', "r"), ('Ś', "S"), ('ś', "s"), ('Ŝ', "S"), ('ŝ', "s"), ('Ş', "S"), ('ş', "s"), ('Š', "S"),
+    ('š', "s"), ('Ţ', "T"), ('ţ', "t"), ('Ť', "T"), ('ť', "t"), ('Ŧ', "T"), ('ŧ', "t"), ('Ũ', "U"),
+    ('ũ', "u"), ('Ū', "U"), ('ū', "u"), ('Ŭ', "U"), ('ŭ', "u"), ('Ů', "U"), ('ů', "u"), ('Ű', "U"),
+    ('ű', "u"), ('Ų', "U"), ('ų', "u"), ('Ŵ', "W"), ('ŵ', "w"), ('Ŷ', "Y"), ('ŷ', "y"), ('Ÿ', "Y"),
+    ('Ź', "Z"), ('ź', "z"), ('Ż', "Z"), ('ż', "z"), ('Ž', "Z"), ('ž', "z"), ('ƀ', "b"), ('Ɓ', "B"),
+    ('ƒ', "f"), ('Ɨ', "I"), ('Ơ', "O"), ('ơ', "o"), ('Ư', "U"), ('ư', "u"), ('Ƶ', "Z"), ('ƶ', "z"),
+    ('Ǎ', "A"), ('ǎ', "a"), ('Ǐ', "I"), ('ǐ', "i"), ('Ǒ', "O"), ('ǒ', "o"), ('Ǔ', "U"), ('ǔ', "u"),
+    ('Ǖ', "U"), ('ǖ', "u"), ('Ǘ', "U"), ('ǘ', "u"), ('Ǚ', "U"), ('ǚ', "u"), ('Ǜ', "U"), ('ǜ', "u"),
+    ('Ǟ', "A"), ('ǟ', "a"), ('Ǡ', "A"), ('ǡ', "a"), ('Ǧ', "G"), ('ǧ', "g"), ('Ǩ', "K"), ('ǩ', "k"),
+    ('Ǫ', "O"), ('ǫ', "o"), ('Ǭ', "O"), ('ǭ', "o"), ('ǰ', "j"), ('Ǵ', "G"), ('ǵ', "g"), ('Ǹ', "N"),
+    ('ǹ', "n"), ('Ǻ', "A"), ('ǻ', "a"), ('Ȁ', "A"), ('ȁ', "a"), ('Ȃ', "A"), ('ȃ', "a"), ('Ȅ', "E"),
+    ('ȅ', "e"), ('Ȇ', "E"), ('ȇ', "e"), ('Ȉ', "I"), ('ȉ', "i"), ('Ȋ', "I"), ('ȋ', "i"), ('Ȍ', "O"),
+    ('ȍ', "o"), ('Ȏ', "O"), ('ȏ', "o"), ('Ȑ', "R"), ('ȑ', "r"), ('Ȓ', "R"), ('ȓ', "r"), ('Ȕ', "U"),
+    ('ȕ', "u"), ('Ȗ', "U"), ('ȗ', "u"), ('Ș', "S"), ('ș', "s"), ('Ț', "T"), ('ț', "t"), ('Ȟ', "H"),
+    ('ȟ', "h"), ('Ȧ', "A"), ('ȧ', "a"), ('Ȩ', "E"), ('ȩ', "e"), ('Ȫ', "O"), ('ȫ', "o"), ('Ȭ', "O"),
+    ('ȭ', "o"), ('Ȯ', "O"), ('ȯ', "o"), ('Ȱ', "O"), ('ȱ', "o"), ('Ȳ', "Y"), ('ȳ', "y"), ('ɨ', "i"),
+    ('Ḁ', "A"), ('ḁ', "a"), ('Ḃ', "B"), ('ḃ', "b"), ('Ḅ', "B"), ('ḅ', "b"), ('Ḇ', "B"), ('ḇ', "b"),
+    ('Ḉ', "C"), ('ḉ', "c"), ('Ḋ', "D"), ('ḋ', "d"), ('Ḍ', "D"), ('ḍ', "d"), ('Ḏ', "D"), ('ḏ', "d"),
+    ('Ḑ', "D"), ('ḑ', "d"), ('Ḓ', "D"), ('ḓ', "d"), ('Ḕ', "E"), ('ḕ', "e"), ('Ḗ', "E"), ('ḗ', "e"),
+    ('Ḙ', "E"), ('ḙ', "e"), ('Ḛ', "E"), ('ḛ', "e"), ('Ḝ', "E"), ('ḝ', "e"), ('Ḟ', "F"), ('ḟ', "f"),
+    ('Ḡ', "G"), ('ḡ', "g"), ('Ḣ', "H"), ('ḣ', "h"), ('Ḥ', "H"), ('ḥ', "h"), ('Ḧ', "H"), ('ḧ', "h"),
+    ('Ḩ', "H"), ('ḩ', "h"), ('Ḫ', "H"), ('ḫ', "h"), ('Ḭ', "I"), ('ḭ', "i"), ('Ḯ', "I"), ('ḯ', "i"),
+    ('Ḱ', "K"), ('ḱ', "k"), ('Ḳ', "K"), ('ḳ', "k"), ('Ḵ', "K"), ('ḵ', "k"), ('Ḷ', "L"), ('ḷ', "l"),
+    ('Ḹ', "L"), ('ḹ', "l"), ('Ḻ', "L"), ('ḻ', "l"), ('Ḽ', "L"), ('ḽ', "l"), ('Ḿ', "M"), ('ḿ', "m"),
+    ('Ṁ', "M"), ('ṁ', "m"), ('Ṃ', "M"), ('ṃ', "m"), ('Ṅ', "N"), ('ṅ', "n"), ('Ṇ', "N"), ('ṇ', "n"),
+    ('Ṉ', "N"), ('ṉ', "n"), ('Ṋ', "N"), ('ṋ', "n"), ('Ṍ', "O"), ('ṍ', "o"), ('Ṏ', "O"), ('ṏ', "o"),
+    ('Ṑ', "O"), ('ṑ', "o"), ('Ṓ', "O"), ('ṓ', "o"), ('Ṕ', "P"), ('ṕ', "p"), ('Ṗ', "P"), ('ṗ', "p"),
+    ('Ṙ', "R"), ('ṙ', "r"), ('Ṛ', "R"), ('ṛ', "r"), ('Ṝ', "R"), ('ṝ', "r"), ('Ṟ', "R"), ('ṟ', "r"),
+    ('Ṡ', "S"), ('ṡ', "s"), ('Ṣ', "S"), ('ṣ', "s"), ('Ṥ', "S"), ('ṥ', "s"), ('Ṧ', "S"), ('ṧ', "s"),
+    ('Ṩ', "S"), ('ṩ', "s"), ('Ṫ', "T"), ('ṫ', "t"), ('Ṭ', "T"), ('ṭ', "t"), ('Ṯ', "T"), ('ṯ', "t"),
+    ('Ṱ', "T"), ('ṱ', "t"), ('Ṳ', "U"), ('ṳ', "u"), ('Ṵ', "U"), ('ṵ', "u"), ('Ṷ', "U"), ('ṷ', "u"),
+    ('Ṹ', "U"), ('ṹ', "u"), ('Ṻ', "U"), ('ṻ', "u"), ('Ṽ', "V"), ('ṽ', "v"), ('Ṿ', "V"), ('ṿ', "v"),
+    ('Ẁ', "W"), ('ẁ', "w"), ('Ẃ', "W"), ('ẃ', "w"), ('Ẅ', "W"), ('ẅ', "w"), ('Ẇ', "W"), ('ẇ', "w"),
+    ('Ẉ', "W"), ('ẉ', "w"), ('Ẋ', "X"), ('ẋ', "x"), ('Ẍ', "X"), ('ẍ', "x"), ('Ẏ', "Y"), ('ẏ', "y"),
+    ('Ẑ', "Z"), ('ẑ', "z"), ('Ẓ', "Z"), ('ẓ', "z"), ('Ẕ', "Z"), ('ẕ', "z"), ('ẖ', "h"), ('ẗ', "t"),
+    ('ẘ', "w"), ('ẙ', "y"), ('ẞ', "SS"), ('Ạ', "A"), ('ạ', "a"), ('Ả', "A"), ('ả', "a"),
+    ('Ấ', "A"), ('ấ', "a"), ('Ầ', "A"), ('ầ', "a"), ('Ẩ', "A"), ('ẩ', "a"), ('Ẫ', "A"), ('ẫ', "a"),
+    ('Ậ', "A"), ('ậ', "a"), ('Ắ', "A"), ('ắ', "a"), ('Ằ', "A"), ('ằ', "a"), ('Ẳ', "A"), ('ẳ', "a"),
+    ('Ẵ', "A"), ('ẵ', "a"), ('Ặ', "A"), ('ặ', "a"), ('Ẹ', "E"), ('ẹ', "e"), ('Ẻ', "E"), ('ẻ', "e"),
+    ('Ẽ', "E"), ('ẽ', "e"), ('Ế', "E"), ('ế', "e"), ('Ề', "E"), ('ề', "e"), ('Ể', "E"), ('ể', "e"),
+    ('Ễ', "E"), ('ễ', "e"), ('Ệ', "E"), ('ệ', "e"), ('Ỉ', "I"), ('ỉ', "i"), ('Ị', "I"), ('ị', "i"),
+    ('Ọ', "O"), ('ọ', "o"), ('Ỏ', "O"), ('ỏ', "o"), ('Ố', "O"), ('ố', "o"), ('Ồ', "O"), ('ồ', "o"),
+    ('Ổ', "O"), ('ổ', "o"), ('Ỗ', "O"), ('ỗ', "o"), ('Ộ', "O"), ('ộ', "o"), ('Ớ', "O"), ('ớ', "o"),
+    ('Ờ', "O"), ('ờ', "o"), ('Ở', "O"), ('ở', "o"), ('Ỡ', "O"), ('ỡ', "o"), ('Ợ', "O"), ('ợ', "o"),
+    ('Ụ', "U"), ('ụ', "u"), ('Ủ', "U"), ('ủ', "u"), ('Ứ', "U"), ('ứ', "u"), ('Ừ', "U"), ('ừ', "u"),
+    ('Ử', "U"), ('ử', "u"), ('Ữ', "U"), ('ữ', "u"), ('Ự', "U"), ('ự', "u"), ('Ỳ', "Y"), ('ỳ', "y"),
+    ('Ỵ', "Y"), ('ỵ', "y"), ('Ỷ', "Y"), ('ỷ', "y"), ('Ỹ', "Y"), ('ỹ', "y")
+];
+
+/// Combining marks that are drawn over or under the previous character
+fn is_combining_mark(letter: char) -> bool {
+    matches!(letter, '\u{300}'..='\u{36F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}' | '\u{FE20}'..='\u{FE2F}')
+}
+
+fn fold_accent(letter: char) -> Fold {
+    if letter.is_ascii() {
+        return Fold::Keep;
+    }
+    if is_combining_mark(letter) {
+        return Fold::Remove;
+    }
+    match ACCENTS.binary_search_by_key(&letter, |(accented, _)| *accented) {
+        Ok(index) => Fold::Str(ACCENTS[index].1),
+        Err(_) => Fold::Keep
+    }
+}
+
+/// Remove diacritics from Latin letters
+///
+/// Letters such as "é" or "ł" are replaced with their plain spelling, ligatures such as
+/// "æ" or "ß" are spelled out and combining marks are removed.
+/// The string is only copied when it has to be changed.
+/// # Example
+/// ```
+/// use similar_string::fold_accents;
+///
+/// fold_accents("Crème brûlée"); // "Creme brulee"
+/// fold_accents("Łódź"); // "Lodz"
+/// ```
+pub fn fold_accents(text: &str) -> Cow<'_, str> {
+    fold_text(text, fold_accent)
+}
+
+/// Fullwidth forms of characters from U+FF61 to U+FF9F
+const HALFWIDTH_KATAKANA: [char; 63] = [
+    '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー', 'ア', 'イ', 'ウ',
+    'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ', 'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ',
+    'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ', 'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル',
+    'レ', 'ロ', 'ワ', 'ン', '\u{3099}', '\u{309A}'
+];
+
+/// Fullwidth currency signs and symbols from U+FFE0 to U+FFE6
+const FULLWIDTH_SYMBOLS: [char; 7] = ['¢', '£', '¬', '¯', '¦', '¥', '₩'];
+
+/// Character of the regular width or `None` when it is the same character
+fn fold_width_char(letter: char) -> Option<char> {
+    match letter {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(letter as u32 - 0xFEE0),
+        '\u{3000}' => Some(' '),
+        '\u{FF61}'..='\u{FF9F}' => Some(HALFWIDTH_KATAKANA[letter as usize - 0xFF61]),
+        '\u{FFE0}'..='\u{FFE6}' => Some(FULLWIDTH_SYMBOLS[letter as usize - 0xFFE0]),
+        _ => None
+    }
+}
+
+/// Katakana combined with the voiced (dakuten) or semi-voiced (handakuten) sound mark
+fn voice(kana: char, semi_voiced: bool) -> Option<char> {
+    match (kana, semi_voiced) {
+        ('カ' | 'キ' | 'ク' | 'ケ' | 'コ' | 'サ' | 'シ' | 'ス' | 'セ' | 'ソ' | 'タ' | 'チ' | 'ツ' | 'テ' | 'ト', false) |
+        ('ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ', false) => char::from_u32(kana as u32 + 1),
+        ('ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ', true) => char::from_u32(kana as u32 + 2),
+        ('ウ', false) => Some('ヴ'),
+        ('ワ', false) => Some('ヷ'),
+        ('ヲ', false) => Some('ヺ'),
+        _ => None
+    }
+}
+
+/// Replace fullwidth and halfwidth characters with their regular width forms
+///
+/// Fullwidth ASCII letters, digits and symbols become ASCII, the ideographic space becomes
+/// a regular space and halfwidth katakana become fullwidth katakana. Halfwidth sound marks
+/// are combined with the preceding kana, so that "ｶﾞ" becomes "ガ".
+/// The string is only copied when it has to be changed.
+/// # Example
+/// ```
+/// use similar_string::fold_width;
+///
+/// fold_width("ＡＢＣ１２３"); // "ABC123"
+/// fold_width("ｶﾞｲﾄﾞ"); // "ガイド"
+/// ```
+pub fn fold_width(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(|letter| fold_width_char(letter).is_some()) {
+        return Cow::Borrowed(text);
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut letters = text.chars().peekable();
+    while let Some(letter) = letters.next() {
+        let folded = fold_width_char(letter).unwrap_or(letter);
+        if let Some(&mark @ ('\u{FF9E}' | '\u{FF9F}')) = letters.peek() {
+            if let Some(voiced) = voice(folded, mark == '\u{FF9F}') {
+                result.push(voiced);
+                letters.next();
+                continue;
+            }
+        }
+        result.push(folded);
+    }
+    Cow::Owned(result)
+}
+
+/// Hiragana letter of the same sound as a katakana letter
+fn to_hiragana(letter: char) -> Option<char> {
+    match letter {
+        '\u{30A1}'..='\u{30F6}' | '\u{30FD}' | '\u{30FE}' => char::from_u32(letter as u32 - 0x60),
+        _ => None
+    }
+}
+
+/// Replace katakana with hiragana
+///
+/// Both scripts spell the same sounds, so "ラーメン" and "らーめん" become the same string.
+/// The string is only copied when it has to be changed.
+/// # Example
+/// ```
+/// use similar_string::fold_kana;
+///
+/// fold_kana("ラーメン"); // "らーめん"
+/// ```
+pub fn fold_kana(text: &str) -> Cow<'_, str> {
+    fold_text(text, |letter| match to_hiragana(letter) {
+        Some(hiragana) => Fold::Char(hiragana),
+        None => Fold::Keep
+    })
+}
+
+/// Vowels of hiragana letters from U+3041 to U+3096, "-" for letters without one
+const KANA_VOWELS: &[u8; 86] = b"aaiiuueeooaaiiuueeooaaiiuueeooaaiiuuueeooaiueoaaaiiiuuueeeoooaiueoaauuooaiueoaaieo-uae";
+
+/// Vowel of a kana letter spelled in the same script
+fn kana_vowel(letter: char) -> Option<char> {
+    let hiragana = to_hiragana(letter).unwrap_or(letter);
+    let vowel = match hiragana {
+        '\u{3041}'..='\u{3096}' => KANA_VOWELS[hiragana as usize - 0x3041],
+        _ => return None
+    };
+    let vowel = match vowel {
+        b'a' => 'あ',
+        b'i' => 'い',
+        b'u' => 'う',
+        b'e' => 'え',
+        b'o' => 'お',
+        _ => return None
+    };
+    // Katakana vowels are placed at the same offset as the hiragana ones
+    if hiragana == letter { Some(vowel) } else { char::from_u32(vowel as u32 + 0x60) }
+}
+
+/// Replace the long vowel mark "ー" with the vowel of the previous kana
+///
+/// The mark lengthens the previous vowel, so "らーめん" is spelled as "らあめん".
+/// Marks that don't follow a kana with a vowel are left as they are.
+/// The string is only copied when it has to be changed.
+/// # Example
+/// ```
+/// use similar_string::expand_long_vowels;
+///
+/// expand_long_vowels("らーめん"); // "らあめん"
+/// expand_long_vowels("コーヒー"); // "コオヒイ"
+/// ```
+pub fn expand_long_vowels(text: &str) -> Cow<'_, str> {
+    if !text.contains('ー') {
+        return Cow::Borrowed(text);
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut vowel = None;
+    for letter in text.chars() {
+        if letter == 'ー' {
+            result.push(vowel.unwrap_or(letter));
+            continue;
+        }
+        vowel = kana_vowel(letter);
+        result.push(letter);
+    }
+    Cow::Owned(result)
+}
+
+/// Characters that look alike and their common spelling, sorted by the character
+///
+/// This is a subset of the Unicode confusables list covering Latin lookalikes
+/// from Cyrillic, Greek and Armenian scripts as well as digits that pass for letters.
+const CONFUSABLES: &[(char, &str)] = &[
+    ('0', "O"), ('1', "l"), ('I', "l"), ('m', "rn"), ('|', "l"), ('ı', "i"), ('ſ', "f"),
+    ('ȷ', "j"), ('ɑ', "a"), ('ɡ', "g"), ('ɩ', "i"), ('ɪ', "i"), ('ʏ', "y"), ('Α', "A"), ('Β', "B"),
+    ('Ε', "E"), ('Ζ', "Z"), ('Η', "H"), ('Ι', "l"), ('Κ', "K"), ('Μ', "M"), ('Ν', "N"), ('Ο', "O"),
+    ('Ρ', "P"), ('Τ', "T"), ('Υ', "Y"), ('Χ', "X"), ('α', "a"), ('γ', "y"), ('ι', "i"), ('κ', "k"),
+    ('ν', "v"), ('ο', "o"), ('ρ', "p"), ('σ', "o"), ('υ', "u"), ('ω', "w"), ('ϲ', "c"), ('ϳ', "j"),
+    ('Ϲ', "C"), ('Ѕ', "S"), ('І', "l"), ('Ј', "J"), ('А', "A"), ('В', "B"), ('Е', "E"), ('З', "3"),
+    ('К', "K"), ('М', "M"), ('Н', "H"), ('О', "O"), ('Р', "P"), ('С', "C"), ('Т', "T"), ('У', "Y"),
+    ('Х', "X"), ('Ь', "b"), ('а', "a"), ('е', "e"), ('о', "o"), ('р', "p"), ('с', "c"), ('у', "y"),
+    ('х', "x"), ('ь', "b"), ('ѕ', "s"), ('і', "i"), ('ј', "j"), ('Ѵ', "V"), ('ѵ', "v"), ('һ', "h"),
+    ('Ӏ', "l"), ('ӏ', "l"), ('ԁ', "d"), ('Ԛ', "Q"), ('ԛ', "q"), ('Ԝ', "W"), ('ԝ', "w"), ('Տ', "S"),
+    ('Օ', "O"), ('հ', "h"), ('ո', "n"), ('ս', "u"), ('ց', "g"), ('օ', "o"), ('ℓ', "l")
+];
+
+fn fold_confusable(letter: char) -> Fold {
+    match CONFUSABLES.binary_search_by_key(&letter, |(confusable, _)| *confusable) {
+        Ok(index) => Fold::Str(CONFUSABLES[index].1),
+        Err(_) => Fold::Keep
+    }
+}
+
+/// Replace characters that look alike with one common spelling
+///
+/// Cyrillic "а" and Latin "a" become the same letter, and so do "0" and "O" or "1", "I" and "l".
+/// The letter "m" is spelled as "rn", which looks the same in many fonts.
+/// This helps to detect spoofed names, combine it with [`fold_width`] to also catch fullwidth letters.
+/// The string is only copied when it has to be changed.
+/// # Example
+/// ```
+/// use similar_string::fold_confusables;
+///
+/// // The first letter is Cyrillic
+/// fold_confusables("аpple"); // "apple"
+/// fold_confusables("G00GLE"); // "GOOGLE"
+/// fold_confusables("microsoft") == fold_confusables("rnicrosoft"); // true
+/// ```
+pub fn fold_confusables(text: &str) -> Cow<'_, str> {
+    fold_text(text, fold_confusable)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::*;
+
+    #[test]
+    fn accents_are_folded() {
+        assert_eq!(fold_accents("Crème brûlée"), "Creme brulee");
+        assert_eq!(fold_accents("Straße Ærø"), "Strasse AEro");
+        assert_eq!(fold_accents("cafe\u{301}"), "cafe");
+        assert_eq!(fold_accents("Tiếng Việt"), "Tieng Viet");
+        assert!(matches!(fold_accents("plain"), Cow::Borrowed("plain")));
+        assert_eq!(fold_accents("日本"), "日本");
+    }
+
+    #[test]
+    fn width_is_folded() {
+        assert_eq!(fold_width("ＡＢＣ１２３"), "ABC123");
+        assert_eq!(fold_width("ｶﾞｲﾄﾞﾌﾞｯｸ"), "ガイドブック");
+        assert_eq!(fold_width("ﾊﾟﾝ　ﾎﾟ"), "パン ポ");
+        assert_eq!(fold_width("ｱﾞ"), "ア\u{3099}");
+        assert_eq!(fold_width("￥１００"), "¥100");
+        assert!(matches!(fold_width("ABC ガイド"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn kana_is_folded() {
+        assert_eq!(fold_kana("ラーメン"), "らーめん");
+        assert_eq!(fold_kana("ヴァイオリン"), "ゔぁいおりん");
+        assert!(matches!(fold_kana("ひらがな abc"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn long_vowels_are_expanded() {
+        assert_eq!(expand_long_vowels("らーめん"), "らあめん");
+        assert_eq!(expand_long_vowels("コーヒー"), "コオヒイ");
+        assert_eq!(expand_long_vowels("ゲーーム"), "ゲエエム");
+        assert_eq!(expand_long_vowels("ンー ー"), "ンー ー");
+    }
+
+    #[test]
+    fn confusables_are_folded() {
+        assert_eq!(fold_confusables("раураl"), "paypal");
+        assert_eq!(fold_confusables("G00GLE"), "GOOGLE");
+        assert_eq!(fold_confusables("Ι1|I"), "llll");
+        assert_eq!(fold_confusables("micro"), fold_confusables("rnicro"));
+        assert!(matches!(fold_confusables("paypal"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn confusable_table_is_sorted() {
+        assert!(super::CONFUSABLES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn accent_table_is_sorted() {
+        assert!(super::ACCENTS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeSet;
+
+use crate::compare_similarity;
+use crate::lcs::PatternMasks;
+use crate::tokens::split_words;
+
+/// Words of the string sorted and joined with single spaces
+fn sorted_words(text: &str) -> String {
+    let mut words = split_words(text);
+    words.sort_unstable();
+    words.join(" ")
+}
+
+/// Get score of similarity of two strings regardless of the word order
+///
+/// The words of both strings are sorted and joined with single spaces
+/// before they are compared with [`compare_similarity`], punctuation is dropped.
+/// This mirrors `token_sort_ratio` of Python's `fuzzywuzzy`, but the rating is in range
+/// from 0.0 to 1.0 and the letter case is kept.
+/// # Example
+/// ```
+/// use similar_string::token_sort_ratio;
+///
+/// token_sort_ratio("flat for rent London", "London flat for rent"); // 1.0
+/// token_sort_ratio("fox, brown", "brown dog"); // 0.7777777777777778
+/// ```
+pub fn token_sort_ratio(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    compare_similarity(sorted_words(left.as_ref()), sorted_words(right.as_ref()))
+}
+
+/// Join the prefix and the words that follow it with single spaces
+fn join_after<'a>(prefix: &str, words: impl Iterator<Item = &'a str>) -> String {
+    let rest: Vec<&str> = words.collect();
+    match (prefix.is_empty(), rest.is_empty()) {
+        (_, true) => prefix.to_string(),
+        (true, false) => rest.join(" "),
+        (false, false) => format!("{} {}", prefix, rest.join(" "))
+    }
+}
+
+/// Get score of similarity of two strings based on their sets of words
+///
+/// The words both strings share are sorted and compared with what each string adds to them,
+/// so a string that contains all the words of the other one gets a high rating
+/// regardless of the extra words. Repeated words count once and the word order doesn't matter.
+/// This mirrors `token_set_ratio` of Python's `fuzzywuzzy`, but the rating is in range
+/// from 0.0 to 1.0 and the letter case is kept.
+/// # Example
+/// ```
+/// use similar_string::token_set_ratio;
+///
+/// token_set_ratio("The Matrix", "The Matrix (1999) - Official Trailer"); // 1.0
+/// token_set_ratio("fuzzy was a bear", "fuzzy fuzzy was a bear"); // 1.0
+/// ```
+pub fn token_set_ratio(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    token_set_with(left.as_ref(), right.as_ref(), |left, right| compare_similarity(left, right))
+}
+
+/// Token set comparison that rates the joined words with the given scorer
+fn token_set_with(left: &str, right: &str, scorer: impl Fn(&str, &str) -> f64) -> f64 {
+    let left_words: BTreeSet<&str> = split_words(left).into_iter().collect();
+    let right_words: BTreeSet<&str> = split_words(right).into_iter().collect();
+    // Without words on one side, the empty intersection would match the empty side perfectly
+    if left_words.is_empty() != right_words.is_empty() {
+        return 0.0;
+    }
+    let common: Vec<&str> = left_words.intersection(&right_words).copied().collect();
+    let common = common.join(" ");
+    let left = join_after(&common, left_words.difference(&right_words).copied());
+    let right = join_after(&common, right_words.difference(&left_words).copied());
+    scorer(&common, &left)
+        .max(scorer(&common, &right))
+        .max(scorer(&left, &right))
+}
+
+/// Get score of similarity of the shorter string and the best matching part of the longer one
+///
+/// The shorter string is compared with every window of the longer string of the same
+/// character count and the best rating is returned, so a query that appears inside
+/// a long title gets a high rating. The order of the arguments doesn't matter.
+/// This mirrors `partial_ratio` of Python's `fuzzywuzzy`, but the rating is in range from 0.0 to 1.0.
+/// An empty string only matches another empty string.
+/// # Example
+/// ```
+/// use similar_string::partial_ratio;
+///
+/// partial_ratio("yankees", "new york yankees"); // 1.0
+/// partial_ratio("new york mets", "the new york meats"); // 0.9230769230769231
+/// ```
+pub fn partial_ratio(short: impl AsRef<str>, long: impl AsRef<str>) -> f64 {
+    let short: Vec<char> = short.as_ref().chars().collect();
+    let long: Vec<char> = long.as_ref().chars().collect();
+    let (short, long) = if short.len() <= long.len() { (short, long) } else { (long, short) };
+    if short.is_empty() {
+        return if long.is_empty() { 1.0 } else { 0.0 };
+    }
+    let masks = PatternMasks::new(&short);
+    let mut best = 0;
+    for window in long.windows(short.len()) {
+        best = best.max(masks.lcs_length(window.iter().copied()));
+        if best == short.len() {
+            break;
+        }
+    }
+    best as f64 / short.len() as f64
+}
+
+/// Weight of the token based ratings, which can't tell apart strings with the words in different order
+const TOKEN_SCALE: f64 = 0.95;
+
+/// Get score of similarity of two strings that combines the plain, partial and token based ratings
+///
+/// Strings of similar length are rated with [`compare_similarity`], [`token_sort_ratio`] and
+/// [`token_set_ratio`]. When one string is at least one and a half times longer than the other,
+/// the partial variants of the ratings are used instead and scaled down, the more so the bigger
+/// the difference in length. The best of the ratings is returned.
+/// This mirrors `WRatio` of Python's `fuzzywuzzy`, but the rating is in range from 0.0 to 1.0.
+/// # Example
+/// ```
+/// use similar_string::weighted_ratio;
+///
+/// weighted_ratio("new york mets", "new york mets"); // 1.0
+/// weighted_ratio("mets new york", "new york mets"); // 0.95
+/// weighted_ratio("yankees", "new york yankees"); // 0.9
+/// ```
+pub fn weighted_ratio(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let (left, right) = (left.as_ref(), right.as_ref());
+    let (left_len, right_len) = (left.chars().count(), right.chars().count());
+    let base = compare_similarity(left, right);
+    if left_len == 0 || right_len == 0 {
+        return base;
+    }
+    let length_ratio = left_len.max(right_len) as f64 / left_len.min(right_len) as f64;
+    if length_ratio < 1.5 {
+        let token_sort = token_sort_ratio(left, right) * TOKEN_SCALE;
+        let token_set = token_set_ratio(left, right) * TOKEN_SCALE;
+        return base.max(token_sort).max(token_set);
+    }
+    let partial_scale = if length_ratio < 8.0 { 0.9 } else { 0.6 };
+    let partial = partial_ratio(left, right) * partial_scale;
+    let token_sort = partial_ratio(sorted_words(left), sorted_words(right)) * TOKEN_SCALE * partial_scale;
+    let token_set = token_set_with(left, right, |left, right| partial_ratio(left, right)) * TOKEN_SCALE * partial_scale;
+    base.max(partial).max(token_sort).max(token_set)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn token_sort_works() {
+        assert_eq!(token_sort_ratio("flat for rent London", "London flat for rent"), 1.0);
+        assert_eq!(token_sort_ratio("a  b,c", "c b a"), 1.0);
+        assert_eq!(token_sort_ratio("", ""), 1.0);
+        assert!(token_sort_ratio("new york mets", "mets new york") > compare_similarity("new york mets", "mets new york"));
+    }
+
+    #[test]
+    fn partial_works() {
+        assert_eq!(partial_ratio("yankees", "new york yankees"), 1.0);
+        assert_eq!(partial_ratio("new york yankees", "yankees"), 1.0);
+        assert_eq!(partial_ratio("", ""), 1.0);
+        assert_eq!(partial_ratio("", "abc"), 0.0);
+        assert_eq!(partial_ratio("abc", "abc"), 1.0);
+        assert_eq!(partial_ratio("axc", "zzabczz"), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn weighted_works() {
+        assert_eq!(weighted_ratio("new york mets", "new york mets"), 1.0);
+        assert_eq!(weighted_ratio("mets new york", "new york mets"), 0.95);
+        assert_eq!(weighted_ratio("yankees", "new york yankees"), 0.9);
+        assert_eq!(weighted_ratio("", ""), 1.0);
+        assert_eq!(weighted_ratio("abc", ""), 0.0);
+        // A string without words doesn't match the words of the other one
+        assert_eq!(weighted_ratio("cat", "..."), 0.0);
+        assert_eq!(weighted_ratio("cat", "- - - - - -"), 0.0);
+        // Very different lengths scale the partial ratings down further
+        assert_eq!(weighted_ratio("ab", "ab and a lot of other words"), 0.6);
+    }
+
+    #[test]
+    fn token_set_works() {
+        assert_eq!(token_set_ratio("The Matrix", "The Matrix (1999) - Official Trailer"), 1.0);
+        assert_eq!(token_set_ratio("fuzzy was a bear", "fuzzy fuzzy was a bear"), 1.0);
+        assert_eq!(token_set_ratio("", ""), 1.0);
+        assert_eq!(token_set_ratio("cat", ""), 0.0);
+        assert_eq!(token_set_ratio("abc", "..."), 0.0);
+        assert_eq!(token_set_ratio("cat", "dog"), compare_similarity("cat", "dog"));
+        assert!(token_set_ratio("mariners vs angels", "los angeles angels vs seattle mariners") > 0.9);
+    }
+}
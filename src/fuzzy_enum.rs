@@ -0,0 +1,153 @@
+use crate::case::compare_similarity_ignore_case;
+use crate::error::UnknownVariantError;
+
+/// Default minimal rating of the strings that [`FuzzyEnum::fuzzy_parse`] resolves to a variant
+pub const DEFAULT_FUZZY_ENUM_SCORE: f64 = 0.6;
+
+/// Variant of an enum that a string was resolved to, see [`FuzzyEnum::fuzzy_parse`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resolved<T> {
+    /// The variant
+    pub value: T,
+    /// The string that was parsed
+    pub input: String,
+    /// Name of the variant
+    pub name: &'static str,
+    /// Similarity rating of the string and the name, letter case is ignored
+    pub score: f64
+}
+
+impl<T> Resolved<T> {
+    /// Tell whether the string was the name of the variant, ignoring letter case
+    pub fn is_exact(&self) -> bool {
+        self.score == 1.0
+    }
+
+    /// Get the warning about the string that was read as a different name, `None` if it was exact
+    pub fn warning(&self) -> Option<String> {
+        if self.is_exact() {
+            None
+        } else {
+            Some(format!("\"{}\" was read as \"{}\"", self.input, self.name))
+        }
+    }
+}
+
+/// Enum that can be parsed from misspelled names of its variants
+///
+/// A string is resolved to the variant with the most similar name, see
+/// [`compare_similarity_ignore_case`](crate::compare_similarity_ignore_case), as long as the
+/// rating is at least [`FuzzyEnum::MIN_SCORE`]. The first variant wins a tie.
+///
+/// With the `derive` feature, `#[derive(FuzzyFromStr)]` implements this trait and `FromStr`
+/// for enums of unit variants. `FromStr` prints a warning to the standard error when the string
+/// was not the exact name of the variant. The attributes of the derive are:
+/// - `#[fuzzy(min_score = 0.8)]` on the enum sets [`FuzzyEnum::MIN_SCORE`]
+/// - `#[fuzzy(warn = path::to::function)]` on the enum calls the function with the warning instead of printing it
+/// - `#[fuzzy(rename = "prod")]` on a variant sets its name, which is the name of the variant by default
+/// # Example
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use similar_string::FuzzyFromStr;
+///
+/// #[derive(Debug, PartialEq, FuzzyFromStr)]
+/// #[fuzzy(min_score = 0.7)]
+/// enum Environment {
+///     Development,
+///     Staging,
+///     Production
+/// }
+///
+/// // warning: "prodcution" was read as "Production"
+/// "prodcution".parse::<Environment>(); // Ok(Environment::Production)
+/// "local".parse::<Environment>().is_err(); // true
+/// # }
+/// ```
+pub trait FuzzyEnum: Sized {
+    /// Names of the variants
+    const NAMES: &'static [&'static str];
+
+    /// Minimal rating of the strings that are resolved to a variant
+    const MIN_SCORE: f64 = DEFAULT_FUZZY_ENUM_SCORE;
+
+    /// Get the variant with the name at the index of [`FuzzyEnum::NAMES`]
+    fn from_name_index(index: usize) -> Self;
+
+    /// Resolve the string to the variant with the most similar name
+    fn fuzzy_parse(input: &str) -> Result<Resolved<Self>, UnknownVariantError> {
+        let best = Self::NAMES.iter()
+            .enumerate()
+            .map(|(index, name)| (index, compare_similarity_ignore_case(input, name)))
+            .fold(None, |best: Option<(usize, f64)>, (index, score)| match best {
+                Some((_, best_score)) if best_score >= score => best,
+                _ => Some((index, score))
+            });
+        match best {
+            Some((index, score)) if score >= Self::MIN_SCORE => Ok(Resolved {
+                value: Self::from_name_index(index),
+                input: input.to_string(),
+                name: Self::NAMES[index],
+                score
+            }),
+            _ => Err(UnknownVariantError { input: input.to_string(), expected: Self::NAMES.to_vec() })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Color {
+        Red,
+        Green
+    }
+
+    impl FuzzyEnum for Color {
+        const NAMES: &'static [&'static str] = &["red", "green"];
+
+        fn from_name_index(index: usize) -> Self {
+            [Color::Red, Color::Green][index]
+        }
+    }
+
+    #[test]
+    fn fuzzy_enum_resolves() {
+        let resolved = Color::fuzzy_parse("GREEN").unwrap();
+        assert_eq!((resolved.value, resolved.is_exact(), resolved.warning()), (Color::Green, true, None));
+        let resolved = Color::fuzzy_parse("gren").unwrap();
+        assert_eq!((resolved.value, resolved.warning()), (Color::Green, Some("\"gren\" was read as \"green\"".to_string())));
+        let error = Color::fuzzy_parse("blue").unwrap_err();
+        assert_eq!(error.to_string(), "unknown variant \"blue\", expected one of \"red\", \"green\"");
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn fuzzy_from_str_derive() {
+        use std::cell::RefCell;
+
+        thread_local! {
+            static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+        }
+
+        fn record(warning: &str) {
+            WARNINGS.with(|warnings| warnings.borrow_mut().push(warning.to_string()));
+        }
+
+        #[derive(Debug, PartialEq, FuzzyFromStr)]
+        #[fuzzy(min_score = 0.7, warn = record)]
+        enum Environment {
+            Development,
+            Staging,
+            #[fuzzy(rename = "production")]
+            Prod
+        }
+
+        assert_eq!(Environment::NAMES, ["Development", "Staging", "production"]);
+        assert_eq!("staging".parse(), Ok(Environment::Staging));
+        assert_eq!("prodcution".parse(), Ok(Environment::Prod));
+        assert!("local".parse::<Environment>().is_err());
+        WARNINGS.with(|warnings| assert_eq!(*warnings.borrow(), ["\"prodcution\" was read as \"production\""]));
+    }
+}
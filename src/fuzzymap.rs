@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use crate::search::best_rated;
+
+/// Map from strings to values that can also look up the key most similar to a given one
+///
+/// Exact lookups use a hash map, while [`get_fuzzy`](FuzzyMap::get_fuzzy) rates all the keys
+/// with [`compare_similarity`](crate::compare_similarity). The entries keep the order in which
+/// they were inserted, which also decides ties between equally similar keys.
+/// # Example
+/// ```
+/// use similar_string::FuzzyMap;
+///
+/// let mut commands = FuzzyMap::new();
+/// commands.insert("install", 1);
+/// commands.insert("uninstall", 2);
+/// commands.insert("update", 3);
+///
+/// commands.get("update"); // Some(&3)
+/// commands.get_fuzzy("instal", 0.5); // Some(("install", &1, 0.8571428571428571))
+/// commands.get_fuzzy("xyz", 0.5); // None
+/// ```
+#[derive(Debug, Clone)]
+pub struct FuzzyMap<V> {
+    entries: Vec<(String, V)>,
+    positions: HashMap<String, usize>
+}
+
+impl<V> FuzzyMap<V> {
+    /// Create an empty map
+    pub fn new() -> Self {
+        FuzzyMap { entries: vec![], positions: HashMap::new() }
+    }
+
+    /// Get the number of entries in the map
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the map has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Insert a value under the key and get the value it replaced
+    ///
+    /// A replaced entry keeps its position.
+    pub fn insert(&mut self, key: impl Into<String>, value: V) -> Option<V> {
+        let key = key.into();
+        if let Some(&position) = self.positions.get(&key) {
+            return Some(std::mem::replace(&mut self.entries[position].1, value));
+        }
+        self.positions.insert(key.clone(), self.entries.len());
+        self.entries.push((key, value));
+        None
+    }
+
+    /// Remove the entry with exactly the given key and get its value
+    pub fn remove(&mut self, key: impl AsRef<str>) -> Option<V> {
+        let position = self.positions.remove(key.as_ref())?;
+        let (_, value) = self.entries.remove(position);
+        for (key, _) in &self.entries[position..] {
+            *self.positions.get_mut(key).expect("every key has a position") -= 1;
+        }
+        Some(value)
+    }
+
+    /// Get the value of exactly the given key
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&V> {
+        self.positions.get(key.as_ref()).map(|position| &self.entries[*position].1)
+    }
+
+    /// Get the mutable value of exactly the given key
+    pub fn get_mut(&mut self, key: impl AsRef<str>) -> Option<&mut V> {
+        self.positions.get(key.as_ref()).map(|position| &mut self.entries[*position].1)
+    }
+
+    /// Check whether the map has exactly the given key
+    pub fn contains_key(&self, key: impl AsRef<str>) -> bool {
+        self.positions.contains_key(key.as_ref())
+    }
+
+    /// Get the entry whose key is the most similar to the given one together with its rating
+    ///
+    /// Returns `None` if no key rates at least `min_score`. An exact key is found without
+    /// rating the others and the first inserted key wins a tie.
+    pub fn get_fuzzy(&self, key: impl AsRef<str>, min_score: f64) -> Option<(&str, &V, f64)> {
+        let key = key.as_ref();
+        if let Some(&position) = self.positions.get(key) {
+            let (key, value) = &self.entries[position];
+            return Some((key, value, 1.0));
+        }
+        let (position, _, score) = best_rated(key, self.keys())?;
+        let (key, value) = &self.entries[position];
+        (score >= min_score).then_some((key.as_str(), value, score))
+    }
+
+    /// Iterate over the keys in the order in which they were inserted
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Iterate over the entries in the order in which they were inserted
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.entries.iter().map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+impl<V> Default for FuzzyMap<V> {
+    fn default() -> Self {
+        FuzzyMap::new()
+    }
+}
+
+impl<K: Into<String>, V> Extend<(K, V)> for FuzzyMap<V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, entries: I) {
+        for (key, value) in entries {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Into<String>, V> FromIterator<(K, V)> for FuzzyMap<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        let mut map = FuzzyMap::new();
+        map.extend(entries);
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn fuzzy_map_lookup() {
+        let map: FuzzyMap<usize> = [("fill", 0), ("night", 1), ("light", 2)].into_iter().collect();
+        assert_eq!(map.get("night"), Some(&1));
+        assert_eq!(map.get("fight"), None);
+        assert_eq!(map.get_fuzzy("fight", 0.5), Some(("night", &1, 0.8)));
+        assert_eq!(map.get_fuzzy("fight", 0.9), None);
+        assert_eq!(map.get_fuzzy("light", 1.0), Some(("light", &2, 1.0)));
+        assert_eq!(FuzzyMap::<usize>::new().get_fuzzy("fight", 0.0), None);
+    }
+
+    #[test]
+    fn fuzzy_map_updates() {
+        let mut map = FuzzyMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert("fill", 0), None);
+        assert_eq!(map.insert("night", 1), None);
+        assert_eq!(map.insert("light", 2), None);
+        assert_eq!(map.insert("fill", 3), Some(0));
+        *map.get_mut("light").unwrap() += 10;
+        assert_eq!(map.remove("night"), Some(1));
+        assert_eq!(map.remove("night"), None);
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![("fill", &3), ("light", &12)]);
+        assert_eq!(map.get("light"), Some(&12));
+        assert!(map.contains_key("fill") && !map.contains_key("night"));
+        assert_eq!(map.get_fuzzy("fight", 0.5), Some(("light", &12, 0.8)));
+        assert_eq!(map.len(), 2);
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use crate::levenshtein::normalized_levenshtein;
+use crate::ngram::ngram_profile;
+use crate::search::Match;
+
+/// Default minimal rating of the matches of a [`FuzzySet`]
+pub const DEFAULT_FUZZY_SET_SCORE: f64 = 0.33;
+
+/// Number of the best n-gram candidates that are rated with the Levenshtein distance
+const CANDIDATE_LIMIT: usize = 50;
+
+/// Inverted index of the n-grams of one size
+struct GramIndex {
+    size: usize,
+    postings: HashMap<String, Vec<(usize, usize)>>,
+    norms: Vec<f64>
+}
+
+/// Set of strings that finds the ones most similar to a query, after the fuzzyset.js library
+///
+/// Strings are lowercased and padded with dashes, so that n-grams at the start and the end
+/// carry more weight. A query first collects the items whose n-gram profiles have the highest
+/// cosine similarity with its own, starting from the largest n-gram size, and then rates the
+/// best of them with the [`normalized_levenshtein`](crate::normalized_levenshtein) of the
+/// lowercased strings. Smaller n-gram sizes are only tried when the larger ones find nothing.
+/// # Example
+/// ```
+/// use similar_string::FuzzySet;
+///
+/// let mut set = FuzzySet::new();
+/// set.add("Michael Axiak");
+/// set.add("Michaela Jones");
+///
+/// let found = set.get("micael asiak");
+/// (found[0].value, found[0].score); // ("Michael Axiak", 0.8461538461538461)
+/// ```
+pub struct FuzzySet {
+    items: Vec<String>,
+    lowercase: HashMap<String, usize>,
+    lowercase_items: Vec<String>,
+    indexes: Vec<GramIndex>,
+    min_score: f64
+}
+
+/// Lowercased string padded with dashes on both sides
+fn padded(text: &str) -> String {
+    format!("-{}-", text.to_lowercase())
+}
+
+impl FuzzySet {
+    /// Create an empty set that compares n-grams of two and three characters
+    pub fn new() -> Self {
+        FuzzySet::with_gram_sizes(2, 3)
+    }
+
+    /// Create an empty set that compares n-grams of sizes from `min` to `max` characters
+    /// # Panics
+    /// This function panics if `min` is zero or greater than `max`
+    pub fn with_gram_sizes(min: usize, max: usize) -> Self {
+        assert!(min > 0 && min <= max, "n-gram sizes must be a non-empty range above zero");
+        FuzzySet {
+            items: vec![],
+            lowercase: HashMap::new(),
+            lowercase_items: vec![],
+            indexes: (min..=max).rev().map(|size| GramIndex { size, postings: HashMap::new(), norms: vec![] }).collect(),
+            min_score: DEFAULT_FUZZY_SET_SCORE
+        }
+    }
+
+    /// Only return matches that rate at least `min_score`
+    pub fn min_score(mut self, min_score: f64) -> Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Get the number of strings in the set
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check whether the set has no strings
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Add a string to the set
+    ///
+    /// Returns `false` and leaves the set unchanged if a string that differs
+    /// only in letter case is already there.
+    pub fn add(&mut self, text: impl AsRef<str>) -> bool {
+        let text = text.as_ref();
+        let lowercase = text.to_lowercase();
+        if self.lowercase.contains_key(&lowercase) {
+            return false;
+        }
+        let id = self.items.len();
+        let padded = padded(text);
+        for index in &mut self.indexes {
+            let profile = ngram_profile(&padded, index.size);
+            index.norms.push(profile.values().map(|count| (count * count) as f64).sum::<f64>().sqrt());
+            for (gram, count) in profile {
+                index.postings.entry(gram.to_string()).or_default().push((id, count));
+            }
+        }
+        self.items.push(text.to_string());
+        self.lowercase.insert(lowercase.clone(), id);
+        self.lowercase_items.push(lowercase);
+        true
+    }
+
+    /// Check whether the set has the string, ignoring the letter case
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
+        self.lowercase.contains_key(&text.as_ref().to_lowercase())
+    }
+
+    /// Get the strings of the set that are the most similar to the query
+    ///
+    /// The matches are sorted from the best one, matches with equal ratings keep
+    /// the order in which the strings were added. The index of a match is
+    /// the number of strings added before it.
+    pub fn get(&self, query: impl AsRef<str>) -> Vec<Match<'_>> {
+        let query = query.as_ref();
+        let lowercase = query.to_lowercase();
+        // Exact matches win right away
+        if let Some(&index) = self.lowercase.get(&lowercase) {
+            return vec![Match { index, value: &self.items[index], score: 1.0 }];
+        }
+        let padded = padded(query);
+        for index in &self.indexes {
+            let profile = ngram_profile(&padded, index.size);
+            let norm = profile.values().map(|count| (count * count) as f64).sum::<f64>().sqrt();
+            let mut dots: HashMap<usize, usize> = HashMap::new();
+            for (gram, count) in &profile {
+                for (id, other) in index.postings.get(*gram).into_iter().flatten() {
+                    *dots.entry(*id).or_insert(0) += count * other;
+                }
+            }
+            let mut candidates: Vec<(usize, f64)> = dots.into_iter()
+                .map(|(id, dot)| (id, dot as f64 / (norm * index.norms[id])))
+                .collect();
+            candidates.sort_by(|(left_id, left), (right_id, right)| right.total_cmp(left).then(left_id.cmp(right_id)));
+            candidates.truncate(CANDIDATE_LIMIT);
+            let mut result: Vec<Match<'_>> = candidates.into_iter()
+                .map(|(id, _)| Match {
+                    index: id,
+                    value: &self.items[id],
+                    score: normalized_levenshtein(&lowercase, &self.lowercase_items[id])
+                })
+                .filter(|found| found.score >= self.min_score)
+                .collect();
+            if !result.is_empty() {
+                result.sort_by(|left, right| right.score.total_cmp(&left.score).then(left.index.cmp(&right.index)));
+                return result;
+            }
+        }
+        vec![]
+    }
+
+    /// Iterate over the strings in the order in which they were added
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().map(String::as_str)
+    }
+}
+
+impl Default for FuzzySet {
+    fn default() -> Self {
+        FuzzySet::new()
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for FuzzySet {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, items: I) {
+        for item in items {
+            self.add(item);
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for FuzzySet {
+    fn from_iter<I: IntoIterator<Item = S>>(items: I) -> Self {
+        let mut set = FuzzySet::new();
+        set.extend(items);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn fuzzy_set_finds_similar() {
+        let set: FuzzySet = ["Michael Axiak", "Michaela Jones", "Bob", "Alice"].into_iter().collect();
+        let found = set.get("micael asiak");
+        assert_eq!((found[0].index, found[0].value), (0, "Michael Axiak"));
+        assert_eq!(found[0].score, 1.0 - 2.0 / 13.0);
+        assert_eq!(set.get("BOB"), vec![Match { index: 2, value: "Bob", score: 1.0 }]);
+        assert!(set.get("xyz").is_empty());
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec!["Michael Axiak", "Michaela Jones", "Bob", "Alice"]);
+    }
+
+    #[test]
+    fn fuzzy_set_options() {
+        let mut set = FuzzySet::with_gram_sizes(1, 2).min_score(0.9);
+        assert!(set.is_empty());
+        assert!(set.add("Night"));
+        assert!(!set.add("NIGHT"));
+        assert!(set.add("light"));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains("night") && !set.contains("fight"));
+        assert!(set.get("fight").is_empty());
+        let set = set.min_score(0.5);
+        let values: Vec<&str> = set.get("fight").iter().map(|found| found.value).collect();
+        assert_eq!(values, vec!["Night", "light"]);
+    }
+}
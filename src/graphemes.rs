@@ -0,0 +1,183 @@
+//! Comparison of strings by extended grapheme clusters
+//!
+//! The functions of the crate root compare strings character by character, so emoji
+//! sequences, flags and letters with combining marks count as several units.
+//! The functions of this module split strings into grapheme clusters with
+//! `unicode-segmentation` instead, so that every user-perceived character is one unit.
+//! Ratings are normalized by the number of grapheme clusters.
+//!
+//! This module is available with the `unicode` feature.
+//! # Example
+//! ```
+//! use similar_string::graphemes;
+//!
+//! // The family emoji is a single grapheme made of five characters
+//! graphemes::lcs_length("👨‍👩‍👧 family", "👨‍👩‍👦 family"); // 7
+//! graphemes::compare_similarity("e\u{301}te\u{301}", "ete"); // 0.3333333333333333
+//! ```
+
+use std::cmp::max;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::damerau::{damerau_levenshtein_slices, osa_slices};
+use crate::error::LengthMismatchError;
+use crate::jaro::{jaro_slices, jaro_winkler_slices, DEFAULT_PREFIX_SCALE};
+use crate::lcs::{lcs_length_slices, lcs_pairs};
+use crate::levenshtein::levenshtein_slices;
+use crate::ratcliff::SequenceIndex;
+
+/// Split the string into extended grapheme clusters
+pub fn split(text: &str) -> Vec<&str> {
+    text.graphemes(true).collect()
+}
+
+/// Number of extended grapheme clusters of the string
+pub fn count(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// Turn a distance into a rating, normalized by the grapheme count of the longer string
+fn normalize(distance: usize, left: usize, right: usize) -> f64 {
+    let size = max(left, right);
+    // Empty strings should match
+    if size == 0 { 1.0 } else { 1.0 - distance as f64 / size as f64 }
+}
+
+/// Get length of the longest common subsequence of grapheme clusters
+pub fn lcs_length(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
+    lcs_length_slices(&split(left.as_ref()), &split(right.as_ref()))
+}
+
+/// Get the longest common subsequence of grapheme clusters
+pub fn lcs(left: impl AsRef<str>, right: impl AsRef<str>) -> String {
+    let left = split(left.as_ref());
+    let right = split(right.as_ref());
+    lcs_pairs(&left, &right).into_iter()
+        .map(|(index, _)| left[index])
+        .collect()
+}
+
+/// Get score of similarity of two strings based on the longest common subsequence of grapheme clusters
+pub fn compare_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left = split(left.as_ref());
+    let right = split(right.as_ref());
+    let size = max(left.len(), right.len());
+    // Empty strings should match
+    if size == 0 { 1.0 } else { lcs_length_slices(&left, &right) as f64 / size as f64 }
+}
+
+/// Find the option that is the most similar to the target one, comparing grapheme clusters
+///
+/// The first option wins a tie and `None` is returned if there are no options.
+pub fn find_best_similarity(target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Option<(String, f64)> {
+    let mut best: Option<(String, f64)> = None;
+    for option in options {
+        let score = compare_similarity(option.as_ref(), target.as_ref());
+        if best.as_ref().is_none_or(|(_, high_score)| score > *high_score) {
+            best = Some((option.as_ref().to_string(), score));
+        }
+    }
+    best
+}
+
+/// Get all similarity scores against the target string, comparing grapheme clusters
+///
+/// Returns `None` if there are no options.
+pub fn get_similarity_ratings(target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Option<Vec<f64>> {
+    let result: Vec<f64> = options.into_iter()
+        .map(|option| compare_similarity(option.as_ref(), target.as_ref()))
+        .collect();
+    if result.is_empty() { None } else { Some(result) }
+}
+
+/// Get the Levenshtein edit distance of two strings in grapheme clusters
+pub fn levenshtein_distance(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
+    levenshtein_slices(&split(left.as_ref()), &split(right.as_ref()))
+}
+
+/// Get score of similarity based on the Levenshtein distance of grapheme clusters
+pub fn normalized_levenshtein(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left = split(left.as_ref());
+    let right = split(right.as_ref());
+    normalize(levenshtein_slices(&left, &right), left.len(), right.len())
+}
+
+/// Get the optimal string alignment distance of two strings in grapheme clusters
+pub fn osa_distance(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
+    osa_slices(&split(left.as_ref()), &split(right.as_ref()))
+}
+
+/// Get score of similarity based on the optimal string alignment distance of grapheme clusters
+pub fn normalized_osa(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left = split(left.as_ref());
+    let right = split(right.as_ref());
+    normalize(osa_slices(&left, &right), left.len(), right.len())
+}
+
+/// Get the Damerau-Levenshtein distance of two strings in grapheme clusters
+pub fn damerau_levenshtein_distance(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
+    damerau_levenshtein_slices(&split(left.as_ref()), &split(right.as_ref()))
+}
+
+/// Get score of similarity based on the Damerau-Levenshtein distance of grapheme clusters
+pub fn normalized_damerau_levenshtein(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left = split(left.as_ref());
+    let right = split(right.as_ref());
+    normalize(damerau_levenshtein_slices(&left, &right), left.len(), right.len())
+}
+
+/// Get the Jaro similarity of grapheme clusters of two strings
+pub fn jaro_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    jaro_slices(&split(left.as_ref()), &split(right.as_ref()))
+}
+
+/// Get the Jaro-Winkler similarity of grapheme clusters of two strings
+pub fn jaro_winkler_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    jaro_winkler_slices(&split(left.as_ref()), &split(right.as_ref()), DEFAULT_PREFIX_SCALE)
+}
+
+/// Get the Hamming distance of two strings with the same number of grapheme clusters
+pub fn hamming_distance(left: impl AsRef<str>, right: impl AsRef<str>) -> Result<usize, LengthMismatchError> {
+    let left = split(left.as_ref());
+    let right = split(right.as_ref());
+    if left.len() != right.len() {
+        return Err(LengthMismatchError { left: left.len(), right: right.len() });
+    }
+    Ok(left.iter().zip(&right).filter(|(lletter, rletter)| lletter != rletter).count())
+}
+
+/// Get the Ratcliff-Obershelp similarity of grapheme clusters of two strings
+pub fn ratcliff_obershelp_ratio(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let index = SequenceIndex::new(split(right.as_ref()));
+    index.ratio(&split(left.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graphemes;
+
+    #[test]
+    fn graphemes_are_single_units() {
+        let family = "👨‍👩‍👧";
+        assert_eq!(family.chars().count(), 5);
+        assert_eq!(graphemes::count(family), 1);
+        assert_eq!(graphemes::lcs_length("👨‍👩‍👧 family", "👨‍👩‍👦 family"), 7);
+        assert_eq!(graphemes::levenshtein_distance("🇵🇱🇩🇪", "🇵🇱🇫🇷"), 1);
+        assert_eq!(graphemes::compare_similarity("e\u{301}te\u{301}", "ete"), 1.0 / 3.0);
+        assert_eq!(graphemes::hamming_distance("🇵🇱a", "🇩🇪a"), Ok(1));
+        assert!(graphemes::hamming_distance("🇵🇱", "ab").is_err());
+    }
+
+    #[test]
+    fn grapheme_search() {
+        let options = ["🇩🇪 berlin", "🇵🇱 warsaw"];
+        let (best, _) = graphemes::find_best_similarity("🇵🇱 warsow", options).unwrap();
+        assert_eq!(best, "🇵🇱 warsaw");
+        assert_eq!(graphemes::get_similarity_ratings("x", [] as [&str; 0]), None);
+        assert_eq!(graphemes::lcs("a🇵🇱b", "🇵🇱b"), "🇵🇱b");
+        assert_eq!(graphemes::normalized_levenshtein("", ""), 1.0);
+        assert_eq!(graphemes::jaro_similarity("🇵🇱", "🇵🇱"), 1.0);
+        assert_eq!(graphemes::ratcliff_obershelp_ratio("🇵🇱a", "🇵🇱b"), 0.5);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::LengthMismatchError;
+
+/// Get the Hamming distance of two strings of equal length
+///
+/// The distance is the number of positions at which the characters differ.
+/// Strings are compared in a single pass without allocating.
+///
+/// This function returns [`LengthMismatchError`] if the strings differ in character count
+/// # Example
+/// ```
+/// use similar_string::hamming_distance;
+///
+/// hamming_distance("karolin", "kathrin"); // Ok(3)
+/// hamming_distance("abc", "abcd").is_err(); // true
+/// ```
+pub fn hamming_distance(left: impl AsRef<str>, right: impl AsRef<str>) -> Result<usize, LengthMismatchError> {
+    let mut left = left.as_ref().chars();
+    let mut right = right.as_ref().chars();
+    let mut distance = 0;
+    let mut length = 0;
+    loop {
+        match (left.next(), right.next()) {
+            (Some(lletter), Some(rletter)) => {
+                if lletter != rletter {
+                    distance += 1;
+                }
+                length += 1;
+            }
+            (None, None) => return Ok(distance),
+            (Some(_), None) => return Err(LengthMismatchError {
+                left: length + 1 + left.count(),
+                right: length
+            }),
+            (None, Some(_)) => return Err(LengthMismatchError {
+                left: length,
+                right: length + 1 + right.count()
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn hamming_works() {
+        assert_eq!(hamming_distance("karolin", "kathrin"), Ok(3));
+        assert_eq!(hamming_distance("", ""), Ok(0));
+        assert_eq!(hamming_distance("żółw", "zółw"), Ok(1));
+    }
+
+    #[test]
+    fn hamming_length_mismatch() {
+        let error = hamming_distance("abc", "abcde").unwrap_err();
+        assert_eq!(error, LengthMismatchError { left: 3, right: 5 });
+        let error = hamming_distance("abcd", "a").unwrap_err();
+        assert_eq!(error, LengthMismatchError { left: 4, right: 1 });
+    }
+}
@@ -0,0 +1,58 @@
+use crate::lcs::lcs_pairs;
+use crate::search::{best_rated, Match};
+
+/// Get the character indices of the candidate that match the target
+///
+/// These are the characters of the longest common subsequence that [`compare_similarity`](crate::compare_similarity)
+/// counts, in increasing order, so that a user interface can highlight them.
+/// When there are several longest common subsequences, the positions of one of them are returned.
+/// # Example
+/// ```
+/// use similar_string::matched_positions;
+///
+/// // "i", "g", "h" and "t" of "fight" are found in "night"
+/// matched_positions("fight", "night"); // [1, 2, 3, 4]
+/// ```
+pub fn matched_positions(target: impl AsRef<str>, candidate: impl AsRef<str>) -> Vec<usize> {
+    let target: Vec<char> = target.as_ref().chars().collect();
+    let candidate: Vec<char> = candidate.as_ref().chars().collect();
+    lcs_pairs(&target, &candidate).into_iter().map(|(_, position)| position).collect()
+}
+
+/// Find the option that is the most similar to the target string together with its matched characters
+///
+/// Works like [`find_best_match`](crate::find_best_match) and also returns the
+/// [`matched_positions`] of the best option. Returns `None` if there are no options.
+/// # Example
+/// ```
+/// use similar_string::find_best_match_with_positions;
+///
+/// let options = vec!["fill", "night", "ride"];
+///
+/// let (best, positions) = find_best_match_with_positions("fight", &options).unwrap();
+/// (best.value, positions); // ("night", [1, 2, 3, 4])
+/// ```
+pub fn find_best_match_with_positions<'a, S: AsRef<str> + ?Sized + 'a>(target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>) -> Option<(Match<'a>, Vec<usize>)> {
+    let target = target.as_ref();
+    let (index, value, score) = best_rated(target, options)?;
+    let value = value.as_ref();
+    Some((Match { index, value, score }, matched_positions(target, value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn positions_of_matches() {
+        assert_eq!(matched_positions("fight", "night"), vec![1, 2, 3, 4]);
+        assert_eq!(matched_positions("longest", "stone"), vec![2, 3, 4]);
+        assert_eq!(matched_positions("żółw", "wół żółwia"), vec![4, 5, 6, 7]);
+        assert!(matched_positions("abc", "xyz").is_empty());
+        let options = vec!["fill", "night", "ride"];
+        let (best, positions) = find_best_match_with_positions("fight", &options).unwrap();
+        assert_eq!(best, find_best_match("fight", &options).unwrap());
+        assert_eq!(positions.len() as f64 / 5.0, best.score);
+        assert_eq!(find_best_match_with_positions("fight", &[] as &[&str]), None);
+    }
+}
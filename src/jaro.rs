@@ -0,0 +1,145 @@
+use std::cmp::{max, min};
+
+/// Default prefix scaling factor used by [`jaro_winkler_similarity`]
+pub const DEFAULT_PREFIX_SCALE: f64 = 0.1;
+
+/// Jaro similarity over arbitrary sequences
+pub(crate) fn jaro_slices<T: PartialEq>(left: &[T], right: &[T]) -> f64 {
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+    let window = (max(left.len(), right.len()) / 2).saturating_sub(1);
+    let mut left_matched = vec![false; left.len()];
+    let mut right_matched = vec![false; right.len()];
+    let mut matches = 0;
+
+    for (lindex, lletter) in left.iter().enumerate() {
+        let start = lindex.saturating_sub(window);
+        let end = min(lindex + window + 1, right.len());
+        for rindex in start..end {
+            if !right_matched[rindex] && right[rindex] == *lletter {
+                left_matched[lindex] = true;
+                right_matched[rindex] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // Count matched characters that appear in a different order
+    let mut transpositions = 0;
+    let mut rindex = 0;
+    for (lindex, lletter) in left.iter().enumerate() {
+        if !left_matched[lindex] {
+            continue;
+        }
+        while !right_matched[rindex] {
+            rindex += 1;
+        }
+        if *lletter != right[rindex] {
+            transpositions += 1;
+        }
+        rindex += 1;
+    }
+
+    let matches = matches as f64;
+    let transpositions = (transpositions / 2) as f64;
+    (matches / left.len() as f64 + matches / right.len() as f64 + (matches - transpositions) / matches) / 3.0
+}
+
+/// Jaro-Winkler similarity over arbitrary sequences
+pub(crate) fn jaro_winkler_slices<T: PartialEq>(left: &[T], right: &[T], prefix_scale: f64) -> f64 {
+    let jaro = jaro_slices(left, right);
+    let prefix = left.iter()
+        .zip(right.iter())
+        .take(4)
+        .take_while(|(lletter, rletter)| lletter == rletter)
+        .count();
+    let prefix_scale = prefix_scale.clamp(0.0, 0.25);
+    jaro + prefix as f64 * prefix_scale * (1.0 - jaro)
+}
+
+/// Get the Jaro similarity of two strings
+///
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// This metric works well on short strings such as names.
+/// # Example
+/// ```
+/// use similar_string::jaro_similarity;
+///
+/// jaro_similarity("martha", "marhta"); // 0.9444444444444445
+/// ```
+pub fn jaro_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    jaro_slices(&left, &right)
+}
+
+/// Get the Jaro-Winkler similarity of two strings
+///
+/// This is the Jaro similarity that additionally rewards strings sharing
+/// a common prefix of up to 4 characters. Uses the [`DEFAULT_PREFIX_SCALE`].
+/// # Example
+/// ```
+/// use similar_string::jaro_winkler_similarity;
+///
+/// jaro_winkler_similarity("martha", "marhta"); // 0.9611111111111111
+/// ```
+pub fn jaro_winkler_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    jaro_winkler_similarity_with_scale(left, right, DEFAULT_PREFIX_SCALE)
+}
+
+/// Get the Jaro-Winkler similarity of two strings with a custom prefix scaling factor
+///
+/// The `prefix_scale` is clamped to the range from 0.0 to 0.25
+/// so that the rating never exceeds 1.0.
+/// # Example
+/// ```
+/// use similar_string::jaro_winkler_similarity_with_scale;
+///
+/// jaro_winkler_similarity_with_scale("martha", "marhta", 0.2); // 0.9777777777777777
+/// ```
+pub fn jaro_winkler_similarity_with_scale(left: impl AsRef<str>, right: impl AsRef<str>, prefix_scale: f64) -> f64 {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    jaro_winkler_slices(&left, &right, prefix_scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn round(value: f64) -> f64 {
+        (value * 1000.0).round() / 1000.0
+    }
+
+    #[test]
+    fn jaro_works() {
+        assert_eq!(round(jaro_similarity("martha", "marhta")), 0.944);
+        assert_eq!(round(jaro_similarity("dixon", "dicksonx")), 0.767);
+        assert_eq!(jaro_similarity("", ""), 1.0);
+        assert_eq!(jaro_similarity("abc", ""), 0.0);
+        assert_eq!(jaro_similarity("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn jaro_winkler_works() {
+        assert_eq!(round(jaro_winkler_similarity("martha", "marhta")), 0.961);
+        assert_eq!(round(jaro_winkler_similarity("dixon", "dicksonx")), 0.813);
+        assert_eq!(jaro_winkler_similarity("hello", "hello"), 1.0);
+    }
+
+    #[test]
+    fn jaro_winkler_scale_is_clamped() {
+        let score = jaro_winkler_similarity_with_scale("prefixed", "prefixes", 10.0);
+        assert!(score <= 1.0);
+        let plain = jaro_winkler_similarity_with_scale("martha", "marhta", 0.0);
+        assert_eq!(plain, jaro_similarity("martha", "marhta"));
+    }
+}
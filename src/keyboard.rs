@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use crate::weighted::EditCosts;
+
+/// Default cost of substituting a key with one of its neighbours
+pub const DEFAULT_ADJACENT_COST: f64 = 0.5;
+
+/// Physical keyboard layout that makes typos of neighbouring keys cheaper
+///
+/// Every key has a position given by its row and its column shifted by the stagger of the row.
+/// Two keys are neighbours when they are at most one row and one key width apart.
+/// Substituting a key with a neighbour costs [`DEFAULT_ADJACENT_COST`] by default,
+/// any other edit costs 1.0. Letter case is ignored when looking up the keys.
+/// # Example
+/// ```
+/// use similar_string::{KeyboardLayout, weighted_levenshtein};
+///
+/// let qwerty = KeyboardLayout::qwerty();
+/// // "g" is next to "f", but "p" is far away
+/// weighted_levenshtein("fun", "gun", &qwerty); // 0.5
+/// weighted_levenshtein("fun", "pun", &qwerty); // 1.0
+/// qwerty.is_adjacent('q', 'a'); // true
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardLayout {
+    positions: HashMap<char, (f64, f64)>,
+    adjacent_cost: f64
+}
+
+impl KeyboardLayout {
+    /// Create a layout from rows of keys and the horizontal stagger of every row in key widths
+    pub fn from_rows<S: AsRef<str>>(rows: impl IntoIterator<Item = (S, f64)>) -> Self {
+        let mut positions = HashMap::new();
+        for (row, (keys, stagger)) in rows.into_iter().enumerate() {
+            for (col, key) in keys.as_ref().chars().enumerate() {
+                positions.insert(key, (row as f64, col as f64 + stagger));
+            }
+        }
+        KeyboardLayout { positions, adjacent_cost: DEFAULT_ADJACENT_COST }
+    }
+
+    /// The US QWERTY layout
+    pub fn qwerty() -> Self {
+        KeyboardLayout::from_rows([
+            ("1234567890-=", 0.0),
+            ("qwertyuiop[]", 0.5),
+            ("asdfghjkl;'", 0.75),
+            ("zxcvbnm,./", 1.25)
+        ])
+    }
+
+    /// The German QWERTZ layout
+    pub fn qwertz() -> Self {
+        KeyboardLayout::from_rows([
+            ("1234567890ß", 0.0),
+            ("qwertzuiopü+", 0.5),
+            ("asdfghjklöä#", 0.75),
+            ("<yxcvbnm,.-", 0.25)
+        ])
+    }
+
+    /// The French AZERTY layout
+    pub fn azerty() -> Self {
+        KeyboardLayout::from_rows([
+            ("&é\"'(-è_çà)=", 0.0),
+            ("azertyuiop^$", 0.5),
+            ("qsdfghjklmù*", 0.75),
+            ("<wxcvbn,;:!", 0.25)
+        ])
+    }
+
+    /// Set the cost of substituting a key with one of its neighbours
+    pub fn with_adjacent_cost(mut self, cost: f64) -> Self {
+        self.adjacent_cost = cost;
+        self
+    }
+
+    fn position(&self, key: char) -> Option<(f64, f64)> {
+        self.positions.get(&key)
+            .or_else(|| key.to_lowercase().next().and_then(|key| self.positions.get(&key)))
+            .copied()
+    }
+
+    /// Check whether two different keys are neighbours on the keyboard
+    pub fn is_adjacent(&self, left: char, right: char) -> bool {
+        match (self.position(left), self.position(right)) {
+            (Some(left), Some(right)) => {
+                let (rows, cols) = ((left.0 - right.0).abs(), (left.1 - right.1).abs());
+                left != right && rows <= 1.0 && cols <= 1.0
+            }
+            _ => false
+        }
+    }
+}
+
+impl EditCosts for KeyboardLayout {
+    fn substitution(&self, from: char, to: char) -> f64 {
+        if self.is_adjacent(from, to) { self.adjacent_cost } else { 1.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn qwerty_neighbours() {
+        let qwerty = KeyboardLayout::qwerty();
+        for (left, right) in [('q', 'w'), ('q', 'a'), ('a', 'z'), ('g', 't'), ('g', 'y'), ('g', 'b'), ('G', 'h')] {
+            assert!(qwerty.is_adjacent(left, right), "{} {}", left, right);
+        }
+        for (left, right) in [('q', 'p'), ('a', 'x'), ('q', 'z'), ('a', 'a'), ('a', '€')] {
+            assert!(!qwerty.is_adjacent(left, right), "{} {}", left, right);
+        }
+    }
+
+    #[test]
+    fn adjacent_typos_are_cheaper() {
+        let qwerty = KeyboardLayout::qwerty();
+        assert_eq!(weighted_levenshtein("paint", "painy", &qwerty), 0.5);
+        assert_eq!(weighted_levenshtein("paint", "paina", &qwerty), 1.0);
+        let custom = KeyboardLayout::qwerty().with_adjacent_cost(0.2);
+        assert_eq!(weighted_levenshtein("fun", "gun", &custom), 0.2);
+        assert!(normalized_weighted_levenshtein("paint", "painy", &qwerty) > normalized_levenshtein("paint", "painy"));
+    }
+
+    #[test]
+    fn other_layouts() {
+        assert!(KeyboardLayout::qwertz().is_adjacent('z', 'u'));
+        assert!(KeyboardLayout::azerty().is_adjacent('a', 'q'));
+        let custom = KeyboardLayout::from_rows([("abc", 0.0), ("def", 0.0)]);
+        assert!(custom.is_adjacent('a', 'e'));
+        assert!(!custom.is_adjacent('a', 'f'));
+    }
+}
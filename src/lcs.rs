@@ -0,0 +1,485 @@
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+// Without std there is no hasher, so ordered collections take the place of the hashed ones
+#[cfg(not(feature = "std"))]
+use alloc::collections::{BTreeMap as HashMap, BTreeSet as HashSet};
+
+#[cfg(feature = "simd")]
+use crate::simd::{forward_row, backward_row};
+
+/// Bit masks of character positions in a pattern, used by the bit-parallel algorithms
+///
+/// Every bit of a mask matches one row of the dynamic programming table, so
+/// the algorithms process 64 rows per machine word,
+/// which makes computing the length much faster than the plain DP.
+pub(crate) struct PatternMasks {
+    ascii: Vec<Vec<u64>>,
+    other: HashMap<char, Vec<u64>>,
+    words: usize,
+    len: usize
+}
+
+impl PatternMasks {
+    pub(crate) fn new(pattern: &[char]) -> Self {
+        let words = pattern.len().div_ceil(64);
+        let mut ascii = vec![vec![]; 128];
+        let mut other: HashMap<char, Vec<u64>> = HashMap::new();
+        for (index, letter) in pattern.iter().enumerate() {
+            let mask = if letter.is_ascii() {
+                &mut ascii[*letter as usize]
+            } else {
+                other.entry(*letter).or_default()
+            };
+            if mask.is_empty() {
+                mask.resize(words, 0);
+            }
+            mask[index / 64] |= 1 << (index % 64);
+        }
+        PatternMasks { ascii, other, words, len: pattern.len() }
+    }
+
+    /// Number of 64 bit words of every mask
+    #[inline]
+    pub(crate) fn words(&self) -> usize {
+        self.words
+    }
+
+    /// Number of characters of the pattern
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Mask of positions of the given character, if it appears in the pattern
+    #[inline]
+    pub(crate) fn get(&self, letter: char) -> Option<&[u64]> {
+        let mask = if letter.is_ascii() {
+            &self.ascii[letter as usize]
+        } else {
+            self.other.get(&letter)?
+        };
+        if mask.is_empty() { None } else { Some(mask) }
+    }
+
+    /// Process the next character of the text
+    #[inline]
+    fn advance(&self, vector: &mut [u64], letter: char) {
+        let Some(mask) = self.get(letter) else { return };
+        let mut carry = 0;
+        for (word, mask) in vector.iter_mut().zip(mask) {
+            let matched = *word & mask;
+            let (sum, overflow_left) = word.overflowing_add(matched);
+            let (sum, overflow_right) = sum.overflowing_add(carry);
+            carry = (overflow_left || overflow_right) as u64;
+            *word = sum | (*word & !mask);
+        }
+    }
+
+    /// Length of the subsequence encoded in the vector
+    #[inline]
+    fn count(&self, vector: &[u64]) -> usize {
+        let ones: usize = vector.iter().map(|word| word.count_ones() as usize).sum();
+        // Bits past the end of the pattern have empty masks, so they always stay set
+        let padding = self.words * 64 - self.len;
+        self.len + padding - ones
+    }
+
+    /// Length of the longest common subsequence of the pattern and the text
+    pub(crate) fn lcs_length(&self, text: impl IntoIterator<Item = char>) -> usize {
+        // Zero bits of the vector mark columns where the LCS length grows
+        let mut vector = vec![u64::MAX; self.words];
+        for letter in text {
+            self.advance(&mut vector, letter);
+        }
+        self.count(&vector)
+    }
+
+    /// Length of the longest common subsequence if it is at least `min_required`
+    ///
+    /// Stops as soon as the remaining characters of the text can no longer
+    /// extend the subsequence to the required length.
+    pub(crate) fn lcs_length_bounded(&self, text: &[char], min_required: usize) -> Option<usize> {
+        if min(self.len, text.len()) < min_required {
+            return None;
+        }
+        let mut vector = vec![u64::MAX; self.words];
+        for (index, letter) in text.iter().enumerate() {
+            self.advance(&mut vector, *letter);
+            // Counting is not free, so the bound is only checked every few characters
+            if index % BOUND_CHECK_INTERVAL == 0 && self.count(&vector) + text.len() - index - 1 < min_required {
+                return None;
+            }
+        }
+        let length = self.count(&vector);
+        (length >= min_required).then_some(length)
+    }
+}
+
+/// Number of characters processed between the checks of [`PatternMasks::lcs_length_bounded`]
+const BOUND_CHECK_INTERVAL: usize = 16;
+
+/// Full table of longest common subsequence lengths of all prefixes
+pub(crate) fn lcs_table<T: PartialEq>(left: &[T], right: &[T]) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; right.len() + 1]; left.len() + 1];
+    for (row, lletter) in left.iter().enumerate() {
+        for (col, rletter) in right.iter().enumerate() {
+            table[row + 1][col + 1] = if lletter == rletter {
+                table[row][col] + 1
+            } else {
+                max(table[row][col + 1], table[row + 1][col])
+            };
+        }
+    }
+    table
+}
+
+/// Get length of the longest common subsequence of arbitrary sequences
+///
+/// Works with any elements that can be compared, such as words, bytes or grapheme clusters.
+/// The table is computed row by row in O(n * m) time and O(min(n, m)) memory.
+/// # Example
+/// ```
+/// use similar_string::lcs_length_slices;
+///
+/// let left = ["the", "quick", "brown", "fox"];
+/// let right = ["the", "brown", "dog"];
+/// lcs_length_slices(&left, &right); // 2
+/// lcs_length_slices(b"longest", b"stone"); // 3
+/// ```
+pub fn lcs_length_slices<T: PartialEq>(left: &[T], right: &[T]) -> usize {
+    lcs_length_slices_by(left, right, |lletter, rletter| lletter == rletter)
+}
+
+/// Length of the longest common subsequence with a custom equality of elements
+pub(crate) fn lcs_length_slices_by<L, R>(left: &[L], right: &[R], equal: impl Fn(&L, &R) -> bool) -> usize {
+    // Keep the row as long as the shorter sequence, the closure still gets the arguments in order
+    if left.len() < right.len() {
+        lcs_row(left, right, equal)
+    } else {
+        lcs_row(right, left, |rletter, lletter| equal(lletter, rletter))
+    }
+}
+
+fn lcs_row<S, L>(short: &[S], long: &[L], equal: impl Fn(&S, &L) -> bool) -> usize {
+    let mut previous = vec![0; short.len() + 1];
+    let mut current = vec![0; short.len() + 1];
+    for lletter in long {
+        for (col, sletter) in short.iter().enumerate() {
+            current[col + 1] = if equal(sletter, lletter) {
+                previous[col] + 1
+            } else {
+                max(previous[col + 1], current[col])
+            };
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[short.len()]
+}
+
+/// Inputs with more table cells than this are reconstructed with Hirschberg's algorithm
+const TABLE_CELLS_LIMIT: usize = 1 << 20;
+
+/// Traceback through the full table, appending pairs shifted by the given offsets
+fn table_pairs<T: PartialEq>(left: &[T], right: &[T], offset: (usize, usize), pairs: &mut Vec<(usize, usize)>) {
+    let table = lcs_table(left, right);
+    let (mut row, mut col) = (left.len(), right.len());
+    let start = pairs.len();
+    while row > 0 && col > 0 {
+        if left[row - 1] == right[col - 1] {
+            row -= 1;
+            col -= 1;
+            pairs.push((offset.0 + row, offset.1 + col));
+        } else if table[row - 1][col] >= table[row][col - 1] {
+            row -= 1;
+        } else {
+            col -= 1;
+        }
+    }
+    pairs[start..].reverse();
+}
+
+/// Last row of the LCS table of `left` against all prefixes of `right`
+#[cfg(not(feature = "simd"))]
+fn forward_row<T: PartialEq>(left: &[T], right: &[T]) -> Vec<usize> {
+    let mut previous = vec![0; right.len() + 1];
+    let mut current = vec![0; right.len() + 1];
+    for lletter in left {
+        for (col, rletter) in right.iter().enumerate() {
+            current[col + 1] = if lletter == rletter {
+                previous[col] + 1
+            } else {
+                max(previous[col + 1], current[col])
+            };
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
+/// LCS lengths of `left` against all suffixes of `right`, indexed by the suffix start
+#[cfg(not(feature = "simd"))]
+fn backward_row<T: PartialEq>(left: &[T], right: &[T]) -> Vec<usize> {
+    let mut previous = vec![0; right.len() + 1];
+    let mut current = vec![0; right.len() + 1];
+    for lletter in left.iter().rev() {
+        for (col, rletter) in right.iter().enumerate().rev() {
+            current[col] = if lletter == rletter {
+                previous[col + 1] + 1
+            } else {
+                max(previous[col], current[col + 1])
+            };
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
+/// Hirschberg's divide and conquer reconstruction in linear memory
+fn hirschberg_pairs<T: PartialEq>(left: &[T], right: &[T], offset: (usize, usize), pairs: &mut Vec<(usize, usize)>) {
+    if left.is_empty() || right.is_empty() {
+        return;
+    }
+    if (left.len() + 1) * (right.len() + 1) <= TABLE_CELLS_LIMIT || left.len() == 1 {
+        return table_pairs(left, right, offset, pairs);
+    }
+    let middle = left.len() / 2;
+    let forward = forward_row(&left[..middle], right);
+    let backward = backward_row(&left[middle..], right);
+    let split = (0..=right.len())
+        .max_by_key(|&col| (forward[col] + backward[col], core::cmp::Reverse(col)))
+        .unwrap();
+    hirschberg_pairs(&left[..middle], &right[..split], offset, pairs);
+    hirschberg_pairs(&left[middle..], &right[split..], (offset.0 + middle, offset.1 + split), pairs);
+}
+
+/// Positions of matched elements of some longest common subsequence
+///
+/// Large inputs are handled with Hirschberg's algorithm which needs only linear memory.
+pub(crate) fn lcs_pairs<T: PartialEq>(left: &[T], right: &[T]) -> Vec<(usize, usize)> {
+    let mut pairs = vec![];
+    hirschberg_pairs(left, right, (0, 0), &mut pairs);
+    pairs
+}
+
+/// Get the longest common subsequence of two strings
+///
+/// When there are several longest common subsequences, only one of them is returned.
+/// This function takes O(n * m) time. Long strings are handled with Hirschberg's
+/// algorithm, so that memory usage stays linear in the length of the inputs.
+/// # Example
+/// ```
+/// use similar_string::lcs;
+///
+/// lcs("longest", "stone"); // "one"
+/// ```
+pub fn lcs(left: impl AsRef<str>, right: impl AsRef<str>) -> String {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    lcs_pairs(&left, &right).into_iter()
+        .map(|(index, _)| left[index])
+        .collect()
+}
+
+/// Get character indices of the longest common subsequence in both strings
+///
+/// Every pair holds the index of a matched character in the left and the right string.
+/// The pairs are sorted in the order of the subsequence.
+/// # Example
+/// ```
+/// use similar_string::lcs_indices;
+///
+/// // "o", "n" and "e" of "longest" match the same letters of "stone"
+/// lcs_indices("longest", "stone"); // [(1, 2), (2, 3), (4, 4)]
+/// ```
+pub fn lcs_indices(left: impl AsRef<str>, right: impl AsRef<str>) -> Vec<(usize, usize)> {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    lcs_pairs(&left, &right)
+}
+
+/// Get all distinct longest common subsequences of two strings
+///
+/// The subsequences are sorted lexicographically. Two strings may share an exponential
+/// number of longest common subsequences, so consider using [`all_lcs_with_limit`].
+/// If the strings share no characters, the only longest common subsequence is the empty string.
+/// # Example
+/// ```
+/// use similar_string::all_lcs;
+///
+/// all_lcs("ABCBDAB", "BDCABA"); // ["BCAB", "BCBA", "BDAB"]
+/// ```
+pub fn all_lcs(left: impl AsRef<str>, right: impl AsRef<str>) -> Vec<String> {
+    all_lcs_with_limit(left, right, usize::MAX)
+}
+
+/// Get up to `limit` distinct longest common subsequences of two strings
+///
+/// Returns the lexicographically smallest subsequences, sorted.
+/// Intermediate results are capped as well, which keeps memory usage in check.
+/// # Example
+/// ```
+/// use similar_string::all_lcs_with_limit;
+///
+/// all_lcs_with_limit("ABCBDAB", "BDCABA", 2); // ["BCAB", "BCBA"]
+/// ```
+pub fn all_lcs_with_limit(left: impl AsRef<str>, right: impl AsRef<str>, limit: usize) -> Vec<String> {
+    if limit == 0 {
+        return vec![];
+    }
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    let table = lcs_table(&left, &right);
+    let parents = |(row, col): (usize, usize)| -> Vec<(usize, usize)> {
+        if row == 0 || col == 0 {
+            vec![]
+        } else if left[row - 1] == right[col - 1] {
+            vec![(row - 1, col - 1)]
+        } else {
+            let mut result = vec![];
+            if table[row - 1][col] == table[row][col] {
+                result.push((row - 1, col));
+            }
+            if table[row][col - 1] == table[row][col] {
+                result.push((row, col - 1));
+            }
+            result
+        }
+    };
+
+    // Find the cells that lie on any optimal path
+    let mut reachable = HashSet::new();
+    let mut stack = vec![(left.len(), right.len())];
+    while let Some(cell) = stack.pop() {
+        if reachable.insert(cell) {
+            stack.extend(parents(cell));
+        }
+    }
+    let mut cells: Vec<(usize, usize)> = reachable.into_iter().collect();
+    cells.sort_unstable();
+
+    // Every parent precedes its child in the sorted order
+    let mut subsequences: HashMap<(usize, usize), BTreeSet<String>> = HashMap::new();
+    for cell in cells {
+        let (row, col) = cell;
+        let mut result = BTreeSet::new();
+        if row == 0 || col == 0 {
+            result.insert(String::new());
+        } else if left[row - 1] == right[col - 1] {
+            for prefix in &subsequences[&(row - 1, col - 1)] {
+                result.insert(format!("{prefix}{}", left[row - 1]));
+            }
+        } else {
+            for parent in parents(cell) {
+                result.extend(subsequences[&parent].iter().cloned());
+            }
+        }
+        while result.len() > limit {
+            result.pop_last();
+        }
+        subsequences.insert(cell, result);
+    }
+    subsequences.remove(&(left.len(), right.len()))
+        .unwrap_or_default()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn lcs_string_works() {
+        assert_eq!(lcs("longest", "stone"), "one");
+        assert_eq!(lcs("", "stone"), "");
+        assert_eq!(lcs("żółw", "żaba ółw"), "żółw");
+        assert_eq!(lcs("abc", "xyz"), "");
+    }
+
+    #[test]
+    fn lcs_slices_works() {
+        assert_eq!(lcs_length_slices(&["the", "quick", "brown", "fox"], &["the", "brown", "dog"]), 2);
+        assert_eq!(lcs_length_slices(b"longest", b"stone"), 3);
+        assert_eq!(lcs_length_slices::<u32>(&[], &[1, 2]), 0);
+    }
+
+    #[test]
+    fn all_lcs_works() {
+        assert_eq!(all_lcs("ABCBDAB", "BDCABA"), vec!["BCAB", "BCBA", "BDAB"]);
+        assert_eq!(all_lcs("abc", "xyz"), vec![""]);
+        assert_eq!(all_lcs("", ""), vec![""]);
+        assert_eq!(all_lcs("ab", "ba"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn all_lcs_limit() {
+        assert_eq!(all_lcs_with_limit("ABCBDAB", "BDCABA", 2), vec!["BCAB", "BCBA"]);
+        assert!(all_lcs_with_limit("ABCBDAB", "BDCABA", 0).is_empty());
+        // Each swapped pair doubles the number of subsequences
+        assert_eq!(all_lcs("abcdefghij", "badcfehgji").len(), 32);
+        assert_eq!(all_lcs_with_limit("abcdefghij", "badcfehgji", 5), vec!["acegi", "acegj", "acehi", "acehj", "acfgi"]);
+    }
+
+    #[test]
+    fn hirschberg_matches_table() {
+        let mut seed: u64 = 42;
+        let mut random = |limit: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % limit
+        };
+        for _ in 0..50 {
+            let left: Vec<u64> = (0..random(40)).map(|_| random(4)).collect();
+            let right: Vec<u64> = (0..random(40)).map(|_| random(4)).collect();
+            let (mut split, mut full) = (vec![], vec![]);
+            super::table_pairs(&left, &right, (0, 0), &mut full);
+            // Force the divide and conquer path on small inputs
+            let middle = left.len() / 2;
+            let forward = super::forward_row(&left[..middle], &right);
+            let backward = super::backward_row(&left[middle..], &right);
+            let col = (0..=right.len()).max_by_key(|&col| forward[col] + backward[col]).unwrap();
+            super::hirschberg_pairs(&left[..middle], &right[..col], (0, 0), &mut split);
+            super::hirschberg_pairs(&left[middle..], &right[col..], (middle, col), &mut split);
+            assert_eq!(split.len(), full.len());
+            assert!(split.iter().all(|&(row, col)| left[row] == right[col]));
+            assert!(split.windows(2).all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1));
+        }
+    }
+
+    #[test]
+    fn bit_parallel_matches_table() {
+        let mut seed: u64 = 7;
+        let mut random = |limit: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % limit
+        };
+        let alphabet = ['a', 'b', 'c', 'ż', 'ó'];
+        for _ in 0..200 {
+            let left: Vec<char> = (0..random(150)).map(|_| alphabet[random(5) as usize]).collect();
+            let right: Vec<char> = (0..random(150)).map(|_| alphabet[random(5) as usize]).collect();
+            let expected = super::lcs_table(&left, &right)[left.len()][right.len()];
+            let masks = super::PatternMasks::new(&left);
+            assert_eq!(masks.lcs_length(right.iter().copied()), expected);
+        }
+    }
+
+    #[test]
+    fn lcs_long_strings() {
+        let left = "abcde".repeat(300);
+        let right = "aXcYe".repeat(300);
+        assert_eq!(lcs(&left, &right), "ace".repeat(300));
+    }
+
+    #[test]
+    fn lcs_indices_works() {
+        assert_eq!(lcs_indices("longest", "stone"), vec![(1, 2), (2, 3), (4, 4)]);
+        let indices = lcs_indices("ABCBDAB", "BDCABA");
+        assert_eq!(indices.len(), lcs_length("ABCBDAB", "BDCABA"));
+        assert!(indices.windows(2).all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1));
+    }
+}
@@ -0,0 +1,269 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+
+use crate::lcs::PatternMasks;
+
+/// Levenshtein distance over arbitrary sequences
+#[cfg(any(test, feature = "unicode"))]
+pub(crate) fn levenshtein_slices<T: PartialEq>(left: &[T], right: &[T]) -> usize {
+    let (left, right) = if left.len() < right.len() { (left, right) } else { (right, left) };
+    let mut previous: Vec<usize> = (0..=left.len()).collect();
+    let mut current = vec![0; left.len() + 1];
+
+    for (row, rletter) in right.iter().enumerate() {
+        current[0] = row + 1;
+        for (col, lletter) in left.iter().enumerate() {
+            let cost = if rletter == lletter { 0 } else { 1 };
+            current[col + 1] = min(
+                previous[col] + cost,
+                min(previous[col + 1], current[col]) + 1
+            );
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[left.len()]
+}
+
+/// Advance one 64 row block of the bit-vector algorithm by one column
+///
+/// Takes the horizontal delta entering the block from above and returns the one leaving it
+/// at the row selected by `last_row`. This is the block step of Myers' algorithm.
+#[inline]
+fn advance_block(positive: &mut u64, negative: &mut u64, mut equal: u64, incoming: i32, last_row: u64) -> i32 {
+    let vertical = equal | *negative;
+    if incoming < 0 {
+        equal |= 1;
+    }
+    let horizontal = ((equal & *positive).wrapping_add(*positive) ^ *positive) | equal;
+    let mut horizontal_positive = *negative | !(horizontal | *positive);
+    let mut horizontal_negative = *positive & horizontal;
+    let outgoing = if horizontal_positive & last_row != 0 {
+        1
+    } else if horizontal_negative & last_row != 0 {
+        -1
+    } else {
+        0
+    };
+    horizontal_positive <<= 1;
+    horizontal_negative <<= 1;
+    if incoming < 0 {
+        horizontal_negative |= 1;
+    } else if incoming > 0 {
+        horizontal_positive |= 1;
+    }
+    *positive = horizontal_negative | !(vertical | horizontal_positive);
+    *negative = horizontal_positive & vertical;
+    outgoing
+}
+
+/// Levenshtein distance of the pattern and the text using Myers' bit-vector algorithm
+///
+/// Patterns of up to 64 characters fit in a single machine word,
+/// longer ones are split into blocks of 64 rows.
+pub(crate) fn myers_distance(pattern: &PatternMasks, text: impl IntoIterator<Item = char>) -> usize {
+    let words = pattern.words();
+    if words == 0 {
+        return text.into_iter().count();
+    }
+    let last_row = 1 << ((pattern.len() - 1) % 64);
+    // Vertical deltas of every row, all of them start positive
+    let mut positive = vec![u64::MAX; words];
+    let mut negative = vec![0; words];
+    let mut score = pattern.len() as isize;
+    let empty = vec![0; words];
+
+    for letter in text {
+        let masks = pattern.get(letter).unwrap_or(&empty);
+        // The first row of the table grows by one with every column
+        let mut carry = 1;
+        for block in 0..words {
+            let row = if block + 1 == words { last_row } else { 1 << 63 };
+            carry = advance_block(&mut positive[block], &mut negative[block], masks[block], carry, row);
+        }
+        score += carry as isize;
+    }
+    score as usize
+}
+
+/// Get the Levenshtein edit distance of two strings
+///
+/// The distance is the minimal number of single character
+/// insertions, deletions and substitutions that turn one string into the other.
+/// It is computed with Myers' bit-vector algorithm, which processes 64 characters
+/// of the shorter string at once.
+/// # Example
+/// ```
+/// use similar_string::levenshtein_distance;
+///
+/// // Substitute "k" with "s", "e" with "i" and insert "g"
+/// levenshtein_distance("kitten", "sitting"); // 3
+/// ```
+pub fn levenshtein_distance(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
+    let (left, right) = (left.as_ref(), right.as_ref());
+    let (left_len, right_len) = (left.chars().count(), right.chars().count());
+    // Use the shorter string as the bit-vector pattern
+    let (pattern, text) = if left_len < right_len { (left, right) } else { (right, left) };
+    let pattern: Vec<char> = pattern.chars().collect();
+    myers_distance(&PatternMasks::new(&pattern), text.chars())
+}
+
+/// Levenshtein distance limited to a diagonal band of the table
+///
+/// Cells further than `max_distance` from the diagonal can't be part of a cheap enough path,
+/// so only the band is computed (Ukkonen's cutoff). Returns `None` as soon as every cell
+/// of a row exceeds the limit.
+pub(crate) fn banded_distance<T: PartialEq>(left: &[T], right: &[T], max_distance: usize) -> Option<usize> {
+    let (left, right) = if left.len() < right.len() { (left, right) } else { (right, left) };
+    if right.len() - left.len() > max_distance {
+        return None;
+    }
+    // Values above the limit are all the same to us
+    let limit = max_distance + 1;
+    let mut previous: Vec<usize> = (0..=left.len()).map(|col| min(col, limit)).collect();
+    let mut current = vec![limit; left.len() + 1];
+
+    for (row, rletter) in right.iter().enumerate() {
+        let row = row + 1;
+        let start = row.saturating_sub(max_distance);
+        let end = min(left.len(), row + max_distance);
+        let mut best = limit;
+        if start == 0 {
+            current[0] = min(row, limit);
+            best = current[0];
+        } else {
+            current[start - 1] = limit;
+        }
+        for col in max(start, 1)..=end {
+            let cost = if left[col - 1] == *rletter { 0 } else { 1 };
+            let value = min(previous[col - 1] + cost, min(previous[col], current[col - 1]) + 1);
+            current[col] = min(value, limit);
+            best = min(best, current[col]);
+        }
+        // The next row reads one cell past the end of this band
+        if end < left.len() {
+            current[end + 1] = limit;
+        }
+        if best > max_distance {
+            return None;
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    let distance = previous[left.len()];
+    (distance <= max_distance).then_some(distance)
+}
+
+/// Get the Levenshtein edit distance of two strings if it does not exceed `max_distance`
+///
+/// Only the part of the table that can lead to a small enough distance is computed,
+/// and the computation stops early once the limit is exceeded.
+/// Strings that differ in length by more than `max_distance` are rejected right away.
+/// # Example
+/// ```
+/// use similar_string::levenshtein_distance_bounded;
+///
+/// levenshtein_distance_bounded("kitten", "sitting", 3); // Some(3)
+/// levenshtein_distance_bounded("kitten", "sitting", 2); // None
+/// ```
+pub fn levenshtein_distance_bounded(left: impl AsRef<str>, right: impl AsRef<str>, max_distance: usize) -> Option<usize> {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    let shorter = min(left.len(), right.len());
+    // A band as wide as the table is not worth it, the bit-vector algorithm is faster there
+    if max_distance >= shorter {
+        let (pattern, text) = if left.len() < right.len() { (left, right) } else { (right, left) };
+        let distance = myers_distance(&PatternMasks::new(&pattern), text);
+        return (distance <= max_distance).then_some(distance);
+    }
+    banded_distance(&left, &right, max_distance)
+}
+
+/// Get score of similarity of two strings based on the Levenshtein distance
+///
+/// The distance is normalized by the character count of the longer string.
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// # Example
+/// ```
+/// use similar_string::normalized_levenshtein;
+///
+/// normalized_levenshtein("kitten", "sitting"); // 0.5714285714285714
+/// ```
+pub fn normalized_levenshtein(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let (left, right) = (left.as_ref(), right.as_ref());
+    let size = max(left.chars().count(), right.chars().count());
+    // Empty strings should match
+    if size == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein_distance(left, right) as f64 / size as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn levenshtein_works() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+        assert_eq!(levenshtein_distance("abc", ""), 3);
+        assert_eq!(levenshtein_distance("flaw", "lawn"), 2);
+    }
+
+    #[test]
+    fn levenshtein_counts_chars() {
+        assert_eq!(levenshtein_distance("żółw", "zolw"), 3);
+    }
+
+    #[test]
+    fn bit_vector_matches_table() {
+        let mut seed: u64 = 3;
+        let mut random = |limit: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % limit
+        };
+        let alphabet = ['a', 'b', 'c', 'ż', 'ó'];
+        for _ in 0..300 {
+            let left: Vec<char> = (0..random(200)).map(|_| alphabet[random(5) as usize]).collect();
+            let right: Vec<char> = (0..random(200)).map(|_| alphabet[random(5) as usize]).collect();
+            let expected = super::levenshtein_slices(&left, &right);
+            let masks = super::PatternMasks::new(&left);
+            assert_eq!(super::myers_distance(&masks, right.iter().copied()), expected);
+        }
+    }
+
+    #[test]
+    fn bounded_levenshtein_works() {
+        assert_eq!(levenshtein_distance_bounded("kitten", "sitting", 3), Some(3));
+        assert_eq!(levenshtein_distance_bounded("kitten", "sitting", 2), None);
+        assert_eq!(levenshtein_distance_bounded("", "abc", 3), Some(3));
+        assert_eq!(levenshtein_distance_bounded("a", "abcdef", 2), None);
+        assert_eq!(levenshtein_distance_bounded("hello", "hello", 0), Some(0));
+    }
+
+    #[test]
+    fn band_matches_table() {
+        let mut seed: u64 = 5;
+        let mut random = |limit: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % limit
+        };
+        let alphabet = ['a', 'b', 'c'];
+        for _ in 0..500 {
+            let left: Vec<char> = (0..random(40)).map(|_| alphabet[random(3) as usize]).collect();
+            let right: Vec<char> = (0..random(40)).map(|_| alphabet[random(3) as usize]).collect();
+            let expected = super::levenshtein_slices(&left, &right);
+            let max_distance = random(30) as usize;
+            let bounded = super::banded_distance(&left, &right, max_distance);
+            assert_eq!(bounded, (expected <= max_distance).then_some(expected));
+        }
+    }
+
+    #[test]
+    fn normalized_levenshtein_range() {
+        assert_eq!(normalized_levenshtein("", ""), 1.0);
+        assert_eq!(normalized_levenshtein("hello", "hello"), 1.0);
+        assert_eq!(normalized_levenshtein("abc", "xyz"), 0.0);
+        assert_eq!(normalized_levenshtein("age", "page"), 0.75);
+    }
+}
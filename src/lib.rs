@@ -1,4 +1,5 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 //! # Similar String - the library for finding string similarities
 //! 
@@ -36,19 +37,290 @@
 //! // The longest common subsequence in this case is "one"
 //! lcs_length("longest", "stone"); // 3
 //! ```
+//!
+//! # Sharing indexes between threads
+//!
+//! The indexes, such as [`Corpus`], [`BkTree`], [`Trie`], [`SymSpell`] and [`TrigramIndex`],
+//! are `Send` and `Sync`. Searches only borrow them immutably and never change any internal state,
+//! so an index that is built once can be searched from many threads behind an `Arc`.
+//! Indexes that change while they are searched can be wrapped in an `RwLock`, so that
+//! searches run concurrently and only insertions and removals wait for exclusive access.
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use std::sync::{Arc, RwLock};
+//! use std::thread;
+//! use similar_string::Corpus;
+//!
+//! let corpus = Arc::new(RwLock::new(Corpus::new(["fill", "night", "ride"])));
+//!
+//! let reader = Arc::clone(&corpus);
+//! let handle = thread::spawn(move || {
+//!     reader.read().unwrap().best_match("fight").map(|best| best.score)
+//! });
+//! corpus.write().unwrap().insert("fight");
+//! handle.join().unwrap(); // Some(0.8) or Some(1.0)
+//! # }
+//! ```
+//!
+//! # Without the standard library
+//!
+//! Turning off the default `std` feature makes the crate `no_std`, so that it only needs an allocator.
+//! The LCS functions, [`compare_similarity`], the Levenshtein and Hamming distances and the searches
+//! such as [`find_best_match`] and [`find_top_k`] are kept, while the other metrics, the indexes
+//! and everything that reads files need `std`.
+//!
+//! ```toml
+//! [dependencies]
+//! similar-string = { version = "2", default-features = false }
+//! ```
 
-use std::cmp::max;
+extern crate alloc;
+// The code generated by the derive macros refers to the crate by its name
+#[cfg(feature = "derive")]
+extern crate self as similar_string;
 
-#[inline]
-fn get_shorter_longer_strings(left: impl AsRef<str>, right: impl AsRef<str>) -> (String, String) {
-    if left.as_ref().len() < right.as_ref().len() {
-        (left.as_ref().to_string(), right.as_ref().to_string())
-    } else {
-        (right.as_ref().to_string(), left.as_ref().to_string())
-    }
-}
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::max;
+
+mod error;
+mod levenshtein;
+#[cfg(feature = "std")]
+mod damerau;
+#[cfg(feature = "std")]
+mod jaro;
+mod hamming;
+#[cfg(feature = "std")]
+mod ngram;
+#[cfg(feature = "std")]
+mod ratcliff;
+#[cfg(feature = "std")]
+mod substring;
+#[cfg(feature = "std")]
+mod alignment;
+mod lcs;
+#[cfg(feature = "std")]
+mod diff;
+mod search;
+#[cfg(feature = "std")]
+mod matcher;
+#[cfg(feature = "std")]
+mod matrix;
+#[cfg(feature = "std")]
+mod ext;
+#[cfg(feature = "std")]
+mod metric;
+#[cfg(feature = "std")]
+mod options;
+#[cfg(feature = "std")]
+mod normalizer;
+#[cfg(feature = "std")]
+mod fold;
+#[cfg(feature = "std")]
+mod case;
+#[cfg(feature = "std")]
+mod equivalence;
+#[cfg(feature = "std")]
+mod tokens;
+#[cfg(feature = "std")]
+mod fuzz;
+#[cfg(feature = "std")]
+mod ensemble;
+#[cfg(feature = "std")]
+mod weighted;
+#[cfg(feature = "std")]
+mod keyboard;
+#[cfg(feature = "std")]
+mod confusion;
+#[cfg(feature = "std")]
+mod phonetic;
+#[cfg(feature = "std")]
+mod bktree;
+#[cfg(feature = "std")]
+mod trie;
+#[cfg(feature = "std")]
+mod automaton;
+#[cfg(feature = "std")]
+mod symspell;
+#[cfg(feature = "std")]
+mod trigram;
+#[cfg(feature = "std")]
+mod minhash;
+#[cfg(feature = "std")]
+mod simhash;
+#[cfg(feature = "std")]
+mod fuzzyset;
+#[cfg(feature = "std")]
+mod fuzzymap;
+#[cfg(feature = "std")]
+mod corpus;
+#[cfg(feature = "std")]
+mod persist;
+#[cfg(feature = "std")]
+mod dictionary;
+#[cfg(feature = "std")]
+mod progress;
+#[cfg(feature = "std")]
+mod cancel;
+#[cfg(feature = "std")]
+mod budget;
+#[cfg(feature = "std")]
+mod subsequence;
+#[cfg(feature = "std")]
+mod highlight;
+#[cfg(feature = "std")]
+mod render;
+#[cfg(feature = "std")]
+mod suggest;
+#[cfg(feature = "clap")]
+mod clap_suggest;
+#[cfg(feature = "std")]
+mod fuzzy_enum;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "unicode")]
+pub mod graphemes;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "python")]
+pub mod python;
+
+pub use error::{BudgetExceededError, LengthMismatchError, UnknownMetricError, UnknownVariantError};
+pub use levenshtein::{levenshtein_distance, levenshtein_distance_bounded, normalized_levenshtein};
+#[cfg(feature = "std")]
+pub use damerau::{
+    osa_distance, normalized_osa,
+    damerau_levenshtein_distance, normalized_damerau_levenshtein
+};
+#[cfg(feature = "std")]
+pub use jaro::{
+    DEFAULT_PREFIX_SCALE,
+    jaro_similarity, jaro_winkler_similarity, jaro_winkler_similarity_with_scale
+};
+pub use hamming::hamming_distance;
+#[cfg(feature = "std")]
+pub use ngram::{
+    jaccard_similarity, sorensen_dice_similarity, cosine_similarity,
+    tversky_index, overlap_coefficient
+};
+#[cfg(feature = "std")]
+pub use ratcliff::{ratcliff_obershelp_ratio, get_matching_blocks};
+#[cfg(feature = "std")]
+pub use substring::{CommonSubstring, longest_common_substring};
+#[cfg(feature = "std")]
+pub use alignment::{
+    GAP, Scoring, SubstitutionMatrix, Alignment,
+    needleman_wunsch, needleman_wunsch_score,
+    smith_waterman, smith_waterman_score
+};
+pub use lcs::{lcs, lcs_indices, lcs_length_slices, all_lcs, all_lcs_with_limit};
+#[cfg(feature = "std")]
+pub use diff::{EditOp, edit_ops, unified_diff};
+pub use search::{
+    Match, TieBreak, SimilarityIter, similarity_iter,
+    get_close_matches, find_best_match, find_best_match_with_tie_break, find_best_similarities,
+    find_top_k, find_matches_above, rank_options, sort_by_similarity
+};
+#[cfg(feature = "std")]
+pub use search::{LineMatch, find_best_with, find_best_in_lines, find_top_k_in_lines};
+#[cfg(feature = "std")]
+pub use matcher::Matcher;
+#[cfg(feature = "std")]
+pub use matrix::{Matrix, similarity_matrix};
+#[cfg(feature = "std")]
+pub use ext::{SimilarityExt, SliceSimilarityExt};
+#[cfg(feature = "std")]
+pub use options::SimilarityOptions;
+#[cfg(feature = "std")]
+pub use normalizer::Normalizer;
+#[cfg(feature = "std")]
+pub use equivalence::Equivalences;
+#[cfg(feature = "std")]
+pub use tokens::{split_words, compare_similarity_words, compare_similarity_lines, compare_similarity_lines_with};
+#[cfg(feature = "std")]
+pub use fuzz::{token_sort_ratio, token_set_ratio, partial_ratio, weighted_ratio};
+#[cfg(feature = "std")]
+pub use fold::{expand_long_vowels, fold_accents, fold_confusables, fold_kana, fold_width};
+#[cfg(feature = "std")]
+pub use case::{CaseLocale, compare_similarity_ignore_case, compare_similarity_ignore_case_with_locale};
+#[cfg(feature = "unicode")]
+pub use normalizer::UnicodeForm;
+#[cfg(feature = "std")]
+pub use metric::{
+    SimilarityMetric, DistanceMetric, Normalized, Lcs, Levenshtein, Osa, DamerauLevenshtein, Jaro, JaroWinkler,
+    SorensenDice, Jaccard, Cosine, RatcliffObershelp,
+    METRIC_NAMES, metric_from_name
+};
+#[cfg(feature = "std")]
+pub use ensemble::{Ensemble, EnsembleScore, MetricScore};
+#[cfg(feature = "std")]
+pub use weighted::{EditCosts, weighted_levenshtein, normalized_weighted_levenshtein};
+#[cfg(feature = "std")]
+pub use keyboard::{DEFAULT_ADJACENT_COST, KeyboardLayout};
+#[cfg(feature = "std")]
+pub use confusion::ConfusionMatrix;
+#[cfg(feature = "std")]
+pub use bktree::BkTree;
+#[cfg(feature = "std")]
+pub use trie::Trie;
+#[cfg(feature = "std")]
+pub use automaton::{LevenshteinAutomaton, LevenshteinState};
+#[cfg(feature = "std")]
+pub use symspell::{DEFAULT_SYMSPELL_DISTANCE, Suggestion, SymSpell};
+#[cfg(feature = "std")]
+pub use trigram::TrigramIndex;
+#[cfg(feature = "std")]
+pub use minhash::{DEFAULT_MINHASH_SEED, MinHashSignature, MinHasher, LshIndex};
+#[cfg(feature = "std")]
+pub use simhash::{simhash, simhash_distance, simhash_near_duplicate};
+#[cfg(feature = "std")]
+pub use fuzzyset::{DEFAULT_FUZZY_SET_SCORE, FuzzySet};
+#[cfg(feature = "std")]
+pub use fuzzymap::FuzzyMap;
+#[cfg(feature = "std")]
+pub use corpus::Corpus;
+#[cfg(feature = "std")]
+pub use dictionary::Dictionary;
+#[cfg(feature = "std")]
+pub use progress::{similarity_matrix_with_progress, get_similarity_ratings_with_progress};
+#[cfg(feature = "std")]
+pub use cancel::{
+    CancelToken, Partial, get_similarity_ratings_cancellable, find_best_match_cancellable,
+    similarity_matrix_cancellable, extend_cancellable
+};
+#[cfg(feature = "std")]
+pub use budget::{try_compare_similarity, try_levenshtein_distance, try_find_best_match, try_similarity_matrix};
+#[cfg(feature = "std")]
+pub use subsequence::{SubsequenceMatch, subsequence_score, subsequence_match, rank_subsequence_matches};
+#[cfg(feature = "std")]
+pub use highlight::{matched_positions, find_best_match_with_positions};
+#[cfg(feature = "std")]
+pub use render::{AnsiStyle, HtmlMarkup, ansi_diff, ansi_inline_diff, html_diff};
+#[cfg(feature = "std")]
+pub use suggest::{DidYouMean, QuoteStyle};
+#[cfg(feature = "clap")]
+pub use clap_suggest::{replace_clap_suggestions, try_get_matches_with_suggestions, try_parse_with_suggestions};
+#[cfg(feature = "std")]
+pub use fuzzy_enum::{DEFAULT_FUZZY_ENUM_SCORE, FuzzyEnum, Resolved};
+#[cfg(feature = "derive")]
+pub use similar_string_derive::FuzzyFromStr;
+#[cfg(feature = "std")]
+pub use phonetic::{
+    soundex, soundex_similarity, DoubleMetaphone, double_metaphone, double_metaphone_similarity,
+    nysiis, nysiis_untruncated, nysiis_similarity, find_best_phonetic
+};
+#[cfg(feature = "parallel")]
+pub use parallel::{par_find_best_similarity, par_get_similarity_ratings, par_similarity_matrix};
 
 /// Get length of the longest common subsequence
+///
+/// The length is computed with a bit-parallel algorithm that processes
+/// 64 characters of the shorter string at once.
 /// ```
 /// use similar_string::lcs_length;
 /// 
@@ -56,24 +328,56 @@ fn get_shorter_longer_strings(left: impl AsRef<str>, right: impl AsRef<str>) ->
 /// lcs_length("longest", "stone"); // 3
 /// ```
 pub fn lcs_length(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
-    let (left, right) = get_shorter_longer_strings(left, right);
-    let mut table = vec![vec![0 as usize; left.len() + 1]; 2];
-
-    for rletter in right.chars() {
-        for (col, lletter) in left.chars().enumerate() {
-            if rletter == lletter {
-                table[1][col + 1] = 1 + table[0][col];
-            } else {
-                table[1][col + 1] = max(table[0][col + 1], table[1][col]);
-            }
-        }
-        table[0] = table.pop().unwrap();
-        table.push(vec![0 as usize; left.len() + 1]);
-    }
-    *table[0].last().unwrap()
+    let (left, right) = (left.as_ref(), right.as_ref());
+    let (left_len, right_len) = (left.chars().count(), right.chars().count());
+    // Use the shorter string as the bit-parallel pattern
+    let (pattern, text) = if left_len < right_len { (left, right) } else { (right, left) };
+    let pattern: Vec<char> = pattern.chars().collect();
+    lcs::PatternMasks::new(&pattern).lcs_length(text.chars())
+}
+
+/// Get length of the longest common subsequence with a custom equality of characters
+///
+/// The closure gets a character of `left` and a character of `right` and decides whether they match,
+/// which allows rules the crate does not provide. The bit-parallel algorithm relies on plain equality,
+/// so this function computes the table row by row in O(n * m) time.
+/// # Example
+/// ```
+/// use similar_string::lcs_length_by;
+///
+/// lcs_length_by("Longest", "STONE", |left, right| left.eq_ignore_ascii_case(&right)); // 3
+/// // Any digit matches any other digit
+/// lcs_length_by("PL 1234", "PL 9876", |left, right| left == right || left.is_ascii_digit() && right.is_ascii_digit()); // 7
+/// ```
+pub fn lcs_length_by(left: impl AsRef<str>, right: impl AsRef<str>, equal: impl Fn(char, char) -> bool) -> usize {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    lcs::lcs_length_slices_by(&left, &right, |lletter, rletter| equal(*lletter, *rletter))
+}
+
+/// Get length of the longest common subsequence if it is at least `min_required`
+///
+/// Returns `None` as soon as the remaining characters can no longer reach
+/// the required length, so clearly different strings don't pay for the full computation.
+/// # Example
+/// ```
+/// use similar_string::lcs_length_bounded;
+///
+/// lcs_length_bounded("longest", "stone", 3); // Some(3)
+/// lcs_length_bounded("longest", "stone", 4); // None
+/// ```
+pub fn lcs_length_bounded(left: impl AsRef<str>, right: impl AsRef<str>, min_required: usize) -> Option<usize> {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    // Use the shorter string as the bit-parallel pattern
+    let (pattern, text) = if left.len() < right.len() { (left, right) } else { (right, left) };
+    lcs::PatternMasks::new(&pattern).lcs_length_bounded(&text, min_required)
 }
 
 /// Get score of similarity of two certain strings
+///
+/// The length of the longest common subsequence is divided by the
+/// character count of the longer string.
 /// # Example
 /// ```
 /// use similar_string::*;
@@ -81,18 +385,44 @@ pub fn lcs_length(left: impl AsRef<str>, right: impl AsRef<str>) -> usize {
 /// // Compares similarity of two strings and returns similarity rating.
 /// // The rating is returned as a f64 value in range from 0.0 to 1.0.
 /// compare_similarity("age", "page"); // 0.75
+/// compare_similarity("żółw", "żółw"); // 1.0
 /// ```
 pub fn compare_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
-    let (len1, len2) = (left.as_ref().len(), right.as_ref().len());
     let lcs_len = lcs_length(left.as_ref(), right.as_ref());
-    let size = max(len1, len2);
+    lcs_rating(lcs_len, left.as_ref(), right.as_ref())
+}
+
+/// Get score of similarity of two certain strings normalized by byte length
+///
+/// This is the rating of [`compare_similarity`] from versions before 2.0.0, which divides
+/// the length of the longest common subsequence by the byte length of the longer string.
+/// Strings with non-ASCII characters get lower ratings than they should.
+/// # Example
+/// ```
+/// use similar_string::compare_similarity_by_bytes;
+///
+/// compare_similarity_by_bytes("age", "page"); // 0.75
+/// compare_similarity_by_bytes("żółw", "żółw"); // 0.5714285714285714
+/// ```
+pub fn compare_similarity_by_bytes(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let lcs_len = lcs_length(left.as_ref(), right.as_ref());
+    let size = max(left.as_ref().len(), right.as_ref().len());
+    // Empty strings should match
+    if size == 0 { 1.0 } else { lcs_len as f64 / size as f64 }
+}
+
+/// Turn the length of the longest common subsequence into the rating of [`compare_similarity`]
+#[inline]
+pub(crate) fn lcs_rating(lcs_len: usize, left: &str, right: &str) -> f64 {
+    let size = max(left.chars().count(), right.chars().count());
     // Empty strings should match
     if size == 0 { 1.0 } else { lcs_len as f64 / size as f64 }
 }
 
 /// Find the string amongs the options that is the most similar to the target one
 /// 
-/// This function returns `None` if the provided options is an empty slice
+/// The options can be any collection or iterator of strings.
+/// This function returns `None` if there are no options
 /// # Example
 /// ```
 /// use similar_string::*;
@@ -103,27 +433,14 @@ pub fn compare_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64
 /// // and returns match with it's rating
 /// find_best_similarity("fight", &options); // ("night", 0.8)
 /// ```
-pub fn find_best_similarity(taregt: impl AsRef<str>, options: &[impl AsRef<str>]) -> Option<(String, f64)> {
-    match options.len() {
-        0 => None,
-        _ => {
-            let mut high_score: f64 = -1.0;
-            let mut position: usize = 0;
-            for (index, option) in options.iter().enumerate() {
-                let score = compare_similarity(option.as_ref(), taregt.as_ref());
-                if score > high_score {
-                    high_score = score;
-                    position = index;
-                }
-            }
-            Some((options[position].as_ref().to_string(), high_score))
-        }
-    }
+pub fn find_best_similarity(taregt: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Option<(String, f64)> {
+    search::best_rated(taregt.as_ref(), options).map(|(_, option, score)| (option.as_ref().to_string(), score))
 }
 
 /// Get all similarity scores against the target string
 /// 
-/// This function returns `None` if the provided options is an empty slice
+/// The options can be any collection or iterator of strings.
+/// This function returns `None` if there are no options
 /// # Example
 /// ```
 /// use similar_string::*;
@@ -134,18 +451,11 @@ pub fn find_best_similarity(taregt: impl AsRef<str>, options: &[impl AsRef<str>]
 /// // of the provided options
 /// get_similarity_ratings("fight", &options); // [0.4, 0.8, 0.2]
 /// ```
-pub fn get_similarity_ratings(taregt: impl AsRef<str>, options: &[impl AsRef<str>]) -> Option<Vec<f64>> {
-    match options.len() {
-        0 => None,
-        _ => {
-            let mut result = vec![];
-            for option in options.iter() {
-                let score = compare_similarity(option.as_ref(), taregt.as_ref());
-                result.push(score);
-            }
-            Some(result)
-        }
-    }
+pub fn get_similarity_ratings(taregt: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Option<Vec<f64>> {
+    let result: Vec<f64> = search::rated_options(taregt.as_ref(), options)
+        .map(|(_, _, score)| score)
+        .collect();
+    if result.is_empty() { None } else { Some(result) }
 }
 
 #[cfg(test)]
@@ -175,12 +485,41 @@ mod tests {
         assert_eq!(score1, score2);
     }
 
+    #[test]
+    fn normalized_by_chars() {
+        assert_eq!(compare_similarity("żółw", "żółw"), 1.0);
+        assert_eq!(compare_similarity("żółw", "zolw"), 0.25);
+        assert_eq!(compare_similarity_by_bytes("żółw", "żółw"), 4.0 / 7.0);
+        assert_eq!(compare_similarity_by_bytes("age", "page"), compare_similarity("age", "page"));
+    }
+
     #[test]
     fn empty_strings() {
         let score = compare_similarity("", "");
         assert_eq!(score, 1.0);
     }
 
+    #[test]
+    fn lcs_by_works() {
+        assert_eq!(lcs_length_by("Longest", "STONE", |left, right| left.eq_ignore_ascii_case(&right)), 3);
+        assert_eq!(lcs_length_by("longest", "stone", |left, right| left == right), lcs_length("longest", "stone"));
+        assert_eq!(lcs_length_by("abc", "", |_, _| true), 0);
+        // The arguments keep their order when the longer string comes first
+        assert_eq!(lcs_length_by("aaaa", "bb", |left, right| left == 'a' && right == 'b'), 2);
+        assert_eq!(lcs_length_by("bb", "aaaa", |left, right| left == 'a' && right == 'b'), 0);
+    }
+
+    #[test]
+    fn lcs_bounded_works() {
+        assert_eq!(lcs_length_bounded("longest", "stone", 3), Some(3));
+        assert_eq!(lcs_length_bounded("longest", "stone", 4), None);
+        assert_eq!(lcs_length_bounded("", "", 0), Some(0));
+        let left = "ab".repeat(100);
+        let right = "a".repeat(50) + &"c".repeat(150);
+        assert_eq!(lcs_length_bounded(&left, &right, 50), Some(50));
+        assert_eq!(lcs_length_bounded(&left, &right, 51), None);
+    }
+
     #[test]
     fn find_best() {
         let target = "fight";
@@ -191,6 +530,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_format)]
     fn find_best_with_set() {
         let target = format!("fight");
         let mut options = BTreeSet::new();
@@ -203,6 +543,40 @@ mod tests {
         assert_eq!(score, 0.8);
     }
 
+    #[test]
+    fn empty_options() {
+        let options: Vec<&str> = vec![];
+        assert_eq!(find_best_similarity("fight", &options), None);
+        assert_eq!(find_best_match("fight", &options), None);
+        assert_eq!(get_similarity_ratings("fight", &options), None);
+    }
+
+    #[test]
+    fn find_best_in_iterators() {
+        let options: BTreeSet<&str> = ["blight", "night", "stride"].into_iter().collect();
+        assert_eq!(find_best_similarity("fight", &options), Some(("night".to_string(), 0.8)));
+        let lines = "fill\nnight\nride".lines();
+        assert_eq!(get_similarity_ratings("fight", lines), Some(vec![0.4, 0.8, 0.2]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn indexes_are_thread_safe() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Corpus>();
+        assert_send_sync::<BkTree>();
+        assert_send_sync::<Trie>();
+        assert_send_sync::<SymSpell>();
+        assert_send_sync::<TrigramIndex>();
+        assert_send_sync::<LshIndex>();
+        assert_send_sync::<FuzzySet>();
+        assert_send_sync::<FuzzyMap<String>>();
+        assert_send_sync::<Dictionary<Vec<u8>>>();
+        assert_send_sync::<LevenshteinAutomaton>();
+        assert_send_sync::<Matcher>();
+        assert_send_sync::<Ensemble>();
+    }
+
     #[test]
     fn similarity_ratings() {
         let expected = vec![0.4, 0.8, 0.2];
@@ -0,0 +1,116 @@
+// Command line interface of the crate, built with the `cli` feature
+//
+// similar-string compare A B
+// similar-string diff A B
+// similar-string best TARGET [--options-file FILE]
+// similar-string ratings TARGET [--options-file FILE]
+//
+// Options are read one per line from the file or from the standard input.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::ExitCode;
+
+use similar_string::{AnsiStyle, ansi_diff, compare_similarity, find_best_in_lines};
+
+const USAGE: &str = "\
+Usage:
+    similar-string compare A B
+    similar-string diff A B
+    similar-string best TARGET [--options-file FILE]
+    similar-string ratings TARGET [--options-file FILE]
+
+Options are read one per line from the file or from the standard input.
+`diff` prints both strings with their differences colored, `best` prints the best option
+and its rating, `ratings` prints every option with its rating.";
+
+/// Open the options file, or the standard input if there is none
+fn options_reader(args: &[String], stdin: Box<dyn BufRead>) -> Result<Box<dyn BufRead>, String> {
+    match args {
+        [] => Ok(stdin),
+        [flag, path] if flag == "--options-file" => {
+            let file = File::open(path).map_err(|error| format!("cannot open {}: {}", path, error))?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+        _ => Err(format!("unexpected arguments: {}", args.join(" ")))
+    }
+}
+
+/// Run the command and tell whether it found anything
+fn run(args: &[String], stdin: Box<dyn BufRead>, out: &mut impl Write) -> Result<bool, String> {
+    let write_error = |error: io::Error| error.to_string();
+    match args {
+        [command, left, right] if command == "compare" => {
+            writeln!(out, "{}", compare_similarity(left, right)).map_err(write_error)?;
+            Ok(true)
+        }
+        [command, left, right] if command == "diff" => {
+            let (left, right) = ansi_diff(left, right, &AnsiStyle::new());
+            writeln!(out, "{}\n{}", left, right).map_err(write_error)?;
+            Ok(true)
+        }
+        [command, target, rest @ ..] if command == "best" => {
+            let reader = options_reader(rest, stdin)?;
+            match find_best_in_lines(target, reader).map_err(|error| error.to_string())? {
+                Some(best) => {
+                    writeln!(out, "{}\t{}", best.line, best.score).map_err(write_error)?;
+                    Ok(true)
+                }
+                None => Ok(false)
+            }
+        }
+        [command, target, rest @ ..] if command == "ratings" => {
+            let reader = options_reader(rest, stdin)?;
+            let mut found = false;
+            for line in reader.lines() {
+                let line = line.map_err(|error| error.to_string())?;
+                writeln!(out, "{}\t{}", line, compare_similarity(target, &line)).map_err(write_error)?;
+                found = true;
+            }
+            Ok(found)
+        }
+        [flag] if flag == "-h" || flag == "--help" => {
+            writeln!(out, "{}", USAGE).map_err(write_error)?;
+            Ok(true)
+        }
+        _ => Err(USAGE.to_string())
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let stdin = Box::new(io::stdin().lock());
+    match run(&args, stdin, &mut io::stdout().lock()) {
+        Ok(true) => ExitCode::SUCCESS,
+        // Nothing was found, like grep without matches
+        Ok(false) => ExitCode::from(1),
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_with(args: &[&str], input: &'static str) -> Result<(bool, String), String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let mut out = vec![];
+        let found = run(&args, Box::new(input.as_bytes()), &mut out)?;
+        Ok((found, String::from_utf8(out).unwrap()))
+    }
+
+    #[test]
+    fn cli_commands() {
+        assert_eq!(run_with(&["compare", "age", "page"], ""), Ok((true, "0.75\n".to_string())));
+        assert_eq!(run_with(&["best", "fight"], "fill\nnight\nride\n"), Ok((true, "night\t0.8\n".to_string())));
+        assert_eq!(run_with(&["best", "fight"], ""), Ok((false, String::new())));
+        assert_eq!(run_with(&["ratings", "fight"], "fill\nnight\n"), Ok((true, "fill\t0.4\nnight\t0.8\n".to_string())));
+        assert!(run_with(&["best", "fight", "--options-file", "/nonexistent/options"], "").is_err());
+        assert!(run_with(&["compare", "age"], "").is_err());
+        assert_eq!(run_with(&["diff", "fight", "night"], ""), Ok((true, "\x1b[31mf\x1b[0might\n\x1b[32mn\x1b[0might\n".to_string())));
+        assert!(run_with(&["--help"], "").unwrap().1.starts_with("Usage:"));
+    }
+}
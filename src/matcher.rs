@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::lcs_rating;
+use crate::lcs::PatternMasks;
+use crate::levenshtein::myers_distance;
+use crate::ngram::{ngram_profile, profile_cosine};
+use crate::ratcliff::SequenceIndex;
+
+/// Default n-gram size used by the [`Matcher`]
+const DEFAULT_NGRAM_SIZE: usize = 2;
+
+/// Compares one string against many others
+///
+/// The matcher precomputes the state that does not depend on the other string,
+/// such as the bit masks of the bit-parallel LCS algorithm, the character positions used by
+/// the Ratcliff-Obershelp algorithm and the n-gram frequency profile.
+/// Every method returns the same rating as its free function counterpart.
+/// # Example
+/// ```
+/// use similar_string::Matcher;
+///
+/// let matcher = Matcher::new("fight");
+/// for option in ["fill", "night", "ride"] {
+///     matcher.ratio(option); // 0.4, 0.8, 0.2
+/// }
+/// ```
+pub struct Matcher {
+    haystack: String,
+    chars: Vec<char>,
+    masks: PatternMasks,
+    index: SequenceIndex<char>,
+    ngram_size: usize,
+    profile: HashMap<String, usize>
+}
+
+impl Matcher {
+    /// Create a matcher for the given haystack
+    pub fn new(haystack: impl AsRef<str>) -> Self {
+        Matcher::with_ngram_size(haystack, DEFAULT_NGRAM_SIZE)
+    }
+
+    /// Create a matcher that uses n-grams of the given size for the n-gram based metrics
+    /// # Panics
+    /// This function panics if `n` is zero
+    pub fn with_ngram_size(haystack: impl AsRef<str>, n: usize) -> Self {
+        let haystack = haystack.as_ref().to_string();
+        let chars: Vec<char> = haystack.chars().collect();
+        let profile = ngram_profile(&haystack, n).into_iter()
+            .map(|(ngram, count)| (ngram.to_string(), count))
+            .collect();
+        Matcher {
+            masks: PatternMasks::new(&chars),
+            index: SequenceIndex::new(chars.clone()),
+            chars,
+            ngram_size: n,
+            profile,
+            haystack
+        }
+    }
+
+    /// The string that the matcher compares against
+    pub fn haystack(&self) -> &str {
+        &self.haystack
+    }
+
+    /// Get the same rating as [`compare_similarity`](crate::compare_similarity)
+    pub fn ratio(&self, other: impl AsRef<str>) -> f64 {
+        let lcs_len = self.masks.lcs_length(other.as_ref().chars());
+        lcs_rating(lcs_len, &self.haystack, other.as_ref())
+    }
+
+    /// Get the same rating as [`ratcliff_obershelp_ratio(other, haystack)`](crate::ratcliff_obershelp_ratio)
+    ///
+    /// Just like with Python's `difflib.SequenceMatcher`, the haystack is the second sequence.
+    pub fn ratcliff_obershelp_ratio(&self, other: impl AsRef<str>) -> f64 {
+        let other: Vec<char> = other.as_ref().chars().collect();
+        self.index.ratio(&other)
+    }
+
+    /// Get the same rating as [`normalized_levenshtein`](crate::normalized_levenshtein)
+    pub fn normalized_levenshtein(&self, other: impl AsRef<str>) -> f64 {
+        let other = other.as_ref().chars();
+        let size = self.chars.len().max(other.clone().count());
+        // Empty strings should match
+        if size == 0 {
+            return 1.0;
+        }
+        1.0 - myers_distance(&self.masks, other) as f64 / size as f64
+    }
+
+    /// Get the same rating as [`cosine_similarity`](crate::cosine_similarity)
+    /// with the n-gram size of the matcher
+    pub fn cosine_similarity(&self, other: impl AsRef<str>) -> f64 {
+        let other = ngram_profile(other.as_ref(), self.ngram_size);
+        profile_cosine(&self.profile, &other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn matcher_agrees_with_functions() {
+        let matcher = Matcher::new("fight");
+        for option in ["fill", "night", "ride", "", "fight club"] {
+            assert_eq!(matcher.ratio(option), compare_similarity("fight", option));
+            assert_eq!(matcher.ratcliff_obershelp_ratio(option), ratcliff_obershelp_ratio(option, "fight"));
+            assert_eq!(matcher.normalized_levenshtein(option), normalized_levenshtein("fight", option));
+            assert_eq!(matcher.cosine_similarity(option), cosine_similarity("fight", option, 2));
+        }
+    }
+
+    #[test]
+    fn matcher_ngram_size() {
+        let matcher = Matcher::with_ngram_size("night", 3);
+        assert_eq!(matcher.haystack(), "night");
+        assert_eq!(matcher.cosine_similarity("fight"), cosine_similarity("night", "fight", 3));
+    }
+}
@@ -0,0 +1,168 @@
+use std::ops::Index;
+
+use crate::lcs::PatternMasks;
+use crate::lcs_rating;
+
+/// Two dimensional table of values stored row by row
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "MatrixData<T>"))]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>
+}
+
+impl<T> Matrix<T> {
+    /// Create a matrix from values stored row by row
+    /// # Panics
+    /// This function panics if the length of `data` is not `rows * cols`
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), rows * cols, "matrix data must have rows * cols values");
+        Matrix { rows, cols, data }
+    }
+
+    /// Number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the value at the given position or `None` if it is out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.data.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    /// Get all values of the given row
+    /// # Panics
+    /// This function panics if the row is out of bounds
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {} is out of bounds", row);
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Get all values stored row by row
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Turn the matrix into values stored row by row
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+}
+
+/// Fields of a deserialized matrix that are checked before they make a [`Matrix`]
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MatrixData<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<MatrixData<T>> for Matrix<T> {
+    type Error = &'static str;
+
+    fn try_from(matrix: MatrixData<T>) -> Result<Self, Self::Error> {
+        if matrix.rows.checked_mul(matrix.cols) != Some(matrix.data.len()) {
+            return Err("matrix data must have rows * cols values");
+        }
+        Ok(Matrix { rows: matrix.rows, cols: matrix.cols, data: matrix.data })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).expect("matrix index out of bounds")
+    }
+}
+
+/// Ratings of one item against all the items after it
+pub(crate) fn upper_row(items: &[impl AsRef<str>], row: usize) -> Vec<f64> {
+    let item = items[row].as_ref();
+    let chars: Vec<char> = item.chars().collect();
+    let masks = PatternMasks::new(&chars);
+    items[row + 1..].iter()
+        .map(|other| lcs_rating(masks.lcs_length(other.as_ref().chars()), item, other.as_ref()))
+        .collect()
+}
+
+/// Mirror the upper triangle rows into a full symmetric matrix with ones on the diagonal
+pub(crate) fn mirror(upper: Vec<Vec<f64>>) -> Matrix<f64> {
+    let size = upper.len();
+    let mut data = vec![1.0; size * size];
+    for (row, ratings) in upper.into_iter().enumerate() {
+        for (offset, rating) in ratings.into_iter().enumerate() {
+            let col = row + 1 + offset;
+            data[row * size + col] = rating;
+            data[col * size + row] = rating;
+        }
+    }
+    Matrix::from_vec(size, size, data)
+}
+
+/// Get similarity ratings of all pairs of the items
+///
+/// The value at `(row, col)` is the [`compare_similarity`](crate::compare_similarity)
+/// of the items at these positions. Since the rating is symmetric, only the pairs above
+/// the diagonal are computed and then mirrored. The diagonal is filled with 1.0.
+/// # Example
+/// ```
+/// use similar_string::similarity_matrix;
+///
+/// let matrix = similarity_matrix(&["fight", "night", "ride"]);
+/// matrix[(0, 1)]; // 0.8
+/// matrix[(1, 0)]; // 0.8
+/// ```
+pub fn similarity_matrix(items: &[impl AsRef<str>]) -> Matrix<f64> {
+    mirror((0..items.len()).map(|row| upper_row(items, row)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn matrix_is_symmetric() {
+        let items = ["fight", "night", "ride", ""];
+        let matrix = similarity_matrix(&items);
+        assert_eq!((matrix.rows(), matrix.cols()), (4, 4));
+        for row in 0..4 {
+            assert_eq!(matrix[(row, row)], 1.0);
+            for col in 0..4 {
+                assert_eq!(matrix[(row, col)], matrix[(col, row)]);
+                assert_eq!(matrix[(row, col)], compare_similarity(items[row], items[col]));
+            }
+        }
+        assert_eq!(matrix.row(0), &[1.0, 0.8, 0.2, 0.0]);
+        assert_eq!(matrix.get(4, 0), None);
+    }
+
+    #[test]
+    fn empty_matrix() {
+        let matrix = similarity_matrix(&[] as &[&str]);
+        assert_eq!((matrix.rows(), matrix.cols()), (0, 0));
+        assert!(matrix.into_vec().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn matrix_serde() {
+        let matrix = similarity_matrix(&["fight", "night"]);
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(json, r#"{"rows":2,"cols":2,"data":[1.0,0.8,0.8,1.0]}"#);
+        assert_eq!(serde_json::from_str::<Matrix<f64>>(&json).unwrap(), matrix);
+        assert!(serde_json::from_str::<Matrix<f64>>(r#"{"rows":2,"cols":2,"data":[1.0]}"#).is_err());
+    }
+}
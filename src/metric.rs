@@ -0,0 +1,288 @@
+use crate::*;
+
+/// Names of the metrics that can be created with [`metric_from_name`]
+pub const METRIC_NAMES: &[&str] = &[
+    "lcs", "levenshtein", "osa", "damerau-levenshtein", "jaro", "jaro-winkler",
+    "sorensen-dice", "jaccard", "cosine", "ratcliff-obershelp"
+];
+
+/// Similarity rating of two strings in range from 0.0 to 1.0
+///
+/// Implemented by the built-in metrics and by closures taking two strings,
+/// so that custom scorers can be used with [`find_best_with`].
+/// # Example
+/// ```
+/// use similar_string::{SimilarityMetric, Lcs, JaroWinkler};
+///
+/// Lcs.similarity("age", "page"); // 0.75
+/// JaroWinkler.similarity("martha", "marhta"); // 0.9611111111111111
+/// (|left: &str, right: &str| if left == right { 1.0 } else { 0.0 }).similarity("a", "b"); // 0.0
+/// ```
+pub trait SimilarityMetric {
+    /// Get the similarity rating of the two strings
+    fn similarity(&self, left: &str, right: &str) -> f64;
+}
+
+impl<F: Fn(&str, &str) -> f64> SimilarityMetric for F {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        self(left, right)
+    }
+}
+
+/// Distance between two strings where 0.0 means equal strings
+///
+/// Distances can be turned into similarity ratings with the [`Normalized`] adapter.
+/// # Example
+/// ```
+/// use similar_string::{DistanceMetric, Normalized, SimilarityMetric, Levenshtein};
+///
+/// Levenshtein.distance("kitten", "sitting"); // 3.0
+/// Normalized(Levenshtein).similarity("kitten", "sitting"); // 0.5714285714285714
+/// ```
+pub trait DistanceMetric {
+    /// Get the distance between the two strings
+    fn distance(&self, left: &str, right: &str) -> f64;
+
+    /// Get the largest distance that the two strings could have
+    ///
+    /// This is used to normalize the distance into a similarity rating.
+    fn max_distance(&self, left: &str, right: &str) -> f64;
+}
+
+/// Adapter that turns a [`DistanceMetric`] into a [`SimilarityMetric`]
+///
+/// The rating is `1.0 - distance / max_distance`, clamped to the range from 0.0 to 1.0.
+/// Strings with zero maximal distance are considered equal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Normalized<D>(pub D);
+
+impl<D: DistanceMetric> SimilarityMetric for Normalized<D> {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        let max_distance = self.0.max_distance(left, right);
+        if max_distance <= 0.0 {
+            return 1.0;
+        }
+        (1.0 - self.0.distance(left, right) / max_distance).clamp(0.0, 1.0)
+    }
+}
+
+/// Edit distances are at most the character count of the longer string
+fn longer_length(left: &str, right: &str) -> f64 {
+    left.chars().count().max(right.chars().count()) as f64
+}
+
+/// Rating based on the longest common subsequence, see [`compare_similarity`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Lcs;
+
+impl SimilarityMetric for Lcs {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        compare_similarity(left, right)
+    }
+}
+
+/// Rating based on the Levenshtein distance, see [`normalized_levenshtein`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Levenshtein;
+
+impl SimilarityMetric for Levenshtein {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        normalized_levenshtein(left, right)
+    }
+}
+
+impl DistanceMetric for Levenshtein {
+    fn distance(&self, left: &str, right: &str) -> f64 {
+        levenshtein_distance(left, right) as f64
+    }
+
+    fn max_distance(&self, left: &str, right: &str) -> f64 {
+        longer_length(left, right)
+    }
+}
+
+/// Rating based on the optimal string alignment distance, see [`normalized_osa`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Osa;
+
+impl SimilarityMetric for Osa {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        normalized_osa(left, right)
+    }
+}
+
+impl DistanceMetric for Osa {
+    fn distance(&self, left: &str, right: &str) -> f64 {
+        osa_distance(left, right) as f64
+    }
+
+    fn max_distance(&self, left: &str, right: &str) -> f64 {
+        longer_length(left, right)
+    }
+}
+
+/// Rating based on the Damerau-Levenshtein distance, see [`normalized_damerau_levenshtein`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DamerauLevenshtein;
+
+impl SimilarityMetric for DamerauLevenshtein {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        normalized_damerau_levenshtein(left, right)
+    }
+}
+
+impl DistanceMetric for DamerauLevenshtein {
+    fn distance(&self, left: &str, right: &str) -> f64 {
+        damerau_levenshtein_distance(left, right) as f64
+    }
+
+    fn max_distance(&self, left: &str, right: &str) -> f64 {
+        longer_length(left, right)
+    }
+}
+
+/// Jaro similarity, see [`jaro_similarity`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Jaro;
+
+impl SimilarityMetric for Jaro {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        jaro_similarity(left, right)
+    }
+}
+
+/// Jaro-Winkler similarity, see [`jaro_winkler_similarity`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JaroWinkler;
+
+impl SimilarityMetric for JaroWinkler {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        jaro_winkler_similarity(left, right)
+    }
+}
+
+/// Sørensen-Dice coefficient of bigrams, see [`sorensen_dice_similarity`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SorensenDice;
+
+impl SimilarityMetric for SorensenDice {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        sorensen_dice_similarity(left, right)
+    }
+}
+
+/// Jaccard index of n-grams of the given size, see [`jaccard_similarity`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jaccard(pub usize);
+
+impl SimilarityMetric for Jaccard {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        jaccard_similarity(left, right, self.0)
+    }
+}
+
+/// Cosine similarity of n-gram profiles of the given size, see [`cosine_similarity`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cosine(pub usize);
+
+impl SimilarityMetric for Cosine {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        cosine_similarity(left, right, self.0)
+    }
+}
+
+/// Ratcliff-Obershelp similarity, see [`ratcliff_obershelp_ratio`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RatcliffObershelp;
+
+impl SimilarityMetric for RatcliffObershelp {
+    fn similarity(&self, left: &str, right: &str) -> f64 {
+        ratcliff_obershelp_ratio(left, right)
+    }
+}
+
+/// Create a built-in metric from its name
+///
+/// The names are listed in [`METRIC_NAMES`]. Letter case is ignored and underscores
+/// or spaces can be used instead of dashes. The n-gram metrics use bigrams.
+/// # Example
+/// ```
+/// use similar_string::metric_from_name;
+///
+/// let metric = metric_from_name("Jaro_Winkler").unwrap();
+/// metric.similarity("martha", "marhta"); // 0.9611111111111111
+/// metric_from_name("unknown").is_err(); // true
+/// ```
+pub fn metric_from_name(name: &str) -> Result<Box<dyn SimilarityMetric + Send + Sync>, UnknownMetricError> {
+    let key = name.trim().to_lowercase().replace(['_', ' '], "-");
+    let metric: Box<dyn SimilarityMetric + Send + Sync> = match key.as_str() {
+        "lcs" => Box::new(Lcs),
+        "levenshtein" => Box::new(Levenshtein),
+        "osa" => Box::new(Osa),
+        "damerau-levenshtein" => Box::new(DamerauLevenshtein),
+        "jaro" => Box::new(Jaro),
+        "jaro-winkler" => Box::new(JaroWinkler),
+        "sorensen-dice" => Box::new(SorensenDice),
+        "jaccard" => Box::new(Jaccard(2)),
+        "cosine" => Box::new(Cosine(2)),
+        "ratcliff-obershelp" => Box::new(RatcliffObershelp),
+        _ => return Err(UnknownMetricError { name: name.to_string() })
+    };
+    Ok(metric)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn metrics_match_functions() {
+        assert_eq!(Lcs.similarity("age", "page"), compare_similarity("age", "page"));
+        assert_eq!(Levenshtein.similarity("kitten", "sitting"), normalized_levenshtein("kitten", "sitting"));
+        assert_eq!(JaroWinkler.similarity("martha", "marhta"), jaro_winkler_similarity("martha", "marhta"));
+        assert_eq!(Jaccard(2).similarity("night", "nacht"), jaccard_similarity("night", "nacht", 2));
+    }
+
+    #[test]
+    fn normalized_distances() {
+        assert_eq!(Levenshtein.distance("kitten", "sitting"), 3.0);
+        assert_eq!(Normalized(Levenshtein).similarity("kitten", "sitting"), Levenshtein.similarity("kitten", "sitting"));
+        assert_eq!(Normalized(Osa).similarity("ca", "ac"), normalized_osa("ca", "ac"));
+        assert_eq!(Normalized(DamerauLevenshtein).similarity("", ""), 1.0);
+
+        struct Squared;
+        impl DistanceMetric for Squared {
+            fn distance(&self, left: &str, right: &str) -> f64 {
+                (levenshtein_distance(left, right) as f64).powi(2)
+            }
+            fn max_distance(&self, _: &str, _: &str) -> f64 {
+                4.0
+            }
+        }
+        assert_eq!(Normalized(Squared).similarity("ab", "ax"), 0.75);
+        assert_eq!(Normalized(Squared).similarity("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn metrics_by_name() {
+        for name in METRIC_NAMES {
+            assert!(metric_from_name(name).is_ok());
+        }
+        let metric = metric_from_name(" Jaro_Winkler ").unwrap();
+        assert_eq!(metric.similarity("martha", "marhta"), jaro_winkler_similarity("martha", "marhta"));
+        let error = metric_from_name("soundex").err().unwrap();
+        assert_eq!(error.to_string(), "unknown metric \"soundex\"");
+    }
+
+    #[test]
+    fn find_best_with_custom_metric() {
+        let options = vec!["fill", "night", "ride"];
+        let best = find_best_with("fight", &options, &Lcs).unwrap();
+        assert_eq!((best.index, best.score), (1, 0.8));
+        let same_length = |left: &str, right: &str| if left.len() == right.len() { 1.0 } else { 0.0 };
+        assert_eq!(find_best_with("fight", &options, &same_length).unwrap().value, "night");
+        let boxed: Box<dyn SimilarityMetric> = Box::new(Jaro);
+        assert_eq!(find_best_with("fight", &options, boxed.as_ref()).unwrap().value, "night");
+        assert_eq!(find_best_with("fight", &[] as &[&str], &Lcs), None);
+    }
+}
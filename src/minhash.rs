@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use crate::ngram::char_ngrams;
+
+/// Default seed of the hash functions of a [`MinHasher`]
+pub const DEFAULT_MINHASH_SEED: u64 = 0x5eed;
+
+/// FNV-1a hash of bytes, stable across platforms and compiler versions
+pub(crate) fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes.into_iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// SplitMix64 finalizer that scrambles the bits of a value
+pub(crate) fn mix(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+    value ^ (value >> 31)
+}
+
+/// MinHash signature of a text, created with [`MinHasher::signature`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MinHashSignature(Vec<u64>);
+
+impl MinHashSignature {
+    /// Get the minimal values of every hash function
+    pub fn values(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// Estimate the Jaccard similarity of the shingles of the two texts
+    ///
+    /// The estimate is the fraction of hash functions with equal minimal values.
+    /// Signatures of different lengths rate 0.0.
+    pub fn similarity(&self, other: &MinHashSignature) -> f64 {
+        if self.0.len() != other.0.len() || self.0.is_empty() {
+            return 0.0;
+        }
+        let same = self.0.iter().zip(&other.0).filter(|(left, right)| left == right).count();
+        same as f64 / self.0.len() as f64
+    }
+}
+
+/// Creates MinHash signatures of texts
+///
+/// A text is split into shingles, the overlapping n-grams of its characters, and the signature
+/// keeps the smallest value of every hash function over the shingles. Two signatures agree on
+/// about as many values as the [`jaccard_similarity`](crate::jaccard_similarity) of the shingles,
+/// so long texts can be compared with a fixed number of integers.
+/// # Example
+/// ```
+/// use similar_string::MinHasher;
+///
+/// let hasher = MinHasher::new(128, 3);
+/// let left = hasher.signature("the quick brown fox jumps over the lazy dog");
+/// let right = hasher.signature("the quick brown fox jumped over the lazy dog");
+///
+/// left.similarity(&right); // about 0.8
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinHasher {
+    seeds: Vec<u64>,
+    shingle_size: usize
+}
+
+impl MinHasher {
+    /// Create a hasher with `num_hashes` hash functions over shingles of `shingle_size` characters
+    /// # Panics
+    /// This function panics if `num_hashes` or `shingle_size` is zero
+    pub fn new(num_hashes: usize, shingle_size: usize) -> Self {
+        MinHasher::with_seed(num_hashes, shingle_size, DEFAULT_MINHASH_SEED)
+    }
+
+    /// Create a hasher whose hash functions are derived from the given seed
+    ///
+    /// Only signatures of hashers with the same seed can be compared.
+    /// # Panics
+    /// This function panics if `num_hashes` or `shingle_size` is zero
+    pub fn with_seed(num_hashes: usize, shingle_size: usize, seed: u64) -> Self {
+        assert!(num_hashes > 0, "number of hash functions must be greater than zero");
+        assert!(shingle_size > 0, "shingle size must be greater than zero");
+        let seeds = (0..num_hashes as u64).map(|index| mix(seed.wrapping_add(index))).collect();
+        MinHasher { seeds, shingle_size }
+    }
+
+    /// Get the number of hash functions, which is the length of the signatures
+    pub fn num_hashes(&self) -> usize {
+        self.seeds.len()
+    }
+
+    /// Create the signature of the text
+    pub fn signature(&self, text: impl AsRef<str>) -> MinHashSignature {
+        let mut values = vec![u64::MAX; self.seeds.len()];
+        for shingle in char_ngrams(text.as_ref(), self.shingle_size) {
+            let hash = fnv1a(shingle.bytes());
+            for (value, seed) in values.iter_mut().zip(&self.seeds) {
+                *value = (*value).min(mix(hash ^ seed));
+            }
+        }
+        MinHashSignature(values)
+    }
+}
+
+/// Index of MinHash signatures that finds near-duplicates without comparing all the pairs
+///
+/// Locality-sensitive hashing splits every signature into bands of rows and texts whose
+/// signatures are equal in any band become candidates. The number of bands and rows sets
+/// the similarity around which texts start to become candidates, and the candidates are
+/// then checked against the threshold with their estimated similarity.
+/// # Example
+/// ```
+/// use similar_string::{LshIndex, MinHasher};
+///
+/// let mut index = LshIndex::with_threshold(MinHasher::new(128, 3), 0.5);
+/// index.insert("the quick brown fox jumps over the lazy dog");
+/// index.insert("lorem ipsum dolor sit amet");
+///
+/// let found = index.query("the quick brown fox jumped over the lazy dog");
+/// found.iter().map(|(id, _)| *id).collect::<Vec<_>>(); // [0]
+/// ```
+pub struct LshIndex {
+    hasher: MinHasher,
+    rows: usize,
+    threshold: f64,
+    signatures: Vec<MinHashSignature>,
+    buckets: Vec<HashMap<u64, Vec<usize>>>
+}
+
+impl LshIndex {
+    /// Create an index that splits signatures into `bands` bands of `rows` values
+    ///
+    /// Texts are found when their estimated similarity is at least `threshold`.
+    /// # Panics
+    /// This function panics if `bands` or `rows` is zero or if they need more
+    /// values than the signatures of the hasher have
+    pub fn new(hasher: MinHasher, bands: usize, rows: usize, threshold: f64) -> Self {
+        assert!(bands > 0 && rows > 0, "number of bands and rows must be greater than zero");
+        assert!(bands * rows <= hasher.num_hashes(), "bands need more values than the signatures have");
+        LshIndex { hasher, rows, threshold, signatures: vec![], buckets: vec![HashMap::new(); bands] }
+    }
+
+    /// Create an index with bands and rows chosen for the similarity threshold
+    ///
+    /// The bands use all the values of the signatures and texts of about `threshold`
+    /// similarity have even odds of becoming candidates.
+    pub fn with_threshold(hasher: MinHasher, threshold: f64) -> Self {
+        let num_hashes = hasher.num_hashes();
+        let error = |bands: usize| {
+            let rows = num_hashes / bands;
+            ((1.0 / bands as f64).powf(1.0 / rows as f64) - threshold).abs()
+        };
+        let bands = (1..=num_hashes)
+            .filter(|bands| num_hashes.is_multiple_of(*bands))
+            .min_by(|left, right| error(*left).total_cmp(&error(*right)))
+            .expect("hashers have at least one hash function");
+        LshIndex::new(hasher, bands, num_hashes / bands, threshold)
+    }
+
+    /// Get the hasher that creates the signatures of the index
+    pub fn hasher(&self) -> &MinHasher {
+        &self.hasher
+    }
+
+    /// Get the number of bands and rows of the signatures
+    pub fn bands(&self) -> (usize, usize) {
+        (self.buckets.len(), self.rows)
+    }
+
+    /// Get the number of texts in the index
+    pub fn len(&self) -> usize {
+        self.signatures.len()
+    }
+
+    /// Check whether the index has no texts
+    pub fn is_empty(&self) -> bool {
+        self.signatures.is_empty()
+    }
+
+    /// Hashes of the bands of the signature
+    fn band_hashes<'a>(&self, signature: &'a MinHashSignature) -> impl Iterator<Item = u64> + 'a {
+        signature.0.chunks_exact(self.rows)
+            .take(self.buckets.len())
+            .map(|band| fnv1a(band.iter().flat_map(|value| value.to_le_bytes())))
+    }
+
+    /// Add a text to the index and get its id, which is the number of texts added before it
+    pub fn insert(&mut self, text: impl AsRef<str>) -> usize {
+        let signature = self.hasher.signature(text);
+        let id = self.signatures.len();
+        let hashes: Vec<u64> = self.band_hashes(&signature).collect();
+        for (bucket, hash) in self.buckets.iter_mut().zip(hashes) {
+            bucket.entry(hash).or_default().push(id);
+        }
+        self.signatures.push(signature);
+        id
+    }
+
+    /// Get ids and estimated similarities of the texts similar to the given one
+    ///
+    /// Only texts that share a band with the query are compared and those with
+    /// an estimated similarity of at least the threshold are returned, sorted by their ids.
+    pub fn query(&self, text: impl AsRef<str>) -> Vec<(usize, f64)> {
+        let signature = self.hasher.signature(text);
+        let mut candidates: Vec<usize> = self.band_hashes(&signature)
+            .zip(&self.buckets)
+            .flat_map(|(hash, bucket)| bucket.get(&hash).into_iter().flatten().copied())
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates.into_iter()
+            .map(|id| (id, signature.similarity(&self.signatures[id])))
+            .filter(|(_, similarity)| *similarity >= self.threshold)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn minhash_estimates_jaccard() {
+        let hasher = MinHasher::new(256, 3);
+        let left = "the quick brown fox jumps over the lazy dog";
+        let right = "the quick brown fox jumped over the lazy dog";
+        let estimate = hasher.signature(left).similarity(&hasher.signature(right));
+        assert!((estimate - jaccard_similarity(left, right, 3)).abs() < 0.1, "{}", estimate);
+        assert_eq!(hasher.signature(left), hasher.signature(left));
+        assert_eq!(hasher.signature("").similarity(&hasher.signature("")), 1.0);
+        assert_eq!(hasher.signature(left).values().len(), 256);
+        let other = MinHasher::new(64, 3);
+        assert_eq!(other.signature(left).similarity(&hasher.signature(left)), 0.0);
+    }
+
+    #[test]
+    fn lsh_finds_near_duplicates() {
+        let mut index = LshIndex::with_threshold(MinHasher::new(128, 3), 0.5);
+        assert_eq!(index.bands(), (32, 4));
+        let texts = [
+            "the quick brown fox jumps over the lazy dog",
+            "lorem ipsum dolor sit amet",
+            "the quick brown fox jumps over the lazy cat",
+            "consectetur adipiscing elit"
+        ];
+        for (id, text) in texts.into_iter().enumerate() {
+            assert_eq!(index.insert(text), id);
+        }
+        let found: Vec<usize> = index.query("the quick brown fox jumped over the lazy dog").into_iter().map(|(id, _)| id).collect();
+        assert_eq!(found, vec![0, 2]);
+        assert_eq!(index.query("lorem ipsum dolor sit amet"), vec![(1, 1.0)]);
+        assert!(index.query("something else entirely").is_empty());
+        assert_eq!(index.len(), 4);
+    }
+}
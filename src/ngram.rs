@@ -0,0 +1,271 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::iter::once;
+
+/// Split a string into overlapping n-grams of characters
+///
+/// Strings shorter than `n` characters produce a single n-gram
+/// made of the whole string and empty strings produce none.
+pub(crate) fn char_ngrams(text: &str, n: usize) -> Vec<&str> {
+    assert!(n > 0, "n-gram size must be greater than zero");
+    let bounds: Vec<usize> = text.char_indices()
+        .map(|(index, _)| index)
+        .chain(once(text.len()))
+        .collect();
+    let count = bounds.len() - 1;
+    match count {
+        0 => vec![],
+        _ if count < n => vec![text],
+        _ => (0..=count - n)
+            .map(|index| &text[bounds[index]..bounds[index + n]])
+            .collect()
+    }
+}
+
+/// Count occurrences of every character n-gram of a string
+pub(crate) fn ngram_profile(text: &str, n: usize) -> HashMap<&str, usize> {
+    let mut profile = HashMap::new();
+    for ngram in char_ngrams(text, n) {
+        *profile.entry(ngram).or_insert(0) += 1;
+    }
+    profile
+}
+
+/// Euclidean norm of an n-gram frequency vector
+#[inline]
+fn profile_norm<K>(profile: &HashMap<K, usize>) -> f64 {
+    profile.values().map(|count| (count * count) as f64).sum::<f64>().sqrt()
+}
+
+/// Cosine of the angle between two n-gram frequency vectors
+pub(crate) fn profile_cosine<L, R>(left: &HashMap<L, usize>, right: &HashMap<R, usize>) -> f64
+where
+    L: Borrow<str> + Eq + Hash,
+    R: Borrow<str> + Eq + Hash
+{
+    // Empty strings should match
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+    // Iterate over the smaller profile
+    let dot: usize = if left.len() < right.len() {
+        left.iter()
+            .filter_map(|(ngram, count)| right.get(ngram.borrow()).map(|other| count * other))
+            .sum()
+    } else {
+        right.iter()
+            .filter_map(|(ngram, count)| left.get(ngram.borrow()).map(|other| count * other))
+            .sum()
+    };
+    dot as f64 / (profile_norm(left) * profile_norm(right))
+}
+
+#[inline]
+fn ngram_set(text: &str, n: usize) -> HashSet<&str> {
+    char_ngrams(text, n).into_iter().collect()
+}
+
+/// Get the Jaccard similarity of character n-grams of two strings
+///
+/// Both strings are split into sets of overlapping n-grams and the size of
+/// their intersection is divided by the size of their union.
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// # Panics
+/// This function panics if `n` is zero
+/// # Example
+/// ```
+/// use similar_string::jaccard_similarity;
+///
+/// // Bigrams "ni", "ig", "gh", "ht" against "fi", "ig", "gh", "ht"
+/// jaccard_similarity("night", "fight", 2); // 0.6
+/// ```
+pub fn jaccard_similarity(left: impl AsRef<str>, right: impl AsRef<str>, n: usize) -> f64 {
+    let left = ngram_set(left.as_ref(), n);
+    let right = ngram_set(right.as_ref(), n);
+    let union = left.union(&right).count();
+    // Empty strings should match
+    if union == 0 {
+        return 1.0;
+    }
+    left.intersection(&right).count() as f64 / union as f64
+}
+
+/// Get the Sørensen–Dice coefficient of character bigrams of two strings
+///
+/// Whitespace is ignored and bigrams are counted with repetitions, which matches
+/// `sorensen_dice` from strsim and `compareTwoStrings` from the JavaScript string-similarity package.
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// # Example
+/// ```
+/// use similar_string::sorensen_dice_similarity;
+///
+/// sorensen_dice_similarity("french", "quebec"); // 0.0
+/// sorensen_dice_similarity("healed", "sealed"); // 0.8
+/// ```
+pub fn sorensen_dice_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left: String = left.as_ref().chars().filter(|letter| !letter.is_whitespace()).collect();
+    let right: String = right.as_ref().chars().filter(|letter| !letter.is_whitespace()).collect();
+    if left == right {
+        return 1.0;
+    }
+    let (left_size, right_size) = (left.chars().count(), right.chars().count());
+    if left_size < 2 || right_size < 2 {
+        return 0.0;
+    }
+    let mut counts = ngram_profile(&left, 2);
+    let mut intersection = 0;
+    for bigram in char_ngrams(&right, 2) {
+        if let Some(count) = counts.get_mut(bigram) {
+            if *count > 0 {
+                *count -= 1;
+                intersection += 1;
+            }
+        }
+    }
+    (2 * intersection) as f64 / (left_size + right_size - 2) as f64
+}
+
+/// Get the cosine similarity of character n-gram frequency profiles of two strings
+///
+/// Both strings are turned into vectors of n-gram occurrence counts and the cosine
+/// of the angle between them is returned. This runs in linear time, which makes it
+/// a good approximate metric for long texts.
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// # Panics
+/// This function panics if `n` is zero
+/// # Example
+/// ```
+/// use similar_string::cosine_similarity;
+///
+/// cosine_similarity("night", "fight", 2); // 0.75
+/// ```
+pub fn cosine_similarity(left: impl AsRef<str>, right: impl AsRef<str>, n: usize) -> f64 {
+    let left = ngram_profile(left.as_ref(), n);
+    let right = ngram_profile(right.as_ref(), n);
+    profile_cosine(&left, &right)
+}
+
+/// Get the Tversky index of character n-grams of two strings
+///
+/// This is an asymmetric generalization of the Jaccard similarity. N-grams present only in
+/// the `left` string are weighted with `alpha` and those present only in the `right` one with `beta`.
+/// Setting both to 1.0 gives the Jaccard similarity and setting both to 0.5 gives the Dice coefficient.
+/// With a low `beta` a query contained in the candidate scores close to 1.0.
+/// The weights should be non-negative, then the rating is in range from 0.0 to 1.0.
+/// # Panics
+/// This function panics if `n` is zero
+/// # Example
+/// ```
+/// use similar_string::tversky_index;
+///
+/// // The query "fire" is fully contained in the candidate
+/// tversky_index("fire", "firefighter", 2, 1.0, 0.0); // 1.0
+/// tversky_index("firefighter", "fire", 2, 1.0, 0.0); // 0.3333333333333333
+/// ```
+pub fn tversky_index(left: impl AsRef<str>, right: impl AsRef<str>, n: usize, alpha: f64, beta: f64) -> f64 {
+    let left = ngram_set(left.as_ref(), n);
+    let right = ngram_set(right.as_ref(), n);
+    // Empty strings should match
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    let common = left.intersection(&right).count() as f64;
+    let left_only = left.difference(&right).count() as f64;
+    let right_only = right.difference(&left).count() as f64;
+    let denominator = common + alpha * left_only + beta * right_only;
+    if denominator == 0.0 { 0.0 } else { common / denominator }
+}
+
+/// Get the overlap coefficient of character n-grams of two strings
+///
+/// The size of the intersection of both n-gram sets is divided by the size of the smaller one,
+/// so a string fully contained in the other one scores 1.0.
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// # Panics
+/// This function panics if `n` is zero
+/// # Example
+/// ```
+/// use similar_string::overlap_coefficient;
+///
+/// overlap_coefficient("fire", "firefighter", 2); // 1.0
+/// ```
+pub fn overlap_coefficient(left: impl AsRef<str>, right: impl AsRef<str>, n: usize) -> f64 {
+    let left = ngram_set(left.as_ref(), n);
+    let right = ngram_set(right.as_ref(), n);
+    // Empty strings should match
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    let size = left.len().min(right.len());
+    if size == 0 {
+        return 0.0;
+    }
+    left.intersection(&right).count() as f64 / size as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use super::char_ngrams;
+
+    #[test]
+    fn ngrams_split() {
+        assert_eq!(char_ngrams("night", 2), vec!["ni", "ig", "gh", "ht"]);
+        assert_eq!(char_ngrams("żółw", 3), vec!["żół", "ółw"]);
+        assert_eq!(char_ngrams("ab", 3), vec!["ab"]);
+        assert!(char_ngrams("", 2).is_empty());
+    }
+
+    #[test]
+    fn jaccard_works() {
+        assert_eq!(jaccard_similarity("night", "fight", 2), 0.6);
+        assert_eq!(jaccard_similarity("", "", 2), 1.0);
+        assert_eq!(jaccard_similarity("abc", "", 2), 0.0);
+        assert_eq!(jaccard_similarity("abc", "abc", 3), 1.0);
+    }
+
+    #[test]
+    fn sorensen_dice_works() {
+        assert_eq!(sorensen_dice_similarity("french", "quebec"), 0.0);
+        assert_eq!(sorensen_dice_similarity("healed", "sealed"), 0.8);
+        assert_eq!(sorensen_dice_similarity("a", "b"), 0.0);
+        assert_eq!(sorensen_dice_similarity("", ""), 1.0);
+        assert_eq!(sorensen_dice_similarity("web applications", "applications of the web"), 0.7878787878787878);
+    }
+
+    #[test]
+    fn cosine_works() {
+        assert_eq!(cosine_similarity("night", "fight", 2), 0.75);
+        assert_eq!(cosine_similarity("", "", 2), 1.0);
+        assert_eq!(cosine_similarity("abc", "", 2), 0.0);
+        assert_eq!(cosine_similarity("abab", "ab", 2), 2.0 / 5.0f64.sqrt());
+    }
+
+    #[test]
+    fn tversky_works() {
+        assert_eq!(tversky_index("fire", "firefighter", 2, 1.0, 0.0), 1.0);
+        assert_eq!(tversky_index("firefighter", "fire", 2, 1.0, 0.0), 1.0 / 3.0);
+        assert_eq!(tversky_index("night", "fight", 2, 1.0, 1.0), jaccard_similarity("night", "fight", 2));
+        assert_eq!(tversky_index("", "", 2, 1.0, 1.0), 1.0);
+        assert_eq!(tversky_index("ab", "cd", 2, 0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn overlap_works() {
+        assert_eq!(overlap_coefficient("fire", "firefighter", 2), 1.0);
+        assert_eq!(overlap_coefficient("firefighter", "fire", 2), 1.0);
+        assert_eq!(overlap_coefficient("night", "fight", 2), 0.75);
+        assert_eq!(overlap_coefficient("", "", 2), 1.0);
+        assert_eq!(overlap_coefficient("", "abc", 2), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn jaccard_zero_size() {
+        jaccard_similarity("a", "b", 0);
+    }
+}
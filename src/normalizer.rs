@@ -0,0 +1,323 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::equivalence::Equivalences;
+use crate::fold::{expand_long_vowels, fold_accents, fold_confusables, fold_kana, fold_width};
+use crate::lcs::PatternMasks;
+use crate::lcs_rating;
+use crate::search::Match;
+
+/// Preprocessing of strings that happens before they are compared
+pub(crate) trait Preprocess {
+    /// Get the preprocessed string, borrowing it when nothing has to change
+    fn preprocess<'a>(&self, text: &'a str) -> Cow<'a, str>;
+}
+
+/// Ratings of the options against the target after preprocessing of all of them
+pub(crate) fn preprocessed_ratings(preprocess: &impl Preprocess, target: &str, options: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<f64> {
+    let target = preprocess.preprocess(target);
+    let chars: Vec<char> = target.chars().collect();
+    let masks = PatternMasks::new(&chars);
+    options.into_iter()
+        .map(|option| {
+            let option = preprocess.preprocess(option.as_ref());
+            lcs_rating(masks.lcs_length(option.chars()), &target, &option)
+        })
+        .collect()
+}
+
+/// Best option after preprocessing of the target and all the options, the first one wins a tie
+///
+/// The match holds the original option.
+pub(crate) fn preprocessed_best<'a, S: AsRef<str> + ?Sized + 'a>(preprocess: &impl Preprocess, target: &str, options: impl IntoIterator<Item = &'a S>) -> Option<Match<'a>> {
+    let target = preprocess.preprocess(target);
+    let chars: Vec<char> = target.chars().collect();
+    let masks = PatternMasks::new(&chars);
+    let mut best: Option<Match<'a>> = None;
+    for (index, value) in options.into_iter().enumerate() {
+        let value = value.as_ref();
+        let option = preprocess.preprocess(value);
+        let score = lcs_rating(masks.lcs_length(option.chars()), &target, &option);
+        if best.is_none_or(|best| score > best.score) {
+            best = Some(Match { index, value, score });
+        }
+    }
+    best
+}
+
+/// Unicode normalization form, available with the `unicode` feature
+///
+/// Both forms compose characters, so that "é" written as a single character matches
+/// "e" followed by a combining accent. The compatibility form additionally replaces
+/// characters such as ligatures or superscripts with their plain equivalents.
+#[cfg(feature = "unicode")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnicodeForm {
+    /// Canonical composition
+    Nfc,
+    /// Compatibility composition
+    Nfkc
+}
+
+#[cfg(feature = "unicode")]
+impl UnicodeForm {
+    /// Normalize the string, borrowing it when it is already normalized
+    pub(crate) fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        use unicode_normalization::{is_nfc, is_nfkc, UnicodeNormalization};
+
+        match self {
+            UnicodeForm::Nfc if is_nfc(text) => Cow::Borrowed(text),
+            UnicodeForm::Nfc => Cow::Owned(text.nfc().collect()),
+            UnicodeForm::Nfkc if is_nfkc(text) => Cow::Borrowed(text),
+            UnicodeForm::Nfkc => Cow::Owned(text.nfkc().collect())
+        }
+    }
+}
+
+/// Single transformation of a [`Normalizer`]
+#[derive(Clone)]
+enum Step {
+    Lowercase,
+    Trim,
+    CollapseWhitespace,
+    RemovePunctuation,
+    FoldAccents,
+    FoldWidth,
+    FoldKana,
+    ExpandLongVowels,
+    FoldConfusables,
+    Equivalences(Equivalences),
+    #[cfg(feature = "unicode")]
+    Unicode(UnicodeForm),
+    Custom(Arc<dyn Fn(&str) -> String + Send + Sync>)
+}
+
+impl Step {
+    fn apply(&self, text: &str) -> String {
+        match self {
+            Step::Lowercase => text.to_lowercase(),
+            Step::Trim => text.trim().to_string(),
+            Step::CollapseWhitespace => {
+                let mut result = String::with_capacity(text.len());
+                let mut after_space = false;
+                for letter in text.chars() {
+                    if letter.is_whitespace() {
+                        if !after_space {
+                            result.push(' ');
+                        }
+                        after_space = true;
+                    } else {
+                        result.push(letter);
+                        after_space = false;
+                    }
+                }
+                result
+            }
+            Step::RemovePunctuation => text.chars()
+                .filter(|letter| letter.is_alphanumeric() || letter.is_whitespace())
+                .collect(),
+            Step::FoldAccents => fold_accents(text).into_owned(),
+            Step::FoldWidth => fold_width(text).into_owned(),
+            Step::FoldKana => fold_kana(text).into_owned(),
+            Step::ExpandLongVowels => expand_long_vowels(text).into_owned(),
+            Step::FoldConfusables => fold_confusables(text).into_owned(),
+            Step::Equivalences(equivalences) => equivalences.apply(text),
+            #[cfg(feature = "unicode")]
+            Step::Unicode(form) => form.apply(text).into_owned(),
+            Step::Custom(transform) => transform(text)
+        }
+    }
+}
+
+impl fmt::Debug for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Lowercase => write!(f, "Lowercase"),
+            Step::Trim => write!(f, "Trim"),
+            Step::CollapseWhitespace => write!(f, "CollapseWhitespace"),
+            Step::RemovePunctuation => write!(f, "RemovePunctuation"),
+            Step::FoldAccents => write!(f, "FoldAccents"),
+            Step::FoldWidth => write!(f, "FoldWidth"),
+            Step::FoldKana => write!(f, "FoldKana"),
+            Step::ExpandLongVowels => write!(f, "ExpandLongVowels"),
+            Step::FoldConfusables => write!(f, "FoldConfusables"),
+            Step::Equivalences(equivalences) => write!(f, "{:?}", equivalences),
+            #[cfg(feature = "unicode")]
+            Step::Unicode(form) => write!(f, "Unicode({:?})", form),
+            Step::Custom(_) => write!(f, "Custom")
+        }
+    }
+}
+
+/// Pipeline of transformations applied to strings before they are compared
+///
+/// The steps run in the order they were added. Using the same normalizer for the target
+/// and the options guarantees that both are normalized identically.
+/// # Example
+/// ```
+/// use similar_string::Normalizer;
+///
+/// let normalizer = Normalizer::new()
+///     .lowercase()
+///     .collapse_whitespace()
+///     .custom(|text| text.replace("colour", "color"));
+///
+/// normalizer.apply("Red  Colour"); // "red color"
+/// normalizer.find_best("RED COLOR", &["blue color", "red colour"]).unwrap().value; // "red colour"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Normalizer {
+    steps: Vec<Step>
+}
+
+impl Normalizer {
+    /// Create a normalizer that leaves strings unchanged
+    pub fn new() -> Self {
+        Normalizer::default()
+    }
+
+    fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Convert the string to lowercase
+    pub fn lowercase(self) -> Self {
+        self.step(Step::Lowercase)
+    }
+
+    /// Remove leading and trailing whitespace
+    pub fn trim(self) -> Self {
+        self.step(Step::Trim)
+    }
+
+    /// Replace every run of whitespace with a single space
+    pub fn collapse_whitespace(self) -> Self {
+        self.step(Step::CollapseWhitespace)
+    }
+
+    /// Remove every character that is neither alphanumeric nor whitespace
+    pub fn remove_punctuation(self) -> Self {
+        self.step(Step::RemovePunctuation)
+    }
+
+    /// Remove diacritics from Latin letters, see [`fold_accents`](crate::fold_accents)
+    pub fn fold_accents(self) -> Self {
+        self.step(Step::FoldAccents)
+    }
+
+    /// Replace fullwidth and halfwidth characters, see [`fold_width`](crate::fold_width)
+    pub fn fold_width(self) -> Self {
+        self.step(Step::FoldWidth)
+    }
+
+    /// Replace katakana with hiragana, see [`fold_kana`](crate::fold_kana)
+    pub fn fold_kana(self) -> Self {
+        self.step(Step::FoldKana)
+    }
+
+    /// Spell out long vowel marks, see [`expand_long_vowels`](crate::expand_long_vowels)
+    pub fn expand_long_vowels(self) -> Self {
+        self.step(Step::ExpandLongVowels)
+    }
+
+    /// Replace characters that look alike, see [`fold_confusables`](crate::fold_confusables)
+    pub fn fold_confusables(self) -> Self {
+        self.step(Step::FoldConfusables)
+    }
+
+    /// Replace members of the equivalence classes, see [`Equivalences`]
+    pub fn equivalences(self, equivalences: Equivalences) -> Self {
+        self.step(Step::Equivalences(equivalences))
+    }
+
+    /// Normalize the string to the given Unicode form, available with the `unicode` feature
+    #[cfg(feature = "unicode")]
+    pub fn unicode_form(self, form: UnicodeForm) -> Self {
+        self.step(Step::Unicode(form))
+    }
+
+    /// Apply a custom transformation
+    pub fn custom(self, transform: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        self.step(Step::Custom(Arc::new(transform)))
+    }
+
+    /// Check whether the normalizer has no steps
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Apply all the steps to the string
+    pub fn apply(&self, text: &str) -> String {
+        self.preprocess(text).into_owned()
+    }
+
+    /// Get score of similarity of two strings after normalization, see [`compare_similarity`](crate::compare_similarity)
+    pub fn compare(&self, left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+        crate::compare_similarity(self.preprocess(left.as_ref()), self.preprocess(right.as_ref()))
+    }
+
+    /// Get similarity ratings of all the options after normalization
+    pub fn ratings(&self, target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<f64> {
+        preprocessed_ratings(self, target.as_ref(), options)
+    }
+
+    /// Find the option that is the most similar to the target string after normalization
+    ///
+    /// The match holds the original option. The first option wins a tie
+    /// and `None` is returned if there are no options.
+    pub fn find_best<'a, S: AsRef<str> + ?Sized + 'a>(&self, target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>) -> Option<Match<'a>> {
+        preprocessed_best(self, target.as_ref(), options)
+    }
+}
+
+impl Preprocess for Normalizer {
+    fn preprocess<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut result = Cow::Borrowed(text);
+        for step in &self.steps {
+            result = Cow::Owned(step.apply(&result));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn steps_run_in_order() {
+        let normalizer = Normalizer::new().remove_punctuation().collapse_whitespace().trim().lowercase();
+        assert_eq!(normalizer.apply("  Hello,  World! "), "hello world");
+        let custom_first = Normalizer::new().custom(|text| text.replace('-', " ")).collapse_whitespace();
+        assert_eq!(custom_first.apply("a - b"), "a b");
+        assert_eq!(Normalizer::new().apply("Same"), "Same");
+        assert!(Normalizer::new().is_empty());
+        assert_eq!(Normalizer::new().lowercase().fold_accents().apply("Café"), "cafe");
+        assert_eq!(Normalizer::new().fold_width().apply("ＡＢＣ　ｶﾞ"), "ABC ガ");
+        assert_eq!(Normalizer::new().expand_long_vowels().fold_kana().apply("ラーメン"), "らあめん");
+        assert_eq!(Normalizer::new().fold_confusables().lowercase().apply("АРРLЕ"), "apple");
+        assert_eq!(Normalizer::new().lowercase().equivalences(Equivalences::new().class(["o", "0"])).apply("B00K"), "book");
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn unicode_forms() {
+        let composed = Normalizer::new().unicode_form(UnicodeForm::Nfc);
+        assert_eq!(composed.apply("e\u{301}"), "\u{e9}");
+        assert_eq!(composed.compare("caf\u{e9}", "cafe\u{301}"), 1.0);
+        let compatible = Normalizer::new().unicode_form(UnicodeForm::Nfkc);
+        assert_eq!(compatible.apply("\u{fb01}x\u{b2}"), "fix2");
+        assert_eq!(composed.apply("\u{fb01}"), "\u{fb01}");
+    }
+
+    #[test]
+    fn normalizer_wraps_search() {
+        let normalizer = Normalizer::new().lowercase();
+        assert_eq!(normalizer.compare("AGE", "page"), 0.75);
+        assert_eq!(normalizer.ratings("FIGHT", ["fill", "Night"]), vec![0.4, 0.8]);
+        let best = normalizer.find_best("FIGHT", &["fill", "Night", "ride"]).unwrap();
+        assert_eq!((best.index, best.value), (1, "Night"));
+    }
+}
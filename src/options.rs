@@ -0,0 +1,262 @@
+use std::borrow::Cow;
+
+use crate::fold::{expand_long_vowels, fold_accents, fold_confusables, fold_kana, fold_width, then};
+use crate::normalizer::{Preprocess, preprocessed_best, preprocessed_ratings};
+#[cfg(feature = "unicode")]
+use crate::normalizer::UnicodeForm;
+use crate::search::Match;
+
+/// Preprocessing applied to strings before they are compared
+///
+/// Only the compared copies are changed, matches still refer to the original options.
+/// # Example
+/// ```
+/// use similar_string::SimilarityOptions;
+///
+/// let options = SimilarityOptions::new()
+///     .case_insensitive()
+///     .ignore_punctuation()
+///     .collapse_whitespace()
+///     .trim_whitespace();
+///
+/// options.compare("  Hello,   World! ", "hello world"); // 1.0
+/// options.find_best("NIGHT", &["fill", "night", "ride"]).unwrap().value; // "night"
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SimilarityOptions {
+    case_insensitive: bool,
+    trim_whitespace: bool,
+    ignore_punctuation: bool,
+    collapse_whitespace: bool,
+    fold_accents: bool,
+    fold_width: bool,
+    fold_kana: bool,
+    expand_long_vowels: bool,
+    fold_confusables: bool,
+    #[cfg(feature = "unicode")]
+    unicode_form: Option<UnicodeForm>
+}
+
+impl SimilarityOptions {
+    /// Create options that compare the strings as they are
+    pub fn new() -> Self {
+        SimilarityOptions::default()
+    }
+
+    /// Ignore the letter case
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Ignore leading and trailing whitespace
+    pub fn trim_whitespace(mut self) -> Self {
+        self.trim_whitespace = true;
+        self
+    }
+
+    /// Ignore every character that is neither alphanumeric nor whitespace
+    pub fn ignore_punctuation(mut self) -> Self {
+        self.ignore_punctuation = true;
+        self
+    }
+
+    /// Treat every run of whitespace as a single space
+    pub fn collapse_whitespace(mut self) -> Self {
+        self.collapse_whitespace = true;
+        self
+    }
+
+    /// Remove diacritics from Latin letters, see [`fold_accents`](crate::fold_accents)
+    pub fn fold_accents(mut self) -> Self {
+        self.fold_accents = true;
+        self
+    }
+
+    /// Replace fullwidth and halfwidth characters, see [`fold_width`](crate::fold_width)
+    pub fn fold_width(mut self) -> Self {
+        self.fold_width = true;
+        self
+    }
+
+    /// Replace katakana with hiragana, see [`fold_kana`](crate::fold_kana)
+    pub fn fold_kana(mut self) -> Self {
+        self.fold_kana = true;
+        self
+    }
+
+    /// Spell out long vowel marks, see [`expand_long_vowels`](crate::expand_long_vowels)
+    pub fn expand_long_vowels(mut self) -> Self {
+        self.expand_long_vowels = true;
+        self
+    }
+
+    /// Replace characters that look alike, see [`fold_confusables`](crate::fold_confusables)
+    pub fn fold_confusables(mut self) -> Self {
+        self.fold_confusables = true;
+        self
+    }
+
+    /// Normalize the strings to the given Unicode form first, available with the `unicode` feature
+    #[cfg(feature = "unicode")]
+    pub fn unicode_form(mut self, form: UnicodeForm) -> Self {
+        self.unicode_form = Some(form);
+        self
+    }
+
+    /// Apply the preprocessing to the string
+    ///
+    /// The string is only copied when it has to be changed.
+    pub fn normalize<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(text);
+        #[cfg(feature = "unicode")]
+        if let Some(form) = self.unicode_form {
+            text = then(text, |text| form.apply(text));
+        }
+        if self.fold_width {
+            text = then(text, fold_width);
+        }
+        if self.expand_long_vowels {
+            text = then(text, expand_long_vowels);
+        }
+        if self.fold_kana {
+            text = then(text, fold_kana);
+        }
+        if self.fold_accents {
+            text = then(text, fold_accents);
+        }
+        if self.fold_confusables {
+            text = then(text, fold_confusables);
+        }
+        text = then(text, |text| self.clean(text));
+        // Lowercase letters such as "m" only appear after the case is folded
+        if self.fold_confusables && self.case_insensitive {
+            text = then(text, fold_confusables);
+        }
+        text
+    }
+
+    /// Apply the preprocessing of letter case, punctuation and whitespace
+    fn clean<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let text = if self.trim_whitespace { text.trim() } else { text };
+        if !self.case_insensitive && !self.ignore_punctuation && !self.collapse_whitespace {
+            return Cow::Borrowed(text);
+        }
+        let mut result = String::with_capacity(text.len());
+        let mut after_space = false;
+        for letter in text.chars() {
+            if self.ignore_punctuation && !letter.is_alphanumeric() && !letter.is_whitespace() {
+                continue;
+            }
+            if self.collapse_whitespace && letter.is_whitespace() {
+                if !after_space {
+                    result.push(' ');
+                }
+                after_space = true;
+                continue;
+            }
+            after_space = false;
+            if self.case_insensitive {
+                result.extend(letter.to_lowercase());
+            } else {
+                result.push(letter);
+            }
+        }
+        // Removed punctuation can leave whitespace at the ends
+        if self.trim_whitespace {
+            let trimmed = result.trim();
+            if trimmed.len() != result.len() {
+                result = trimmed.to_string();
+            }
+        }
+        Cow::Owned(result)
+    }
+
+    /// Get score of similarity of two strings after preprocessing, see [`compare_similarity`](crate::compare_similarity)
+    pub fn compare(&self, left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+        crate::compare_similarity(self.normalize(left.as_ref()), self.normalize(right.as_ref()))
+    }
+
+    /// Get similarity ratings of all the options after preprocessing
+    pub fn ratings(&self, target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<f64> {
+        preprocessed_ratings(self, target.as_ref(), options)
+    }
+
+    /// Find the option that is the most similar to the target string after preprocessing
+    ///
+    /// The match holds the original option. The first option wins a tie
+    /// and `None` is returned if there are no options.
+    pub fn find_best<'a, S: AsRef<str> + ?Sized + 'a>(&self, target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>) -> Option<Match<'a>> {
+        preprocessed_best(self, target.as_ref(), options)
+    }
+}
+
+impl Preprocess for SimilarityOptions {
+    fn preprocess<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        self.normalize(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::*;
+
+    #[test]
+    fn normalize_works() {
+        let all = SimilarityOptions::new().case_insensitive().trim_whitespace().ignore_punctuation().collapse_whitespace();
+        assert_eq!(all.normalize("  Hello,\t\n World! "), "hello world");
+        assert_eq!(all.normalize(" ... "), "");
+        assert_eq!(SimilarityOptions::new().collapse_whitespace().normalize(" a  b "), " a b ");
+        assert!(matches!(SimilarityOptions::new().trim_whitespace().normalize(" a "), Cow::Borrowed("a")));
+        assert!(matches!(SimilarityOptions::new().normalize("A"), Cow::Borrowed("A")));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn unicode_form_comes_first() {
+        let options = SimilarityOptions::new().unicode_form(UnicodeForm::Nfc).case_insensitive();
+        assert_eq!(options.normalize("E\u{301}t\u{c9}"), "\u{e9}t\u{e9}");
+        assert_eq!(options.compare("caf\u{e9}", "CAFE\u{301}"), 1.0);
+        assert!(matches!(SimilarityOptions::new().unicode_form(UnicodeForm::Nfkc).normalize("plain"), Cow::Borrowed("plain")));
+    }
+
+    #[test]
+    fn accents_are_folded_before_case() {
+        let options = SimilarityOptions::new().fold_accents().case_insensitive();
+        assert_eq!(options.normalize("CAFÉ"), "cafe");
+        assert_eq!(options.compare("Crème Brûlée", "creme brulee"), 1.0);
+    }
+
+    #[test]
+    fn width_is_folded() {
+        let options = SimilarityOptions::new().fold_width().case_insensitive();
+        assert_eq!(options.compare("ＡＢＣ１２３", "abc123"), 1.0);
+    }
+
+    #[test]
+    fn kana_is_folded() {
+        let options = SimilarityOptions::new().fold_kana();
+        assert_eq!(options.compare("ラーメン", "らーめん"), 1.0);
+        let options = options.expand_long_vowels();
+        assert_eq!(options.compare("ラーメン", "らあめん"), 1.0);
+    }
+
+    #[test]
+    fn confusables_are_folded() {
+        let options = SimilarityOptions::new().fold_confusables().case_insensitive();
+        assert_eq!(options.compare("раураl.com", "PAYPAL.COM"), 1.0);
+        assert_eq!(options.compare("g00gle", "google"), 1.0);
+    }
+
+    #[test]
+    fn options_wrap_search() {
+        let options = SimilarityOptions::new().case_insensitive();
+        assert_eq!(options.compare("AGE", "page"), 0.75);
+        assert_eq!(options.ratings("FIGHT", ["fill", "Night"]), vec![0.4, 0.8]);
+        let best = options.find_best("FIGHT", &["fill", "Night", "ride"]).unwrap();
+        assert_eq!((best.index, best.value, best.score), (1, "Night", 0.8));
+        assert_eq!(options.find_best("fight", &[] as &[&str]), None);
+    }
+}
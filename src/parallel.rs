@@ -0,0 +1,82 @@
+use rayon::prelude::*;
+
+use crate::compare_similarity;
+use crate::matrix::{Matrix, mirror, upper_row};
+
+/// Find the string amongs the options that is the most similar to the target one using all CPU cores
+///
+/// Returns the same result as [`find_best_similarity`](crate::find_best_similarity),
+/// including `None` for an empty slice and the first option winning a tie.
+/// # Example
+/// ```
+/// use similar_string::par_find_best_similarity;
+///
+/// let options = vec!["fill", "night", "ride"];
+///
+/// par_find_best_similarity("fight", &options); // Some(("night", 0.8))
+/// ```
+pub fn par_find_best_similarity(target: impl AsRef<str> + Sync, options: &[impl AsRef<str> + Sync]) -> Option<(String, f64)> {
+    options.par_iter()
+        .enumerate()
+        .map(|(index, option)| (index, compare_similarity(option.as_ref(), target.as_ref())))
+        .reduce_with(|best, next| {
+            // Prefer the earlier option on ties, just like the sequential search
+            if next.1 > best.1 || (next.1 == best.1 && next.0 < best.0) { next } else { best }
+        })
+        .map(|(index, score)| (options[index].as_ref().to_string(), score))
+}
+
+/// Get all similarity scores against the target string using all CPU cores
+///
+/// Returns the same result as [`get_similarity_ratings`](crate::get_similarity_ratings),
+/// including `None` for an empty slice.
+/// # Example
+/// ```
+/// use similar_string::par_get_similarity_ratings;
+///
+/// let options = vec!["fill", "night", "ride"];
+///
+/// par_get_similarity_ratings("fight", &options); // Some([0.4, 0.8, 0.2])
+/// ```
+pub fn par_get_similarity_ratings(target: impl AsRef<str> + Sync, options: &[impl AsRef<str> + Sync]) -> Option<Vec<f64>> {
+    if options.is_empty() {
+        return None;
+    }
+    Some(options.par_iter()
+        .map(|option| compare_similarity(option.as_ref(), target.as_ref()))
+        .collect())
+}
+
+/// Get similarity ratings of all pairs of the items using all CPU cores
+///
+/// Returns the same matrix as [`similarity_matrix`](crate::similarity_matrix).
+/// # Example
+/// ```
+/// use similar_string::par_similarity_matrix;
+///
+/// let matrix = par_similarity_matrix(&["fight", "night", "ride"]);
+/// matrix[(0, 1)]; // 0.8
+/// ```
+pub fn par_similarity_matrix(items: &[impl AsRef<str> + Sync]) -> Matrix<f64> {
+    mirror((0..items.len()).into_par_iter().map(|row| upper_row(items, row)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let options: Vec<String> = (0..500).map(|index| format!("option {}", index * 7919 % 1000)).collect();
+        assert_eq!(par_get_similarity_ratings("option 42", &options), get_similarity_ratings("option 42", &options));
+        assert_eq!(par_find_best_similarity("option 42", &options), find_best_similarity("option 42", &options));
+        assert_eq!(par_find_best_similarity("bat", &["cat", "hat"]), Some(("cat".to_string(), 2.0 / 3.0)));
+        assert_eq!(par_similarity_matrix(&options[..50]), similarity_matrix(&options[..50]));
+    }
+
+    #[test]
+    fn parallel_empty_options() {
+        assert_eq!(par_find_best_similarity("fight", &[] as &[&str]), None);
+        assert_eq!(par_get_similarity_ratings("fight", &[] as &[&str]), None);
+    }
+}
@@ -0,0 +1,97 @@
+use std::io::{self, Read, Write};
+
+/// Version of the binary format, stored after the magic bytes of every index
+const FORMAT_VERSION: u8 = 1;
+
+/// Error of data that isn't a saved index of the expected kind
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Writes the values of an index in the little-endian binary format
+pub(crate) struct Writer<W: Write>(W);
+
+impl<W: Write> Writer<W> {
+    /// Start writing an index of the kind named by the magic bytes
+    pub(crate) fn new(mut writer: W, magic: &[u8; 4]) -> io::Result<Self> {
+        writer.write_all(magic)?;
+        writer.write_all(&[FORMAT_VERSION])?;
+        Ok(Writer(writer))
+    }
+
+    pub(crate) fn u8(&mut self, value: u8) -> io::Result<()> {
+        self.0.write_all(&[value])
+    }
+
+    pub(crate) fn u64(&mut self, value: u64) -> io::Result<()> {
+        self.0.write_all(&value.to_le_bytes())
+    }
+
+    pub(crate) fn usize(&mut self, value: usize) -> io::Result<()> {
+        self.u64(value as u64)
+    }
+
+    pub(crate) fn str(&mut self, value: &str) -> io::Result<()> {
+        self.usize(value.len())?;
+        self.0.write_all(value.as_bytes())
+    }
+
+    /// Finish writing and flush the writer
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Reads the values written by a [`Writer`]
+pub(crate) struct Reader<R: Read>(R);
+
+impl<R: Read> Reader<R> {
+    /// Start reading an index of the kind named by the magic bytes
+    pub(crate) fn new(mut reader: R, magic: &[u8; 4]) -> io::Result<Self> {
+        let mut header = [0; 5];
+        reader.read_exact(&mut header)?;
+        if header[..4] != magic[..] {
+            return Err(invalid_data("not a saved index of this kind"));
+        }
+        if header[4] != FORMAT_VERSION {
+            return Err(invalid_data("unsupported version of the index format"));
+        }
+        Ok(Reader(reader))
+    }
+
+    pub(crate) fn u8(&mut self) -> io::Result<u8> {
+        let mut bytes = [0; 1];
+        self.0.read_exact(&mut bytes)?;
+        Ok(bytes[0])
+    }
+
+    pub(crate) fn u64(&mut self) -> io::Result<u64> {
+        let mut bytes = [0; 8];
+        self.0.read_exact(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    pub(crate) fn usize(&mut self) -> io::Result<usize> {
+        usize::try_from(self.u64()?).map_err(|_| invalid_data("value doesn't fit in usize"))
+    }
+
+    /// Read an id that has to be below the given limit
+    pub(crate) fn id(&mut self, limit: usize) -> io::Result<usize> {
+        let id = self.usize()?;
+        if id >= limit {
+            return Err(invalid_data("id out of range"));
+        }
+        Ok(id)
+    }
+
+    pub(crate) fn string(&mut self) -> io::Result<String> {
+        let len = self.usize()?;
+        let mut bytes = vec![];
+        // The length is not trusted to preallocate the buffer
+        (&mut self.0).take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(bytes).map_err(|_| invalid_data("string is not valid UTF-8"))
+    }
+}
@@ -0,0 +1,896 @@
+use crate::compare_similarity;
+use crate::fold::fold_accents;
+use crate::search::{rated_options, Match};
+
+/// Uppercase ASCII letters of the text, with diacritics removed
+fn ascii_letters(text: &str) -> Vec<u8> {
+    fold_accents(text).bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|letter| letter.to_ascii_uppercase())
+        .collect()
+}
+
+/// Soundex digit of a letter, 0 for vowels and `None` for "H" and "W"
+fn soundex_digit(letter: u8) -> Option<u8> {
+    match letter {
+        b'B' | b'F' | b'P' | b'V' => Some(b'1'),
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => Some(b'2'),
+        b'D' | b'T' => Some(b'3'),
+        b'L' => Some(b'4'),
+        b'M' | b'N' => Some(b'5'),
+        b'R' => Some(b'6'),
+        b'H' | b'W' => None,
+        _ => Some(b'0')
+    }
+}
+
+/// Get the American Soundex code of a word
+///
+/// The code is the first letter followed by three digits that stand for the groups
+/// of similarly sounding consonants. Diacritics are removed and characters other than
+/// Latin letters are skipped, a word without any letters gets an empty code.
+/// # Example
+/// ```
+/// use similar_string::soundex;
+///
+/// soundex("Robert"); // "R163"
+/// soundex("Rupert"); // "R163"
+/// soundex("Tymczak"); // "T522"
+/// ```
+pub fn soundex(text: impl AsRef<str>) -> String {
+    let letters = ascii_letters(text.as_ref());
+    let Some(&first) = letters.first() else {
+        return String::new();
+    };
+    let mut code = vec![first];
+    let mut previous = soundex_digit(first).unwrap_or(b'0');
+    for letter in &letters[1..] {
+        // "H" and "W" don't separate consonants of the same group
+        let Some(digit) = soundex_digit(*letter) else {
+            continue;
+        };
+        if digit != b'0' && digit != previous {
+            code.push(digit);
+            if code.len() == 4 {
+                break;
+            }
+        }
+        previous = digit;
+    }
+    code.resize(4, b'0');
+    String::from_utf8(code).expect("Soundex codes are ASCII")
+}
+
+/// Get score of similarity of two words based on their Soundex codes
+///
+/// The rating is the number of positions at which the codes agree divided by four,
+/// much like the `DIFFERENCE` function of SQL databases. Words that sound alike get 1.0.
+/// # Example
+/// ```
+/// use similar_string::soundex_similarity;
+///
+/// soundex_similarity("Smith", "Smyth"); // 1.0
+/// soundex_similarity("Robert", "Rubin"); // 0.5
+/// ```
+pub fn soundex_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let (left, right) = (soundex(left), soundex(right));
+    if left.is_empty() || right.is_empty() {
+        return if left == right { 1.0 } else { 0.0 };
+    }
+    let same = left.bytes().zip(right.bytes()).filter(|(left, right)| left == right).count();
+    same as f64 / 4.0
+}
+
+/// Primary and alternate Double Metaphone codes of a word, see [`double_metaphone`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleMetaphone {
+    /// Code of the most common pronunciation
+    pub primary: String,
+    /// Code of an alternate pronunciation, the same as the primary one when there is none
+    pub alternate: String
+}
+
+/// Length of Double Metaphone codes
+const METAPHONE_LENGTH: usize = 4;
+
+/// State of the Double Metaphone encoding of a single word
+struct MetaphoneEncoder {
+    word: Vec<char>,
+    slavo_germanic: bool,
+    primary: String,
+    alternate: String
+}
+
+impl MetaphoneEncoder {
+    fn new(text: &str) -> Self {
+        let word: Vec<char> = text.trim().chars().flat_map(char::to_uppercase).collect();
+        let upper: String = word.iter().collect();
+        let slavo_germanic = upper.contains('W') || upper.contains('K') || upper.contains("CZ") || upper.contains("WITZ");
+        MetaphoneEncoder { word, slavo_germanic, primary: String::new(), alternate: String::new() }
+    }
+
+    fn len(&self) -> isize {
+        self.word.len() as isize
+    }
+
+    /// Character at the index, or a null character outside of the word
+    fn at(&self, index: isize) -> char {
+        if index < 0 {
+            return '\0';
+        }
+        self.word.get(index as usize).copied().unwrap_or('\0')
+    }
+
+    fn is_vowel(&self, index: isize) -> bool {
+        matches!(self.at(index), 'A' | 'E' | 'I' | 'O' | 'U' | 'Y')
+    }
+
+    /// Check whether the word has one of the options at the index, all of them have the same length
+    fn matches(&self, start: isize, options: &[&str]) -> bool {
+        let length = options[0].chars().count() as isize;
+        if start < 0 || start + length > self.len() {
+            return false;
+        }
+        let part = &self.word[start as usize..(start + length) as usize];
+        options.iter().any(|option| option.chars().eq(part.iter().copied()))
+    }
+
+    fn is_complete(&self) -> bool {
+        self.primary.len() >= METAPHONE_LENGTH && self.alternate.len() >= METAPHONE_LENGTH
+    }
+
+    fn add_primary(&mut self, code: &str) {
+        let room = METAPHONE_LENGTH.saturating_sub(self.primary.len());
+        self.primary.push_str(&code[..code.len().min(room)]);
+    }
+
+    fn add_alternate(&mut self, code: &str) {
+        let room = METAPHONE_LENGTH.saturating_sub(self.alternate.len());
+        self.alternate.push_str(&code[..code.len().min(room)]);
+    }
+
+    fn add(&mut self, primary: &str, alternate: &str) {
+        self.add_primary(primary);
+        self.add_alternate(alternate);
+    }
+
+    fn add_both(&mut self, code: &str) {
+        self.add(code, code);
+    }
+
+    /// Skip the next character when it is the same as the current one
+    fn skip_double(&self, index: isize, letter: char) -> isize {
+        if self.at(index + 1) == letter { index + 2 } else { index + 1 }
+    }
+
+    fn is_germanic(&self) -> bool {
+        self.matches(0, &["VAN ", "VON "]) || self.matches(0, &["SCH"])
+    }
+
+    fn encode(mut self) -> DoubleMetaphone {
+        // Initial letters that are not pronounced
+        let mut index = if self.matches(0, &["GN", "KN", "PN", "WR", "PS"]) { 1 } else { 0 };
+        while !self.is_complete() && index < self.len() {
+            index = match self.at(index) {
+                'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => {
+                    if index == 0 {
+                        self.add_both("A");
+                    }
+                    index + 1
+                }
+                'B' => {
+                    self.add_both("P");
+                    self.skip_double(index, 'B')
+                }
+                'Ç' => {
+                    self.add_both("S");
+                    index + 1
+                }
+                'C' => self.c(index),
+                'D' => self.d(index),
+                'F' => {
+                    self.add_both("F");
+                    self.skip_double(index, 'F')
+                }
+                'G' => self.g(index),
+                'H' => self.h(index),
+                'J' => self.j(index),
+                'K' => {
+                    self.add_both("K");
+                    self.skip_double(index, 'K')
+                }
+                'L' => self.l(index),
+                'M' => {
+                    self.add_both("M");
+                    let silent_b = self.matches(index - 1, &["UMB"]) && (index + 1 == self.len() - 1 || self.matches(index + 2, &["ER"]));
+                    if self.at(index + 1) == 'M' || silent_b { index + 2 } else { index + 1 }
+                }
+                'N' => {
+                    self.add_both("N");
+                    self.skip_double(index, 'N')
+                }
+                'Ñ' => {
+                    self.add_both("N");
+                    index + 1
+                }
+                'P' => self.p(index),
+                'Q' => {
+                    self.add_both("K");
+                    self.skip_double(index, 'Q')
+                }
+                'R' => self.r(index),
+                'S' => self.s(index),
+                'T' => self.t(index),
+                'V' => {
+                    self.add_both("F");
+                    self.skip_double(index, 'V')
+                }
+                'W' => self.w(index),
+                'X' => self.x(index),
+                'Z' => self.z(index),
+                _ => index + 1
+            };
+        }
+        DoubleMetaphone { primary: self.primary, alternate: self.alternate }
+    }
+
+    fn c(&mut self, index: isize) -> isize {
+        if self.c_is_k(index) {
+            self.add_both("K");
+            index + 2
+        } else if index == 0 && self.matches(index, &["CAESAR"]) {
+            self.add_both("S");
+            index + 2
+        } else if self.matches(index, &["CH"]) {
+            self.ch(index)
+        } else if self.matches(index, &["CZ"]) && !self.matches(index - 2, &["WICZ"]) {
+            self.add("S", "X");
+            index + 2
+        } else if self.matches(index + 1, &["CIA"]) {
+            self.add_both("X");
+            index + 3
+        } else if self.matches(index, &["CC"]) && !(index == 1 && self.at(0) == 'M') {
+            self.cc(index)
+        } else if self.matches(index, &["CK", "CG", "CQ"]) {
+            self.add_both("K");
+            index + 2
+        } else if self.matches(index, &["CI", "CE", "CY"]) {
+            if self.matches(index, &["CIO", "CIE", "CIA"]) {
+                self.add("S", "X");
+            } else {
+                self.add_both("S");
+            }
+            index + 2
+        } else {
+            self.add_both("K");
+            if self.matches(index + 1, &[" C", " Q", " G"]) {
+                index + 3
+            } else if self.matches(index + 1, &["C", "K", "Q"]) && !self.matches(index + 1, &["CE", "CI"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    /// Germanic "ACH" as in "Bacher" is pronounced as "K"
+    fn c_is_k(&self, index: isize) -> bool {
+        if self.matches(index, &["CHIA"]) {
+            return true;
+        }
+        if index <= 1 || self.is_vowel(index - 2) || !self.matches(index - 1, &["ACH"]) {
+            return false;
+        }
+        let next = self.at(index + 2);
+        (next != 'I' && next != 'E') || self.matches(index - 2, &["BACHER", "MACHER"])
+    }
+
+    fn cc(&mut self, index: isize) -> isize {
+        if self.matches(index + 2, &["I", "E", "H"]) && !self.matches(index + 2, &["HU"]) {
+            if (index == 1 && self.at(index - 1) == 'A') || self.matches(index - 1, &["UCCEE", "UCCES"]) {
+                self.add_both("KS");
+            } else {
+                self.add_both("X");
+            }
+            index + 3
+        } else {
+            self.add_both("K");
+            index + 2
+        }
+    }
+
+    fn ch(&mut self, index: isize) -> isize {
+        if index > 0 && self.matches(index, &["CHAE"]) {
+            self.add("K", "X");
+        } else if self.ch_is_greek(index) || self.ch_is_k(index) {
+            self.add_both("K");
+        } else if index > 0 {
+            if self.matches(0, &["MC"]) {
+                self.add_both("K");
+            } else {
+                self.add("X", "K");
+            }
+        } else {
+            self.add_both("X");
+        }
+        index + 2
+    }
+
+    /// Greek roots such as "chemistry" or "chorus"
+    fn ch_is_greek(&self, index: isize) -> bool {
+        index == 0
+            && (self.matches(index + 1, &["HARAC", "HARIS"]) || self.matches(index + 1, &["HOR", "HYM", "HIA", "HEM"]))
+            && !self.matches(0, &["CHORE"])
+    }
+
+    fn ch_is_k(&self, index: isize) -> bool {
+        self.is_germanic()
+            || self.matches(index - 2, &["ORCHES", "ARCHIT", "ORCHID"])
+            || self.matches(index + 2, &["T", "S"])
+            || ((self.matches(index - 1, &["A", "O", "U", "E"]) || index == 0)
+                && (self.matches(index + 2, &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "]) || index + 1 == self.len() - 1))
+    }
+
+    fn d(&mut self, index: isize) -> isize {
+        if self.matches(index, &["DG"]) {
+            if self.matches(index + 2, &["I", "E", "Y"]) {
+                self.add_both("J");
+                index + 3
+            } else {
+                self.add_both("TK");
+                index + 2
+            }
+        } else if self.matches(index, &["DT", "DD"]) {
+            self.add_both("T");
+            index + 2
+        } else {
+            self.add_both("T");
+            index + 1
+        }
+    }
+
+    fn g(&mut self, index: isize) -> isize {
+        if self.at(index + 1) == 'H' {
+            return self.gh(index);
+        }
+        if self.at(index + 1) == 'N' {
+            if index == 1 && self.is_vowel(0) && !self.slavo_germanic {
+                self.add("KN", "N");
+            } else if !self.matches(index + 2, &["EY"]) && self.at(index + 1) != 'Y' && !self.slavo_germanic {
+                self.add("N", "KN");
+            } else {
+                self.add_both("KN");
+            }
+            return index + 2;
+        }
+        if self.matches(index + 1, &["LI"]) && !self.slavo_germanic {
+            self.add("KL", "L");
+            return index + 2;
+        }
+        if index == 0 && (self.at(index + 1) == 'Y' || self.matches(index + 1, &["ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER"])) {
+            self.add("K", "J");
+            return index + 2;
+        }
+        if (self.matches(index + 1, &["ER"]) || self.at(index + 1) == 'Y')
+            && !self.matches(0, &["DANGER", "RANGER", "MANGER"])
+            && !self.matches(index - 1, &["E", "I"])
+            && !self.matches(index - 1, &["RGY", "OGY"])
+        {
+            self.add("K", "J");
+            return index + 2;
+        }
+        if self.matches(index + 1, &["E", "I", "Y"]) || self.matches(index - 1, &["AGGI", "OGGI"]) {
+            if self.is_germanic() || self.matches(index + 1, &["ET"]) {
+                self.add_both("K");
+            } else if self.matches(index + 1, &["IER"]) {
+                self.add_both("J");
+            } else {
+                self.add("J", "K");
+            }
+            return index + 2;
+        }
+        self.add_both("K");
+        self.skip_double(index, 'G')
+    }
+
+    fn gh(&mut self, index: isize) -> isize {
+        if index > 0 && !self.is_vowel(index - 1) {
+            self.add_both("K");
+        } else if index == 0 {
+            if self.at(index + 2) == 'I' {
+                self.add_both("J");
+            } else {
+                self.add_both("K");
+            }
+        } else if (index > 1 && self.matches(index - 2, &["B", "H", "D"]))
+            || (index > 2 && self.matches(index - 3, &["B", "H", "D"]))
+            || (index > 3 && self.matches(index - 4, &["B", "H"]))
+        {
+            // Silent as in "bough" or "broughton"
+        } else if index > 2 && self.at(index - 1) == 'U' && self.matches(index - 3, &["C", "G", "L", "R", "T"]) {
+            self.add_both("F");
+        } else if index > 0 && self.at(index - 1) != 'I' {
+            self.add_both("K");
+        }
+        index + 2
+    }
+
+    fn h(&mut self, index: isize) -> isize {
+        // Only pronounced between vowels or at the start before a vowel
+        if (index == 0 || self.is_vowel(index - 1)) && self.is_vowel(index + 1) {
+            self.add_both("H");
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn j(&mut self, index: isize) -> isize {
+        if self.matches(index, &["JOSE"]) || self.matches(0, &["SAN "]) {
+            if (index == 0 && self.at(index + 4) == ' ') || self.len() == 4 || self.matches(0, &["SAN "]) {
+                self.add_both("H");
+            } else {
+                self.add("J", "H");
+            }
+            return index + 1;
+        }
+        if index == 0 {
+            self.add("J", "A");
+        } else if self.is_vowel(index - 1) && !self.slavo_germanic && matches!(self.at(index + 1), 'A' | 'O') {
+            self.add("J", "H");
+        } else if index == self.len() - 1 {
+            self.add("J", "");
+        } else if !self.matches(index + 1, &["L", "T", "K", "S", "N", "M", "B", "Z"]) && !self.matches(index - 1, &["S", "K", "L"]) {
+            self.add_both("J");
+        }
+        self.skip_double(index, 'J')
+    }
+
+    fn l(&mut self, index: isize) -> isize {
+        if self.at(index + 1) != 'L' {
+            self.add_both("L");
+            return index + 1;
+        }
+        // Spanish "ll" as in "cabrillo" or "gallegos"
+        let length = self.len();
+        let spanish = (index == length - 3 && self.matches(index - 1, &["ILLO", "ILLA", "ALLE"]))
+            || ((self.matches(length - 2, &["AS", "OS"]) || self.matches(length - 1, &["A", "O"])) && self.matches(index - 1, &["ALLE"]));
+        if spanish {
+            self.add_primary("L");
+        } else {
+            self.add_both("L");
+        }
+        index + 2
+    }
+
+    fn p(&mut self, index: isize) -> isize {
+        if self.at(index + 1) == 'H' {
+            self.add_both("F");
+            return index + 2;
+        }
+        self.add_both("P");
+        if self.matches(index + 1, &["P", "B"]) { index + 2 } else { index + 1 }
+    }
+
+    fn r(&mut self, index: isize) -> isize {
+        // French final "r" as in "Rogier" is silent
+        if index == self.len() - 1 && !self.slavo_germanic && self.matches(index - 2, &["IE"]) && !self.matches(index - 4, &["ME", "MA"]) {
+            self.add_alternate("R");
+        } else {
+            self.add_both("R");
+        }
+        self.skip_double(index, 'R')
+    }
+
+    fn s(&mut self, index: isize) -> isize {
+        if self.matches(index - 1, &["ISL", "YSL"]) {
+            // Silent as in "island" or "carlisle"
+            return index + 1;
+        }
+        if index == 0 && self.matches(index, &["SUGAR"]) {
+            self.add("X", "S");
+            return index + 1;
+        }
+        if self.matches(index, &["SH"]) {
+            if self.matches(index + 1, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+                self.add_both("S");
+            } else {
+                self.add_both("X");
+            }
+            return index + 2;
+        }
+        if self.matches(index, &["SIO", "SIA"]) || self.matches(index, &["SIAN"]) {
+            if self.slavo_germanic {
+                self.add_both("S");
+            } else {
+                self.add("S", "X");
+            }
+            return index + 3;
+        }
+        if (index == 0 && self.matches(index + 1, &["M", "N", "L", "W"])) || self.matches(index + 1, &["Z"]) {
+            self.add("S", "X");
+            return if self.matches(index + 1, &["Z"]) { index + 2 } else { index + 1 };
+        }
+        if self.matches(index, &["SC"]) {
+            return self.sc(index);
+        }
+        if index == self.len() - 1 && self.matches(index - 2, &["AI", "OI"]) {
+            // French final "s" as in "Artois" is silent
+            self.add_alternate("S");
+        } else {
+            self.add_both("S");
+        }
+        if self.matches(index + 1, &["S", "Z"]) { index + 2 } else { index + 1 }
+    }
+
+    fn sc(&mut self, index: isize) -> isize {
+        if self.at(index + 2) == 'H' {
+            if self.matches(index + 3, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+                if self.matches(index + 3, &["ER", "EN"]) {
+                    self.add("X", "SK");
+                } else {
+                    self.add_both("SK");
+                }
+            } else if index == 0 && !self.is_vowel(3) && self.at(3) != 'W' {
+                self.add("X", "S");
+            } else {
+                self.add_both("X");
+            }
+        } else if self.matches(index + 2, &["I", "E", "Y"]) {
+            self.add_both("S");
+        } else {
+            self.add_both("SK");
+        }
+        index + 3
+    }
+
+    fn t(&mut self, index: isize) -> isize {
+        if self.matches(index, &["TION"]) || self.matches(index, &["TIA", "TCH"]) {
+            self.add_both("X");
+            return index + 3;
+        }
+        if self.matches(index, &["TH"]) || self.matches(index, &["TTH"]) {
+            if self.matches(index + 2, &["OM", "AM"]) || self.is_germanic() {
+                self.add_both("T");
+            } else {
+                self.add("0", "T");
+            }
+            return index + 2;
+        }
+        self.add_both("T");
+        if self.matches(index + 1, &["T", "D"]) { index + 2 } else { index + 1 }
+    }
+
+    fn w(&mut self, index: isize) -> isize {
+        if self.matches(index, &["WR"]) {
+            self.add_both("R");
+            return index + 2;
+        }
+        if index == 0 && (self.is_vowel(index + 1) || self.matches(index, &["WH"])) {
+            if self.is_vowel(index + 1) {
+                self.add("A", "F");
+            } else {
+                self.add_both("A");
+            }
+        } else if (index == self.len() - 1 && self.is_vowel(index - 1))
+            || self.matches(index - 1, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+            || self.matches(0, &["SCH"])
+        {
+            self.add_alternate("F");
+        } else if self.matches(index, &["WICZ", "WITZ"]) {
+            self.add("TS", "FX");
+            return index + 4;
+        }
+        index + 1
+    }
+
+    fn x(&mut self, index: isize) -> isize {
+        if index == 0 {
+            self.add_both("S");
+            return index + 1;
+        }
+        // French final "x" as in "breaux" is silent
+        let silent = index == self.len() - 1 && (self.matches(index - 3, &["IAU", "EAU"]) || self.matches(index - 2, &["AU", "OU"]));
+        if !silent {
+            self.add_both("KS");
+        }
+        if self.matches(index + 1, &["C", "X"]) { index + 2 } else { index + 1 }
+    }
+
+    fn z(&mut self, index: isize) -> isize {
+        if self.at(index + 1) == 'H' {
+            self.add_both("J");
+            return index + 2;
+        }
+        if self.matches(index + 1, &["ZO", "ZI", "ZA"]) || (self.slavo_germanic && index > 0 && self.at(index - 1) != 'T') {
+            self.add("S", "TS");
+        } else {
+            self.add_both("S");
+        }
+        self.skip_double(index, 'Z')
+    }
+}
+
+/// Get the primary and alternate Double Metaphone codes of a word
+///
+/// Double Metaphone encodes how an English word or name is pronounced in up to four
+/// characters, taking into account spellings borrowed from other languages. Words with
+/// an ambiguous pronunciation get an alternate code as well, "0" stands for "th" and
+/// "X" for "sh" or "ch".
+/// # Example
+/// ```
+/// use similar_string::double_metaphone;
+///
+/// double_metaphone("Smith").primary; // "SM0"
+/// double_metaphone("Smith").alternate; // "XMT"
+/// double_metaphone("Schmidt").primary; // "XMT"
+/// ```
+pub fn double_metaphone(text: impl AsRef<str>) -> DoubleMetaphone {
+    MetaphoneEncoder::new(text.as_ref()).encode()
+}
+
+/// Get score of similarity of two words based on their Double Metaphone codes
+///
+/// Equal primary codes rate 1.0, a primary code equal to the alternate one of the other word
+/// rates 0.75 and equal alternate codes rate 0.5. Words that don't share any code rate 0.0.
+/// # Example
+/// ```
+/// use similar_string::double_metaphone_similarity;
+///
+/// double_metaphone_similarity("Catherine", "Kathryn"); // 1.0
+/// double_metaphone_similarity("Smith", "Schmidt"); // 0.75
+/// double_metaphone_similarity("Smith", "Jones"); // 0.0
+/// ```
+pub fn double_metaphone_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    metaphone_rating(&double_metaphone(left), &double_metaphone(right))
+}
+
+/// Rating of two Double Metaphone codes, see [`double_metaphone_similarity`]
+fn metaphone_rating(left: &DoubleMetaphone, right: &DoubleMetaphone) -> f64 {
+    if left.primary == right.primary {
+        1.0
+    } else if left.primary == right.alternate || left.alternate == right.primary {
+        0.75
+    } else if left.alternate == right.alternate {
+        0.5
+    } else {
+        0.0
+    }
+}
+
+/// Length of NYSIIS codes in the original specification
+const NYSIIS_LENGTH: usize = 6;
+
+fn is_nysiis_vowel(letter: u8) -> bool {
+    matches!(letter, b'A' | b'E' | b'I' | b'O' | b'U')
+}
+
+/// Replace the prefix of the word if it starts with it
+fn replace_prefix(word: &mut Vec<u8>, prefix: &[u8], replacement: &[u8]) {
+    if word.starts_with(prefix) {
+        word.splice(..prefix.len(), replacement.iter().copied());
+    }
+}
+
+/// Replace the last two letters of the word if they are one of the suffixes
+fn replace_suffix(word: &mut Vec<u8>, suffixes: &[&[u8]], replacement: u8) {
+    if suffixes.iter().any(|suffix| word.ends_with(suffix)) {
+        word.truncate(word.len() - 2);
+        word.push(replacement);
+    }
+}
+
+/// Transcode the letter at the index, which can also replace the letters that follow it
+fn nysiis_transcode(word: &mut [u8], index: usize) {
+    let previous = word[index - 1];
+    let next = word.get(index + 1).copied();
+    let replacement: &[u8] = match (word[index], next, word.get(index + 2).copied()) {
+        (b'E', Some(b'V'), _) => b"AF",
+        (letter, _, _) if is_nysiis_vowel(letter) => b"A",
+        (b'Q', _, _) => b"G",
+        (b'Z', _, _) => b"S",
+        (b'M', _, _) => b"N",
+        (b'K', Some(b'N'), _) => b"NN",
+        (b'K', _, _) => b"C",
+        (b'S', Some(b'C'), Some(b'H')) => b"SSS",
+        (b'P', Some(b'H'), _) => b"FF",
+        (b'H', _, _) if !is_nysiis_vowel(previous) || !next.is_some_and(is_nysiis_vowel) => std::slice::from_ref(&previous),
+        (b'W', _, _) if is_nysiis_vowel(previous) => std::slice::from_ref(&previous),
+        _ => return
+    };
+    word[index..index + replacement.len()].copy_from_slice(replacement);
+}
+
+/// Get the full NYSIIS code of a word without truncating it
+///
+/// Use [`nysiis`] for the codes of the original specification.
+/// This follows the `Nysiis` encoder of Apache Commons Codec with the strict mode turned off.
+/// # Example
+/// ```
+/// use similar_string::nysiis_untruncated;
+///
+/// nysiis_untruncated("Westerlund"); // "WASTARLAD"
+/// ```
+pub fn nysiis_untruncated(text: impl AsRef<str>) -> String {
+    let mut word = ascii_letters(text.as_ref());
+    if word.is_empty() {
+        return String::new();
+    }
+    replace_prefix(&mut word, b"MAC", b"MCC");
+    replace_prefix(&mut word, b"KN", b"NN");
+    replace_prefix(&mut word, b"K", b"C");
+    replace_prefix(&mut word, b"PH", b"FF");
+    replace_prefix(&mut word, b"PF", b"FF");
+    replace_prefix(&mut word, b"SCH", b"SSS");
+    replace_suffix(&mut word, &[b"EE", b"IE"], b'Y');
+    replace_suffix(&mut word, &[b"DT", b"RT", b"RD", b"NT", b"ND"], b'D');
+
+    let mut code = vec![word[0]];
+    for index in 1..word.len() {
+        // The previous letter is read already transcoded
+        nysiis_transcode(&mut word, index);
+        if word[index - 1] != word[index] {
+            code.push(word[index]);
+        }
+    }
+
+    if code.len() > 1 {
+        if code.ends_with(b"S") {
+            code.pop();
+        }
+        if code.len() > 2 && code.ends_with(b"AY") {
+            code.remove(code.len() - 2);
+        } else if code.ends_with(b"A") {
+            code.pop();
+        }
+    }
+    String::from_utf8(code).expect("NYSIIS codes are ASCII")
+}
+
+/// Get the NYSIIS code of a word
+///
+/// The New York State Identification and Intelligence System code maps similarly sounding
+/// names to the same key of up to six letters. Diacritics are removed and characters other
+/// than Latin letters are skipped, a word without any letters gets an empty code.
+/// This follows the `Nysiis` encoder of Apache Commons Codec in its default strict mode.
+/// # Example
+/// ```
+/// use similar_string::nysiis;
+///
+/// nysiis("Brian"); // "BRAN"
+/// nysiis("Brown"); // "BRAN"
+/// nysiis("Macintosh"); // "MCANT"
+/// nysiis("Westerlund"); // "WASTAR"
+/// ```
+pub fn nysiis(text: impl AsRef<str>) -> String {
+    let mut code = nysiis_untruncated(text);
+    code.truncate(NYSIIS_LENGTH);
+    code
+}
+
+/// Get score of similarity of two words based on their NYSIIS codes
+///
+/// Words with the same code rate 1.0, other codes are compared with
+/// [`compare_similarity`](crate::compare_similarity).
+/// # Example
+/// ```
+/// use similar_string::nysiis_similarity;
+///
+/// nysiis_similarity("Dane", "Dionne"); // 1.0
+/// nysiis_similarity("Capp", "Cabot"); // 0.4
+/// ```
+pub fn nysiis_similarity(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    compare_similarity(nysiis(left), nysiis(right))
+}
+
+/// Find the option that sounds and is spelled the most like the target string
+///
+/// Options are first grouped by how well their Double Metaphone codes agree with the code
+/// of the target, as rated by [`double_metaphone_similarity`]. The best group wins and its options
+/// are ranked by [`compare_similarity`](crate::compare_similarity), so a name that is spelled
+/// differently but sounds the same beats one that only shares more letters.
+/// The score of the match is the mean of both ratings. The first option wins a tie
+/// and `None` is returned if there are no options.
+/// # Example
+/// ```
+/// use similar_string::{find_best_match, find_best_phonetic};
+///
+/// let options = vec!["Caroline", "Kathryn"];
+///
+/// find_best_match("Catherine", &options).unwrap().value; // "Caroline"
+/// find_best_phonetic("Catherine", &options).unwrap().value; // "Kathryn"
+/// ```
+pub fn find_best_phonetic<'a, S: AsRef<str> + ?Sized + 'a>(target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>) -> Option<Match<'a>> {
+    let target = target.as_ref();
+    let code = double_metaphone(target);
+    let mut best: Option<(f64, f64, Match<'a>)> = None;
+    for (index, value, score) in rated_options(target, options) {
+        let value = value.as_ref();
+        let phonetic = metaphone_rating(&code, &double_metaphone(value));
+        if best.is_none_or(|(high_phonetic, high_score, _)| (phonetic, score) > (high_phonetic, high_score)) {
+            best = Some((phonetic, score, Match { index, value, score: (phonetic + score) / 2.0 }));
+        }
+    }
+    best.map(|(_, _, best)| best)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn soundex_works() {
+        for (word, code) in [
+            ("Robert", "R163"), ("Rupert", "R163"), ("Rubin", "R150"), ("Ashcraft", "A261"),
+            ("Ashcroft", "A261"), ("Tymczak", "T522"), ("Pfister", "P236"), ("Honeyman", "H555"),
+            ("Lee", "L000"), ("Müller", "M460"), ("o'hara", "O600")
+        ] {
+            assert_eq!(soundex(word), code, "{}", word);
+        }
+        assert_eq!(soundex("123"), "");
+    }
+
+    #[test]
+    fn soundex_similarity_works() {
+        assert_eq!(soundex_similarity("Smith", "Smyth"), 1.0);
+        assert_eq!(soundex_similarity("Smith", "Schmidt"), 1.0);
+        assert_eq!(soundex_similarity("Robert", "Rubin"), 0.5);
+        assert_eq!(soundex_similarity("", ""), 1.0);
+        assert_eq!(soundex_similarity("Smith", ""), 0.0);
+    }
+
+    #[test]
+    fn double_metaphone_works() {
+        for (word, primary, alternate) in [
+            ("Smith", "SM0", "XMT"), ("Schmidt", "XMT", "SMT"), ("Thompson", "TMPS", "TMPS"),
+            ("Jose", "HS", "HS"), ("Xavier", "SF", "SFR"), ("Caesar", "SSR", "SSR"),
+            ("Gnome", "NM", "NM"), ("Catherine", "K0RN", "KTRN"), ("Knight", "NT", "NT"),
+            ("Dumb", "TM", "TM"), ("Muñoz", "MNS", "MNS")
+        ] {
+            let code = double_metaphone(word);
+            assert_eq!((code.primary.as_str(), code.alternate.as_str()), (primary, alternate), "{}", word);
+        }
+        assert_eq!(double_metaphone("").primary, "");
+    }
+
+    #[test]
+    fn double_metaphone_similarity_works() {
+        assert_eq!(double_metaphone_similarity("Catherine", "Kathryn"), 1.0);
+        assert_eq!(double_metaphone_similarity("Smith", "Schmidt"), 0.75);
+        assert_eq!(double_metaphone_similarity("Smith", "Jones"), 0.0);
+        assert_eq!(double_metaphone_similarity("", ""), 1.0);
+    }
+
+    #[test]
+    fn nysiis_works() {
+        for (word, code) in [
+            ("Brian", "BRAN"), ("Brown", "BRAN"), ("Capp", "CAP"), ("Kipp", "CAP"), ("Dent", "DAD"),
+            ("Dionne", "DAN"), ("Phil", "FAL"), ("Knuth", "NAT"), ("Mackie", "MCY"), ("Schoenhoeft", "SANAFT"),
+            ("Casstevens", "CASTAF"), ("Carraway", "CARY"), ("O'Daniel", "ODANAL"), ("Müller", "MALAR")
+        ] {
+            assert_eq!(nysiis(word), code, "{}", word);
+        }
+        assert_eq!(nysiis_untruncated("Casstevens"), "CASTAFAN");
+        assert_eq!(nysiis_untruncated("Heitschmidt"), "HATSNAD");
+        assert_eq!(nysiis(""), "");
+    }
+
+    #[test]
+    fn nysiis_similarity_works() {
+        assert_eq!(nysiis_similarity("Dane", "Dean"), 1.0);
+        assert_eq!(nysiis_similarity("", ""), 1.0);
+        assert!(nysiis_similarity("Capp", "Cabot") < 1.0);
+    }
+    #[test]
+    fn phonetic_search_works() {
+        let options = vec!["Caroline", "Kathryn"];
+        assert_eq!(find_best_match("Catherine", &options).unwrap().value, "Caroline");
+        let best = find_best_phonetic("Catherine", &options).unwrap();
+        assert_eq!((best.index, best.value), (1, "Kathryn"));
+        assert_eq!(best.score, (1.0 + 5.0 / 9.0) / 2.0);
+        // Options of the same group are ranked by their spelling
+        assert_eq!(find_best_phonetic("Jon", &["Jonas", "Jan", "Joan"]).unwrap().value, "Joan");
+        assert_eq!(find_best_phonetic("Jon", &[] as &[&str]), None);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::lcs::PatternMasks;
+use crate::lcs_rating;
+use crate::matrix::{Matrix, mirror};
+
+/// Counter of comparisons that reports to the callback every `every` of them and after the last one
+pub(crate) struct Progress<F> {
+    done: usize,
+    total: usize,
+    every: usize,
+    callback: F
+}
+
+impl<F: FnMut(usize, usize)> Progress<F> {
+    /// Create a counter of `total` comparisons
+    /// # Panics
+    /// This function panics if `every` is zero
+    pub(crate) fn new(total: usize, every: usize, callback: F) -> Self {
+        assert!(every > 0, "progress interval must be greater than zero");
+        Progress { done: 0, total, every, callback }
+    }
+
+    /// Count one finished comparison
+    pub(crate) fn step(&mut self) {
+        self.done += 1;
+        if self.done.is_multiple_of(self.every) || self.done == self.total {
+            (self.callback)(self.done, self.total);
+        }
+    }
+}
+
+/// Get similarity ratings of all pairs of the items and report the progress
+///
+/// Returns the same matrix as [`similarity_matrix`](crate::similarity_matrix). The callback gets
+/// the number of finished comparisons and the number of all of them after every `every` comparisons
+/// and after the last one, so that a progress bar can be drawn.
+/// # Panics
+/// This function panics if `every` is zero
+/// # Example
+/// ```
+/// use similar_string::similarity_matrix_with_progress;
+///
+/// let matrix = similarity_matrix_with_progress(&["fight", "night", "ride"], 2, |done, total| {
+///     println!("{}/{}", done, total); // 2/3, 3/3
+/// });
+/// matrix[(0, 1)]; // 0.8
+/// ```
+pub fn similarity_matrix_with_progress(items: &[impl AsRef<str>], every: usize, callback: impl FnMut(usize, usize)) -> Matrix<f64> {
+    let size = items.len();
+    let mut progress = Progress::new(size * size.saturating_sub(1) / 2, every, callback);
+    let upper = (0..size).map(|row| {
+        let item = items[row].as_ref();
+        let chars: Vec<char> = item.chars().collect();
+        let masks = PatternMasks::new(&chars);
+        items[row + 1..].iter()
+            .map(|other| {
+                let rating = lcs_rating(masks.lcs_length(other.as_ref().chars()), item, other.as_ref());
+                progress.step();
+                rating
+            })
+            .collect()
+    }).collect();
+    mirror(upper)
+}
+
+/// Get all similarity scores against the target string and report the progress
+///
+/// Returns the same ratings as [`get_similarity_ratings`](crate::get_similarity_ratings),
+/// including `None` for an empty slice. The callback gets the number of rated options and
+/// the number of all of them after every `every` options and after the last one.
+/// # Panics
+/// This function panics if `every` is zero
+/// # Example
+/// ```
+/// use similar_string::get_similarity_ratings_with_progress;
+///
+/// let options = vec!["fill", "night", "ride"];
+///
+/// get_similarity_ratings_with_progress("fight", &options, 10, |done, total| {
+///     println!("{}/{}", done, total); // 3/3
+/// }); // Some([0.4, 0.8, 0.2])
+/// ```
+pub fn get_similarity_ratings_with_progress(target: impl AsRef<str>, options: &[impl AsRef<str>], every: usize, callback: impl FnMut(usize, usize)) -> Option<Vec<f64>> {
+    let mut progress = Progress::new(options.len(), every, callback);
+    let result: Vec<f64> = crate::search::rated_options(target.as_ref(), options)
+        .map(|(_, _, score)| {
+            progress.step();
+            score
+        })
+        .collect();
+    if result.is_empty() { None } else { Some(result) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn matrix_progress() {
+        let items = ["fight", "night", "ride", "", "light"];
+        let mut reports = vec![];
+        let matrix = similarity_matrix_with_progress(&items, 4, |done, total| reports.push((done, total)));
+        assert_eq!(matrix, similarity_matrix(&items));
+        assert_eq!(reports, vec![(4, 10), (8, 10), (10, 10)]);
+        let mut reports = vec![];
+        similarity_matrix_with_progress(&["fight"], 1, |done, total| reports.push((done, total)));
+        assert!(reports.is_empty());
+    }
+
+    #[test]
+    fn ratings_progress() {
+        let options = vec!["fill", "night", "ride"];
+        let mut reports = vec![];
+        let ratings = get_similarity_ratings_with_progress("fight", &options, 1, |done, total| reports.push((done, total)));
+        assert_eq!(ratings, get_similarity_ratings("fight", &options));
+        assert_eq!(reports, vec![(1, 3), (2, 3), (3, 3)]);
+        assert_eq!(get_similarity_ratings_with_progress("fight", &[] as &[&str], 1, |_, _| {}), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "progress interval must be greater than zero")]
+    fn zero_progress_interval() {
+        similarity_matrix_with_progress(&["fight"], 0, |_, _| {});
+    }
+}
@@ -0,0 +1,161 @@
+//! Python bindings generated with PyO3
+//!
+//! The functions and the `Corpus` class keep the ratings of the Rust API, so notebooks score
+//! strings exactly like the Rust code in production. Matches are `(index, value, score)` tuples
+//! and errors are raised as `ValueError`.
+//!
+//! This module is available with the `python` feature. The extension module is the crate built
+//! as a dynamic library, renamed to `similar_string.so` (`similar_string.pyd` on Windows) and put
+//! where Python looks for modules:
+//! ```sh
+//! cargo rustc --lib --release --features python --crate-type cdylib
+//! cp target/release/libsimilar_string.so similar_string.so
+//! ```
+//! # Example
+//! ```python
+//! import similar_string
+//!
+//! similar_string.compare_similarity("age", "page")  # 0.75
+//! similar_string.similarity("martha", "marhta", "jaro-winkler")  # 0.9611111111111111
+//! similar_string.find_best_match("fight", ["fill", "night", "ride"])  # (1, "night", 0.8)
+//!
+//! corpus = similar_string.Corpus(["fill", "night", "ride"])
+//! corpus.best_match("fight")  # (1, "night", 0.8)
+//! corpus.top_k("fight", 2)  # [(1, "night", 0.8), (0, "fill", 0.4)]
+//! ```
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::search::Match;
+use crate::{metric_from_name, METRIC_NAMES};
+
+/// Match as a `(index, value, score)` tuple
+type PyMatch = (usize, String, f64);
+
+fn to_tuple(found: Match<'_>) -> PyMatch {
+    (found.index, found.value.to_string(), found.score)
+}
+
+/// Get score of similarity of two strings, see [`compare_similarity`](crate::compare_similarity)
+#[pyfunction]
+pub fn compare_similarity(left: &str, right: &str) -> f64 {
+    crate::compare_similarity(left, right)
+}
+
+/// Get the rating of two strings with the metric of the given name, see [`metric_from_name`]
+///
+/// Raises `ValueError` if there is no metric with the name.
+#[pyfunction]
+#[pyo3(signature = (left, right, metric = "lcs"))]
+pub fn similarity(left: &str, right: &str, metric: &str) -> PyResult<f64> {
+    let metric = metric_from_name(metric).map_err(|error| PyValueError::new_err(error.to_string()))?;
+    Ok(metric.similarity(left, right))
+}
+
+/// Get the Levenshtein edit distance of two strings, see [`levenshtein_distance`](crate::levenshtein_distance)
+#[pyfunction]
+pub fn levenshtein_distance(left: &str, right: &str) -> usize {
+    crate::levenshtein_distance(left, right)
+}
+
+/// Find the option that is the most similar to the target string, see [`find_best_match`](crate::find_best_match)
+///
+/// Returns `None` if there are no options.
+#[pyfunction]
+pub fn find_best_match(target: &str, options: Vec<String>) -> Option<PyMatch> {
+    crate::find_best_match(target, &options).map(to_tuple)
+}
+
+/// Get all similarity scores against the target string, an empty list if there are no options
+#[pyfunction]
+pub fn get_similarity_ratings(target: &str, options: Vec<String>) -> Vec<f64> {
+    crate::get_similarity_ratings(target, &options).unwrap_or_default()
+}
+
+/// Find the `k` best rated options as `(index, score)` tuples, see [`find_top_k`](crate::find_top_k)
+#[pyfunction]
+pub fn find_top_k(target: &str, options: Vec<String>, k: usize) -> Vec<(usize, f64)> {
+    crate::find_top_k(target, &options, k)
+}
+
+/// Options prepared to be compared against many queries, see [`Corpus`](crate::Corpus)
+#[pyclass(name = "Corpus")]
+pub struct PyCorpus(crate::Corpus);
+
+#[pymethods]
+impl PyCorpus {
+    /// Prepare the options
+    #[new]
+    pub fn new(options: Vec<String>) -> Self {
+        PyCorpus(crate::Corpus::new(options))
+    }
+
+    /// Number of options
+    pub fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Add an option and get its id
+    pub fn insert(&mut self, option: &str) -> usize {
+        self.0.insert(option)
+    }
+
+    /// Remove the option with the id and get it back, `None` if there is no such option
+    pub fn remove(&mut self, id: usize) -> Option<String> {
+        self.0.remove(id)
+    }
+
+    /// Find the option that is the most similar to the query
+    pub fn best_match(&self, query: &str) -> Option<PyMatch> {
+        self.0.best_match(query).map(to_tuple)
+    }
+
+    /// Find all the options rated at least `min_score`, in the order of the options
+    pub fn matches_above(&self, query: &str, min_score: f64) -> Vec<PyMatch> {
+        self.0.matches_above(query, min_score).into_iter().map(to_tuple).collect()
+    }
+
+    /// Find the `k` best rated options, the best ones first
+    pub fn top_k(&self, query: &str, k: usize) -> Vec<PyMatch> {
+        let mut matches = self.0.matches_above(query, 0.0);
+        matches.sort_by(|left, right| right.score.total_cmp(&left.score));
+        matches.into_iter().take(k).map(to_tuple).collect()
+    }
+}
+
+/// The `similar_string` Python module
+#[pymodule]
+#[pyo3(name = "similar_string")]
+pub fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add("METRIC_NAMES", METRIC_NAMES.to_vec())?;
+    module.add_function(wrap_pyfunction!(compare_similarity, module)?)?;
+    module.add_function(wrap_pyfunction!(similarity, module)?)?;
+    module.add_function(wrap_pyfunction!(levenshtein_distance, module)?)?;
+    module.add_function(wrap_pyfunction!(find_best_match, module)?)?;
+    module.add_function(wrap_pyfunction!(get_similarity_ratings, module)?)?;
+    module.add_function(wrap_pyfunction!(find_top_k, module)?)?;
+    module.add_class::<PyCorpus>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::python::*;
+
+    #[test]
+    fn python_bindings() {
+        assert_eq!(compare_similarity("age", "page"), 0.75);
+        assert_eq!(similarity("martha", "marhta", "jaro-winkler").unwrap(), crate::jaro_winkler_similarity("martha", "marhta"));
+        let options = vec!["fill".to_string(), "night".to_string(), "ride".to_string()];
+        assert_eq!(find_best_match("fight", options.clone()), Some((1, "night".to_string(), 0.8)));
+        assert_eq!(get_similarity_ratings("fight", options.clone()), vec![0.4, 0.8, 0.2]);
+        assert_eq!(find_top_k("fight", options.clone(), 1), vec![(1, 0.8)]);
+        let mut corpus = PyCorpus::new(options);
+        assert_eq!(corpus.insert("light"), 3);
+        assert_eq!(corpus.matches_above("fight", 0.5).len(), 2);
+        assert_eq!(corpus.top_k("fight", 2), vec![(1, "night".to_string(), 0.8), (3, "light".to_string(), 0.8)]);
+        assert_eq!((corpus.remove(1), corpus.__len__()), (Some("night".to_string()), 3));
+        assert_eq!(corpus.best_match("fight"), Some((3, "light".to_string(), 0.8)));
+    }
+}
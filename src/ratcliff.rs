@@ -0,0 +1,202 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Sequences at least this long have their most popular elements
+/// ignored when looking for matches, just like with Python's `autojunk`
+const AUTOJUNK_MIN_SIZE: usize = 200;
+
+/// Precomputed index of the right sequence used by the Ratcliff-Obershelp algorithm
+///
+/// This mirrors the state that Python's `difflib.SequenceMatcher` keeps about its second sequence.
+pub(crate) struct SequenceIndex<T> {
+    right: Vec<T>,
+    positions: HashMap<T, Vec<usize>>
+}
+
+impl<T: Eq + Hash + Clone> SequenceIndex<T> {
+    pub(crate) fn new(right: Vec<T>) -> Self {
+        let mut positions: HashMap<T, Vec<usize>> = HashMap::new();
+        for (index, item) in right.iter().enumerate() {
+            positions.entry(item.clone()).or_default().push(index);
+        }
+        if right.len() >= AUTOJUNK_MIN_SIZE {
+            let limit = right.len() / 100 + 1;
+            let popular: HashSet<T> = positions.iter()
+                .filter(|(_, indices)| indices.len() > limit)
+                .map(|(item, _)| item.clone())
+                .collect();
+            positions.retain(|item, _| !popular.contains(item));
+        }
+        SequenceIndex { right, positions }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.right.len()
+    }
+
+    /// Find the longest matching block in `left[alo..ahi]` and `right[blo..bhi]`
+    ///
+    /// Ties are resolved in favour of the block that starts earliest in `left`
+    /// and then the one that starts earliest in `right`.
+    fn find_longest_match(&self, left: &[T], alo: usize, ahi: usize, blo: usize, bhi: usize) -> (usize, usize, usize) {
+        let right = &self.right;
+        let (mut besti, mut bestj, mut bestsize) = (alo, blo, 0);
+        let mut lengths: HashMap<usize, usize> = HashMap::new();
+        for (i, item) in left.iter().enumerate().take(ahi).skip(alo) {
+            let mut new_lengths = HashMap::new();
+            if let Some(indices) = self.positions.get(item) {
+                for &j in indices {
+                    if j < blo {
+                        continue;
+                    }
+                    if j >= bhi {
+                        break;
+                    }
+                    let size = j.checked_sub(1)
+                        .and_then(|prev| lengths.get(&prev))
+                        .unwrap_or(&0) + 1;
+                    new_lengths.insert(j, size);
+                    if size > bestsize {
+                        besti = i + 1 - size;
+                        bestj = j + 1 - size;
+                        bestsize = size;
+                    }
+                }
+            }
+            lengths = new_lengths;
+        }
+        // Popular elements are not indexed, but can still extend a match
+        while besti > alo && bestj > blo && left[besti - 1] == right[bestj - 1] {
+            besti -= 1;
+            bestj -= 1;
+            bestsize += 1;
+        }
+        while besti + bestsize < ahi && bestj + bestsize < bhi && left[besti + bestsize] == right[bestj + bestsize] {
+            bestsize += 1;
+        }
+        (besti, bestj, bestsize)
+    }
+
+    /// Get matching blocks sorted by position and terminated with `(left.len(), right.len(), 0)`
+    pub(crate) fn matching_blocks(&self, left: &[T]) -> Vec<(usize, usize, usize)> {
+        let mut queue = vec![(0, left.len(), 0, self.right.len())];
+        let mut blocks = vec![];
+        while let Some((alo, ahi, blo, bhi)) = queue.pop() {
+            let (i, j, size) = self.find_longest_match(left, alo, ahi, blo, bhi);
+            if size > 0 {
+                blocks.push((i, j, size));
+                if alo < i && blo < j {
+                    queue.push((alo, i, blo, j));
+                }
+                if i + size < ahi && j + size < bhi {
+                    queue.push((i + size, ahi, j + size, bhi));
+                }
+            }
+        }
+        blocks.sort_unstable();
+
+        // Collapse blocks that are adjacent to each other
+        let mut result = vec![];
+        let (mut i1, mut j1, mut size1) = (0, 0, 0);
+        for (i2, j2, size2) in blocks {
+            if i1 + size1 == i2 && j1 + size1 == j2 {
+                size1 += size2;
+            } else {
+                if size1 > 0 {
+                    result.push((i1, j1, size1));
+                }
+                (i1, j1, size1) = (i2, j2, size2);
+            }
+        }
+        if size1 > 0 {
+            result.push((i1, j1, size1));
+        }
+        result.push((left.len(), self.right.len(), 0));
+        result
+    }
+
+    pub(crate) fn ratio(&self, left: &[T]) -> f64 {
+        let matches: usize = self.matching_blocks(left).iter().map(|(_, _, size)| size).sum();
+        let length = left.len() + self.len();
+        // Empty strings should match
+        if length == 0 { 1.0 } else { 2.0 * matches as f64 / length as f64 }
+    }
+}
+
+/// Get the Ratcliff-Obershelp similarity of two strings
+///
+/// The score matches `difflib.SequenceMatcher(None, left, right).ratio()` from Python,
+/// including its automatic junk heuristic for strings of 200 or more characters.
+/// It is twice the number of matching characters divided by the total number of characters.
+/// The rating is returned as a f64 value in range from 0.0 to 1.0.
+/// # Example
+/// ```
+/// use similar_string::ratcliff_obershelp_ratio;
+///
+/// ratcliff_obershelp_ratio("abcd", "bcde"); // 0.75
+/// ```
+pub fn ratcliff_obershelp_ratio(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let index = SequenceIndex::new(right.as_ref().chars().collect());
+    index.ratio(&left)
+}
+
+/// Get the blocks of characters that match in both strings
+///
+/// Every block is a `(left_start, right_start, len)` triple of character offsets, meaning that
+/// `len` characters starting at `left_start` in the left string match those at `right_start`
+/// in the right one. The blocks are found with the Ratcliff-Obershelp algorithm and equal
+/// the result of `difflib.SequenceMatcher(None, left, right).get_matching_blocks()` from Python.
+/// Just like there, the list is sorted and ends with a `(left_len, right_len, 0)` sentinel.
+/// # Example
+/// ```
+/// use similar_string::get_matching_blocks;
+///
+/// get_matching_blocks("abxcd", "abcd"); // [(0, 0, 2), (3, 2, 2), (5, 4, 0)]
+/// ```
+pub fn get_matching_blocks(left: impl AsRef<str>, right: impl AsRef<str>) -> Vec<(usize, usize, usize)> {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let index = SequenceIndex::new(right.as_ref().chars().collect());
+    index.matching_blocks(&left)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn ratcliff_works() {
+        assert_eq!(ratcliff_obershelp_ratio("abcd", "bcde"), 0.75);
+        assert_eq!(ratcliff_obershelp_ratio("", ""), 1.0);
+        assert_eq!(ratcliff_obershelp_ratio("abc", ""), 0.0);
+        assert_eq!(ratcliff_obershelp_ratio("hello", "hello"), 1.0);
+    }
+
+    #[test]
+    fn ratcliff_matches_python() {
+        // Values computed with Python's difflib.SequenceMatcher
+        assert_eq!(ratcliff_obershelp_ratio("GESTALT PATTERN MATCHING", "GESTALT PRACTICE"), 0.6);
+        assert_eq!(ratcliff_obershelp_ratio("tide", "diet"), 0.25);
+        assert_eq!(ratcliff_obershelp_ratio("diet", "tide"), 0.5);
+        assert_eq!(ratcliff_obershelp_ratio("private Thread currentThread;", "private volatile Thread currentThread;"), 0.8656716417910447);
+    }
+
+    #[test]
+    fn matching_blocks_work() {
+        assert_eq!(get_matching_blocks("abxcd", "abcd"), vec![(0, 0, 2), (3, 2, 2), (5, 4, 0)]);
+        assert_eq!(get_matching_blocks("", ""), vec![(0, 0, 0)]);
+        assert_eq!(get_matching_blocks("abc", "xyz"), vec![(3, 3, 0)]);
+        // Values computed with Python's difflib.SequenceMatcher
+        assert_eq!(get_matching_blocks("qabxcd", "abycdf"), vec![(1, 0, 2), (4, 3, 2), (6, 6, 0)]);
+        assert_eq!(get_matching_blocks("żółw", "żuław"), vec![(0, 0, 1), (2, 2, 1), (3, 4, 1), (4, 5, 0)]);
+    }
+
+    #[test]
+    fn ratcliff_autojunk() {
+        // The popular "a" is ignored by Python, so only the "b" gets matched
+        let left = "b".to_string() + &"a".repeat(200);
+        let right = "a".repeat(200) + "b";
+        let ratio = ratcliff_obershelp_ratio(&left, &right);
+        assert_eq!(ratio, 2.0 / 402.0);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::diff::{EditOp, edit_ops_slices};
+
+/// Colors of the differences rendered by [`ansi_diff`] and [`ansi_inline_diff`]
+///
+/// Every style is a list of SGR parameters such as `"1;31"` for bold red, an empty style
+/// leaves the text as it is. By default deletions are red, insertions are green and equal
+/// characters keep the color of the terminal.
+/// # Example
+/// ```
+/// use similar_string::AnsiStyle;
+///
+/// // Bold red deletions and underlined green insertions
+/// let style = AnsiStyle::new().delete("1;31").insert("4;32");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnsiStyle {
+    equal: String,
+    delete: String,
+    insert: String
+}
+
+impl Default for AnsiStyle {
+    fn default() -> Self {
+        AnsiStyle { equal: String::new(), delete: "31".to_string(), insert: "32".to_string() }
+    }
+}
+
+impl AnsiStyle {
+    /// Create the default style with red deletions and green insertions
+    pub fn new() -> Self {
+        AnsiStyle::default()
+    }
+
+    /// Set the style of the characters that both strings share
+    pub fn equal(mut self, style: impl Into<String>) -> Self {
+        self.equal = style.into();
+        self
+    }
+
+    /// Set the style of the characters that are only in the left string
+    pub fn delete(mut self, style: impl Into<String>) -> Self {
+        self.delete = style.into();
+        self
+    }
+
+    /// Set the style of the characters that are only in the right string
+    pub fn insert(mut self, style: impl Into<String>) -> Self {
+        self.insert = style.into();
+        self
+    }
+
+    /// Append the characters in the style followed by a reset
+    fn paint(style: &str, text: &[char], out: &mut String) {
+        if text.is_empty() {
+            return;
+        }
+        if style.is_empty() {
+            out.extend(text);
+        } else {
+            out.push_str(&format!("\x1b[{}m", style));
+            out.extend(text);
+            out.push_str("\x1b[0m");
+        }
+    }
+}
+
+/// Render both strings for a terminal with their differences colored
+///
+/// The differences come from [`edit_ops`](crate::edit_ops). The left string is returned with the deleted
+/// characters colored and the right string with the inserted ones, so printing them one under
+/// the other shows why [`compare_similarity`](crate::compare_similarity) rated them the way it did.
+/// # Example
+/// ```
+/// use similar_string::{AnsiStyle, ansi_diff};
+///
+/// let (left, right) = ansi_diff("fight", "night", &AnsiStyle::new());
+/// // left: "\x1b[31mf\x1b[0might"
+/// // right: "\x1b[32mn\x1b[0might"
+/// println!("{}\n{}", left, right);
+/// ```
+pub fn ansi_diff(left: impl AsRef<str>, right: impl AsRef<str>, style: &AnsiStyle) -> (String, String) {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    let (mut left_out, mut right_out) = (String::new(), String::new());
+    for op in edit_ops_slices(&left, &right) {
+        match op {
+            EditOp::Equal { .. } => {
+                AnsiStyle::paint(&style.equal, &left[op.left_range()], &mut left_out);
+                AnsiStyle::paint(&style.equal, &right[op.right_range()], &mut right_out);
+            }
+            EditOp::Delete { .. } => AnsiStyle::paint(&style.delete, &left[op.left_range()], &mut left_out),
+            EditOp::Insert { .. } => AnsiStyle::paint(&style.insert, &right[op.right_range()], &mut right_out)
+        }
+    }
+    (left_out, right_out)
+}
+
+/// Render the changes that turn the left string into the right one as a single colored line
+///
+/// Deleted characters of the left string and inserted characters of the right string
+/// are written in the place where they differ, like the word diff of git.
+/// # Example
+/// ```
+/// use similar_string::{AnsiStyle, ansi_inline_diff};
+///
+/// ansi_inline_diff("fight", "night", &AnsiStyle::new()); // "\x1b[31mf\x1b[0m\x1b[32mn\x1b[0might"
+/// ```
+pub fn ansi_inline_diff(left: impl AsRef<str>, right: impl AsRef<str>, style: &AnsiStyle) -> String {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    let mut out = String::new();
+    for op in edit_ops_slices(&left, &right) {
+        match op {
+            EditOp::Equal { .. } => AnsiStyle::paint(&style.equal, &left[op.left_range()], &mut out),
+            EditOp::Delete { .. } => AnsiStyle::paint(&style.delete, &left[op.left_range()], &mut out),
+            EditOp::Insert { .. } => AnsiStyle::paint(&style.insert, &right[op.right_range()], &mut out)
+        }
+    }
+    out
+}
+
+/// Markup of the differences rendered by [`html_diff`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum HtmlMarkup {
+    /// Deletions in `<del>` and insertions in `<ins>` elements
+    #[default]
+    InsDel,
+    /// Deletions and insertions in `<span>` elements with the given classes
+    Classes {
+        /// Class of the deleted characters
+        delete: String,
+        /// Class of the inserted characters
+        insert: String
+    }
+}
+
+/// Append the characters with the special characters of HTML escaped
+fn escape_html(text: &[char], out: &mut String) {
+    for letter in text {
+        match letter {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(*letter)
+        }
+    }
+}
+
+/// Append the escaped characters wrapped in the element of the markup
+fn wrap_html(tag: &str, class: Option<&str>, text: &[char], out: &mut String) {
+    if text.is_empty() {
+        return;
+    }
+    match class {
+        Some(class) => {
+            out.push_str("<span class=\"");
+            escape_html(&class.chars().collect::<Vec<char>>(), out);
+            out.push_str("\">");
+            escape_html(text, out);
+            out.push_str("</span>");
+        }
+        None => {
+            out.push_str(&format!("<{}>", tag));
+            escape_html(text, out);
+            out.push_str(&format!("</{}>", tag));
+        }
+    }
+}
+
+/// Render the changes that turn the left string into the right one as inline HTML
+///
+/// The differences come from [`edit_ops`](crate::edit_ops). Deleted and inserted characters are
+/// wrapped in the elements of the markup in the place where they differ and all the text is escaped,
+/// so the result can be put straight into a page to show how two near-duplicate records differ.
+/// # Example
+/// ```
+/// use similar_string::{HtmlMarkup, html_diff};
+///
+/// html_diff("fight", "night", &HtmlMarkup::InsDel); // "<del>f</del><ins>n</ins>ight"
+///
+/// let markup = HtmlMarkup::Classes { delete: "removed".to_string(), insert: "added".to_string() };
+/// html_diff("fight", "night", &markup);
+/// // "<span class=\"removed\">f</span><span class=\"added\">n</span>ight"
+/// ```
+pub fn html_diff(left: impl AsRef<str>, right: impl AsRef<str>, markup: &HtmlMarkup) -> String {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    let (delete, insert) = match markup {
+        HtmlMarkup::InsDel => (None, None),
+        HtmlMarkup::Classes { delete, insert } => (Some(delete.as_str()), Some(insert.as_str()))
+    };
+    let mut out = String::new();
+    for op in edit_ops_slices(&left, &right) {
+        match op {
+            EditOp::Equal { .. } => escape_html(&left[op.left_range()], &mut out),
+            EditOp::Delete { .. } => wrap_html("del", delete, &left[op.left_range()], &mut out),
+            EditOp::Insert { .. } => wrap_html("ins", insert, &right[op.right_range()], &mut out)
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn ansi_diff_colors() {
+        let (left, right) = ansi_diff("fight", "night", &AnsiStyle::new());
+        assert_eq!(left, "\x1b[31mf\x1b[0might");
+        assert_eq!(right, "\x1b[32mn\x1b[0might");
+        assert_eq!(ansi_inline_diff("fight", "night", &AnsiStyle::new()), "\x1b[31mf\x1b[0m\x1b[32mn\x1b[0might");
+        assert_eq!(ansi_diff("same", "same", &AnsiStyle::new()), ("same".to_string(), "same".to_string()));
+    }
+
+    #[test]
+    fn ansi_diff_styles() {
+        let style = AnsiStyle::new().equal("2").delete("").insert("1;32");
+        assert_eq!(ansi_inline_diff("żółw", "żółty", &style), "\x1b[2mżół\x1b[0mw\x1b[1;32mty\x1b[0m");
+        assert_eq!(ansi_diff("", "ab", &style), (String::new(), "\x1b[1;32mab\x1b[0m".to_string()));
+    }
+
+    #[test]
+    fn html_diff_markup() {
+        assert_eq!(html_diff("fight", "night", &HtmlMarkup::default()), "<del>f</del><ins>n</ins>ight");
+        let markup = HtmlMarkup::Classes { delete: "old".to_string(), insert: "new \"x\"".to_string() };
+        assert_eq!(html_diff("a<b", "a<c", &markup), "a&lt;<span class=\"old\">b</span><span class=\"new &quot;x&quot;\">c</span>");
+        assert_eq!(html_diff("Tom & Jerry", "Tom & Jerry", &HtmlMarkup::InsDel), "Tom &amp; Jerry");
+        assert_eq!(html_diff("", "", &HtmlMarkup::InsDel), "");
+    }
+}
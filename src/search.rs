@@ -0,0 +1,613 @@
+use alloc::collections::BinaryHeap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, Ordering, Reverse};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+
+use crate::lcs::PatternMasks;
+use crate::lcs_rating;
+#[cfg(feature = "std")]
+use crate::metric::SimilarityMetric;
+
+/// Option that matched the target string
+///
+/// With the `serde` feature the match can be serialized. It borrows the option,
+/// so it is not deserialized, use [`LineMatch`] or a type of your own for that.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Match<'a> {
+    /// Position of the option amongst the searched options
+    pub index: usize,
+    /// The option itself
+    pub value: &'a str,
+    /// Similarity rating of the option
+    pub score: f64
+}
+
+/// Line of a reader that matched the target string
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineMatch {
+    /// Position of the line, starting from zero
+    pub index: usize,
+    /// The line without its line ending
+    pub line: String,
+    /// Similarity rating of the line
+    pub score: f64
+}
+
+/// Lines of the reader that stop at the first error, which is stored in `error`
+#[cfg(feature = "std")]
+fn read_lines<'e>(reader: impl BufRead + 'e, error: &'e mut Option<io::Error>) -> impl Iterator<Item = String> + 'e {
+    reader.lines().map_while(|line| line.map_err(|cause| *error = Some(cause)).ok())
+}
+
+/// Ratings of all the options against the target paired with their indices
+pub(crate) fn rated_options<'t, S: AsRef<str>, I>(target: &'t str, options: I) -> impl Iterator<Item = (usize, S, f64)> + 't
+where
+    I: IntoIterator<Item = S>,
+    I::IntoIter: 't
+{
+    // The masks of the target are shared by all the comparisons
+    let chars: Vec<char> = target.chars().collect();
+    let masks = PatternMasks::new(&chars);
+    options.into_iter().enumerate().map(move |(index, option)| {
+        let score = lcs_rating(masks.lcs_length(option.as_ref().chars()), target, option.as_ref());
+        (index, option, score)
+    })
+}
+
+/// Find the best rated option, the first one wins a tie
+///
+/// Options whose subsequence can't beat the current best score are skipped early.
+pub(crate) fn best_rated<S: AsRef<str>>(target: &str, options: impl IntoIterator<Item = S>) -> Option<(usize, S, f64)> {
+    let chars: Vec<char> = target.chars().collect();
+    let masks = PatternMasks::new(&chars);
+    let mut best: Option<(usize, S, f64)> = None;
+    for (index, option) in options.into_iter().enumerate() {
+        let text: Vec<char> = option.as_ref().chars().collect();
+        let high_score = best.as_ref().map_or(-1.0, |(_, _, score)| *score);
+        let size = max(text.len(), chars.len());
+        // Truncating the non-negative product rounds it down
+        let min_required = (high_score * size as f64).max(0.0) as usize;
+        let Some(lcs_len) = masks.lcs_length_bounded(&text, min_required) else { continue };
+        // Empty strings should match
+        let score = if size == 0 { 1.0 } else { lcs_len as f64 / size as f64 };
+        if score > high_score {
+            best = Some((index, option, score));
+        }
+    }
+    best
+}
+
+/// Lazy iterator over ratings of options, created with [`similarity_iter`]
+pub struct SimilarityIter<I> {
+    target: String,
+    masks: PatternMasks,
+    options: core::iter::Enumerate<I>
+}
+
+impl<I> Iterator for SimilarityIter<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>
+{
+    type Item = (usize, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, option) = self.options.next()?;
+        let option = option.as_ref();
+        Some((index, lcs_rating(self.masks.lcs_length(option.chars()), &self.target, option)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.options.size_hint()
+    }
+}
+
+/// Get indices and ratings of the options one at a time
+///
+/// Options are only rated when the iterator is advanced, so the search can be
+/// stopped early, for example on the first perfect match.
+/// # Example
+/// ```
+/// use similar_string::similarity_iter;
+///
+/// let options = vec!["fill", "fight", "night"];
+///
+/// // Stops after rating "fight"
+/// similarity_iter("fight", &options).find(|(_, score)| *score == 1.0); // Some((1, 1.0))
+/// ```
+pub fn similarity_iter<I>(target: impl AsRef<str>, options: I) -> SimilarityIter<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>
+{
+    let chars: Vec<char> = target.as_ref().chars().collect();
+    SimilarityIter {
+        target: target.as_ref().to_string(),
+        masks: PatternMasks::new(&chars),
+        options: options.into_iter().enumerate()
+    }
+}
+
+/// Rated option ordered so that better candidates are greater
+///
+/// Candidates with equal ratings are ordered by their position, the earlier one is better.
+struct Candidate {
+    index: usize,
+    score: f64
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.total_cmp(&other.score).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+/// Get the options that are the most similar to the target string
+///
+/// Returns up to `n` options together with their ratings that score at least `cutoff`.
+/// The results are sorted from the best match and options with equal ratings
+/// keep their original order. This is modelled on `difflib.get_close_matches` from Python,
+/// but the ratings come from [`compare_similarity`](crate::compare_similarity) rather than `SequenceMatcher.ratio()`,
+/// so they may differ, and difflib sorts options with equal ratings by the reverse of their text.
+/// # Example
+/// ```
+/// use similar_string::*;
+///
+/// let options = vec!["ape", "apple", "peach", "puppy"];
+///
+/// get_close_matches("appel", &options, 3, 0.6); // [("apple", 0.8), ("ape", 0.6)]
+/// ```
+pub fn get_close_matches(target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>, n: usize, cutoff: f64) -> Vec<(String, f64)> {
+    let mut result: Vec<(String, f64)> = rated_options(target.as_ref(), options)
+        .filter(|(_, _, score)| *score >= cutoff)
+        .map(|(_, option, score)| (option.as_ref().to_string(), score))
+        .collect();
+    result.sort_by(|(_, left), (_, right)| right.total_cmp(left));
+    result.truncate(n);
+    result
+}
+
+/// Find the option that is the most similar to the target string together with its position
+///
+/// Works like [`find_best_similarity`](crate::find_best_similarity), but borrows the
+/// matched option instead of cloning it. The first option wins a tie and `None` is returned
+/// if there are no options.
+/// # Example
+/// ```
+/// use similar_string::find_best_match;
+///
+/// let options = vec!["fill", "night", "ride"];
+///
+/// let best = find_best_match("fight", &options).unwrap();
+/// (best.index, best.value, best.score); // (1, "night", 0.8)
+/// ```
+pub fn find_best_match<'a, S: AsRef<str> + ?Sized + 'a>(target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>) -> Option<Match<'a>> {
+    best_rated(target.as_ref(), options).map(|(index, value, score)| Match { index, value: value.as_ref(), score })
+}
+
+/// Find the option that is the most similar to the target string according to the given metric
+///
+/// Works like [`find_best_match`] with any [`SimilarityMetric`], including closures.
+/// The first option wins a tie and `None` is returned if there are no options.
+/// # Example
+/// ```
+/// use similar_string::{find_best_with, JaroWinkler};
+///
+/// let options = vec!["fill", "night", "ride"];
+///
+/// find_best_with("fight", &options, &JaroWinkler).unwrap().value; // "night"
+/// ```
+#[cfg(feature = "std")]
+pub fn find_best_with<'a, S: AsRef<str> + ?Sized + 'a>(target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>, metric: &(impl SimilarityMetric + ?Sized)) -> Option<Match<'a>> {
+    let target = target.as_ref();
+    let mut best: Option<Match<'a>> = None;
+    for (index, value) in options.into_iter().enumerate() {
+        let value = value.as_ref();
+        let score = metric.similarity(value, target);
+        if best.is_none_or(|best| score > best.score) {
+            best = Some(Match { index, value, score });
+        }
+    }
+    best
+}
+
+/// Policy of choosing the winner amongst options with equal ratings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// The option that comes first
+    #[default]
+    First,
+    /// The option that comes last
+    Last,
+    /// The option with the fewest characters, then the first one
+    Shortest,
+    /// The lexicographically smallest option, then the first one
+    Lexicographic
+}
+
+impl TieBreak {
+    /// Whether the candidate should replace the current winner with an equal rating
+    fn prefers(&self, candidate: &str, winner: &str) -> bool {
+        match self {
+            TieBreak::First => false,
+            TieBreak::Last => true,
+            TieBreak::Shortest => candidate.chars().count() < winner.chars().count(),
+            TieBreak::Lexicographic => candidate < winner
+        }
+    }
+}
+
+/// Find the option that is the most similar to the target string using the given tie-break policy
+///
+/// With [`TieBreak::First`] this is the same as [`find_best_match`].
+/// Returns `None` if there are no options.
+/// # Example
+/// ```
+/// use similar_string::{find_best_match_with_tie_break, TieBreak};
+///
+/// let options = vec!["night", "light", "sight"];
+///
+/// find_best_match_with_tie_break("fight", &options, TieBreak::Last).unwrap().value; // "sight"
+/// find_best_match_with_tie_break("fight", &options, TieBreak::Lexicographic).unwrap().value; // "light"
+/// ```
+pub fn find_best_match_with_tie_break<'a, S: AsRef<str> + ?Sized + 'a>(target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>, tie_break: TieBreak) -> Option<Match<'a>> {
+    let mut best: Option<Match<'a>> = None;
+    for (index, value, score) in rated_options(target.as_ref(), options) {
+        let value = value.as_ref();
+        let replace = match best {
+            None => true,
+            Some(best) => score > best.score || (score == best.score && tie_break.prefers(value, best.value))
+        };
+        if replace {
+            best = Some(Match { index, value, score });
+        }
+    }
+    best
+}
+
+/// Find all the options that share the highest rating against the target string
+///
+/// The matches keep the original order of the options.
+/// Returns an empty vector if there are no options.
+/// # Example
+/// ```
+/// use similar_string::find_best_similarities;
+///
+/// let options = vec!["fill", "night", "light"];
+///
+/// let best = find_best_similarities("fight", &options);
+/// best.iter().map(|best| best.value).collect::<Vec<_>>(); // ["night", "light"]
+/// ```
+pub fn find_best_similarities<'a, S: AsRef<str> + ?Sized + 'a>(target: impl AsRef<str>, options: impl IntoIterator<Item = &'a S>) -> Vec<Match<'a>> {
+    let mut result: Vec<Match<'a>> = vec![];
+    for (index, value, score) in rated_options(target.as_ref(), options) {
+        let high_score = result.first().map_or(f64::NEG_INFINITY, |best| best.score);
+        if score > high_score {
+            result.clear();
+        }
+        if score >= high_score {
+            result.push(Match { index, value: value.as_ref(), score });
+        }
+    }
+    result
+}
+
+/// Get indices and ratings of the `k` options that are the most similar to the target string
+///
+/// The results are sorted from the best match and options with equal ratings
+/// keep their original order. Only `k` candidates are kept in memory at once,
+/// so this is cheaper than rating and sorting all the options.
+/// # Example
+/// ```
+/// use similar_string::find_top_k;
+///
+/// let options = vec!["fill", "night", "ride", "light"];
+///
+/// find_top_k("fight", &options, 2); // [(1, 0.8), (3, 0.8)]
+/// ```
+pub fn find_top_k(target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>, k: usize) -> Vec<(usize, f64)> {
+    if k == 0 {
+        return vec![];
+    }
+    // Min-heap of the best candidates so far, the worst one is on the top
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (index, _, score) in rated_options(target.as_ref(), options) {
+        heap.push(Reverse(Candidate { index, score }));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec().into_iter()
+        .map(|Reverse(candidate)| (candidate.index, candidate.score))
+        .collect()
+}
+
+/// Find the line of the reader that is the most similar to the target string
+///
+/// The lines are read one at a time and only the best one is kept in memory,
+/// so huge files can be searched without loading them. Lines end with `\n` or `\r\n`.
+/// The first line wins a tie and `None` is returned if there are no lines.
+/// Errors of the reader are returned as soon as they happen.
+/// # Example
+/// ```
+/// use similar_string::find_best_in_lines;
+///
+/// let log = "fill\nnight\nride\n".as_bytes();
+///
+/// let best = find_best_in_lines("fight", log).unwrap().unwrap();
+/// (best.index, best.line, best.score); // (1, "night", 0.8)
+/// ```
+#[cfg(feature = "std")]
+pub fn find_best_in_lines(target: impl AsRef<str>, reader: impl BufRead) -> io::Result<Option<LineMatch>> {
+    let mut error = None;
+    let best = best_rated(target.as_ref(), read_lines(reader, &mut error));
+    match error {
+        Some(error) => Err(error),
+        None => Ok(best.map(|(index, line, score)| LineMatch { index, line, score }))
+    }
+}
+
+/// Find the `k` lines of the reader that are the most similar to the target string
+///
+/// The results are sorted from the best match and lines with equal ratings keep their order.
+/// Only `k` lines are kept in memory at once. Errors of the reader are returned as soon as they happen.
+/// # Example
+/// ```
+/// use similar_string::find_top_k_in_lines;
+///
+/// let log = "fill\nnight\nride\nlight".as_bytes();
+///
+/// let lines = find_top_k_in_lines("fight", log, 2).unwrap();
+/// lines.iter().map(|found| found.line.as_str()).collect::<Vec<_>>(); // ["night", "light"]
+/// ```
+#[cfg(feature = "std")]
+pub fn find_top_k_in_lines(target: impl AsRef<str>, reader: impl BufRead, k: usize) -> io::Result<Vec<LineMatch>> {
+    if k == 0 {
+        return Ok(vec![]);
+    }
+    let mut error = None;
+    // Min-heap of the best candidates so far together with their lines
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    let mut lines = HashMap::with_capacity(k + 1);
+    for (index, line, score) in rated_options(target.as_ref(), read_lines(reader, &mut error)) {
+        heap.push(Reverse(Candidate { index, score }));
+        lines.insert(index, line);
+        if heap.len() > k {
+            if let Some(Reverse(worst)) = heap.pop() {
+                lines.remove(&worst.index);
+            }
+        }
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+    Ok(heap.into_sorted_vec().into_iter()
+        .map(|Reverse(candidate)| LineMatch {
+            index: candidate.index,
+            line: lines.remove(&candidate.index).expect("every candidate has its line"),
+            score: candidate.score
+        })
+        .collect())
+}
+
+/// Get indices and ratings of all the options that score at least `min_score`
+///
+/// The results keep the original order of the options.
+/// # Example
+/// ```
+/// use similar_string::find_matches_above;
+///
+/// let options = vec!["fill", "night", "ride", "light"];
+///
+/// find_matches_above("fight", &options, 0.5); // [(1, 0.8), (3, 0.8)]
+/// ```
+pub fn find_matches_above(target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>, min_score: f64) -> Vec<(usize, f64)> {
+    rated_options(target.as_ref(), options)
+        .filter(|(_, _, score)| *score >= min_score)
+        .map(|(index, _, score)| (index, score))
+        .collect()
+}
+
+/// Get indices and ratings of all the options sorted from the best match
+///
+/// Options with equal ratings keep their original order. The indices can be used
+/// to reorder other data that corresponds to the options.
+/// # Example
+/// ```
+/// use similar_string::rank_options;
+///
+/// let options = vec!["fill", "night", "ride"];
+/// let ids = vec![10, 20, 30];
+///
+/// let ranking = rank_options("fight", &options); // [(1, 0.8), (0, 0.4), (2, 0.2)]
+/// ranking.iter().map(|(index, _)| ids[*index]).collect::<Vec<_>>(); // [20, 10, 30]
+/// ```
+pub fn rank_options(target: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<(usize, f64)> {
+    let mut result: Vec<(usize, f64)> = rated_options(target.as_ref(), options)
+        .map(|(index, _, score)| (index, score))
+        .collect();
+    result.sort_by(|(_, left), (_, right)| right.total_cmp(left));
+    result
+}
+
+/// Sort the options from the most similar to the target string
+///
+/// The sort is stable, so options with equal ratings keep their order.
+/// Every option is rated only once.
+/// # Example
+/// ```
+/// use similar_string::sort_by_similarity;
+///
+/// let mut options = vec!["fill", "night", "ride", "light"];
+/// sort_by_similarity("fight", &mut options);
+/// options; // ["night", "light", "fill", "ride"]
+/// ```
+pub fn sort_by_similarity(target: impl AsRef<str>, options: &mut [impl AsRef<str>]) {
+    let target = target.as_ref();
+    let chars: Vec<char> = target.chars().collect();
+    let masks = PatternMasks::new(&chars);
+    options.sort_by_cached_key(|option| {
+        let option = option.as_ref();
+        let score = lcs_rating(masks.lcs_length(option.chars()), target, option);
+        // Ratings are never negative, so their bits are ordered just like the values
+        Reverse(score.to_bits())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::*;
+
+    #[test]
+    fn close_matches_work() {
+        let options = vec!["ape", "apple", "peach", "puppy"];
+        let matches = get_close_matches("appel", &options, 3, 0.6);
+        assert_eq!(matches, vec![("apple".to_string(), 0.8), ("ape".to_string(), 0.6)]);
+    }
+
+    #[test]
+    fn close_matches_limit_and_ties() {
+        let options = vec!["bat", "cat", "hat", "dog"];
+        let matches = get_close_matches("mat", &options, 2, 0.0);
+        assert_eq!(matches, vec![("bat".to_string(), 2.0 / 3.0), ("cat".to_string(), 2.0 / 3.0)]);
+        assert!(get_close_matches("mat", &options, 0, 0.0).is_empty());
+        assert!(get_close_matches("mat", &[] as &[&str], 3, 0.0).is_empty());
+    }
+
+    #[test]
+    fn best_match_works() {
+        let options = vec!["fill".to_string(), "night".to_string(), "light".to_string()];
+        let best = find_best_match("fight", &options).unwrap();
+        assert_eq!(best, Match { index: 1, value: "night", score: 0.8 });
+        assert_eq!(find_best_match("fight", &[] as &[&str]), None);
+        assert_eq!(find_best_match("", &["", "a"]).unwrap().index, 0);
+    }
+
+    #[test]
+    fn tie_break_works() {
+        let options = vec!["night", "light", "sight", "fill", "slight"];
+        let pick = |tie_break| find_best_match_with_tie_break("fight", &options, tie_break).unwrap().index;
+        assert_eq!(pick(TieBreak::First), 0);
+        assert_eq!(pick(TieBreak::Last), 2);
+        assert_eq!(pick(TieBreak::Lexicographic), 1);
+        assert_eq!(find_best_match_with_tie_break("ab", &["abxx", "abx", "aby"], TieBreak::Shortest).unwrap().index, 1);
+        assert_eq!(find_best_match_with_tie_break("ab", &[] as &[&str], TieBreak::Last), None);
+    }
+
+    #[test]
+    fn all_ties_are_returned() {
+        let options = vec!["fill", "night", "light"];
+        let indices: Vec<usize> = find_best_similarities("fight", &options).iter().map(|best| best.index).collect();
+        assert_eq!(indices, vec![1, 2]);
+        assert!(find_best_similarities("fight", &[] as &[&str]).is_empty());
+    }
+
+    #[test]
+    fn similarity_iter_is_lazy() {
+        let mut rated = 0;
+        let options = ["fill", "fight", "night"].into_iter().inspect(|_| rated += 1);
+        let perfect = similarity_iter("fight", options).find(|(_, score)| *score == 1.0);
+        assert_eq!(perfect, Some((1, 1.0)));
+        assert_eq!(rated, 2);
+        let all: Vec<(usize, f64)> = similarity_iter("fight", &["fill", "night", "ride"]).collect();
+        assert_eq!(all, vec![(0, 0.4), (1, 0.8), (2, 0.2)]);
+    }
+
+    #[test]
+    fn rank_options_works() {
+        let options = vec!["fill", "night", "ride", "light"];
+        assert_eq!(rank_options("fight", &options), vec![(1, 0.8), (3, 0.8), (0, 0.4), (2, 0.2)]);
+        assert!(rank_options("fight", &[] as &[&str]).is_empty());
+    }
+
+    #[test]
+    fn sort_by_similarity_is_stable() {
+        let mut options = vec!["fill", "night", "ride", "light"];
+        sort_by_similarity("fight", &mut options);
+        assert_eq!(options, vec!["night", "light", "fill", "ride"]);
+        let mut options = vec!["cat".to_string(), "mat".to_string(), "bat".to_string()];
+        sort_by_similarity("hat", &mut options);
+        assert_eq!(options, vec!["cat", "mat", "bat"]);
+    }
+
+    #[test]
+    fn any_iterator_of_options() {
+        let text = "fill\nnight\nride";
+        assert_eq!(find_best_match("fight", text.lines()).unwrap().value, "night");
+        let options: HashMap<String, usize> = [("night".to_string(), 1)].into_iter().collect();
+        assert_eq!(find_best_match("fight", options.keys()).unwrap().value, "night");
+        assert_eq!(find_top_k("fight", text.lines(), 1), vec![(1, 0.8)]);
+        assert_eq!(find_matches_above("fight", text.split('\n'), 0.4), vec![(0, 0.4), (1, 0.8)]);
+        assert_eq!(get_close_matches("fight", text.lines().map(String::from), 1, 0.0), vec![("night".to_string(), 0.8)]);
+    }
+
+    #[test]
+    fn top_k_works() {
+        let options = vec!["fill", "night", "ride", "light"];
+        assert_eq!(find_top_k("fight", &options, 2), vec![(1, 0.8), (3, 0.8)]);
+        assert_eq!(find_top_k("fight", &options, 10), vec![(1, 0.8), (3, 0.8), (0, 0.4), (2, 0.2)]);
+        assert!(find_top_k("fight", &options, 0).is_empty());
+        assert!(find_top_k("fight", &[] as &[&str], 5).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn best_in_lines() {
+        let text = "fill\r\nnight\nride\nlight\n";
+        let best = find_best_in_lines("fight", text.as_bytes()).unwrap();
+        assert_eq!(best, Some(LineMatch { index: 1, line: "night".to_string(), score: 0.8 }));
+        assert_eq!(find_best_in_lines("fill", text.as_bytes()).unwrap().unwrap().score, 1.0);
+        assert_eq!(find_best_in_lines("fight", "".as_bytes()).unwrap(), None);
+        let top: Vec<(usize, f64)> = find_top_k_in_lines("fight", text.as_bytes(), 3).unwrap().iter().map(|found| (found.index, found.score)).collect();
+        assert_eq!(top, find_top_k("fight", text.lines(), 3));
+        assert!(find_top_k_in_lines("fight", text.as_bytes(), 0).unwrap().is_empty());
+        // Lines that are not valid UTF-8 are errors
+        assert!(find_best_in_lines("fight", &b"fill\n\xff\n"[..]).is_err());
+        assert!(find_top_k_in_lines("fight", &b"fill\n\xff\n"[..], 1).is_err());
+    }
+
+    #[test]
+    fn matches_above_work() {
+        let options = vec!["fill", "night", "ride", "light"];
+        assert_eq!(find_matches_above("fight", &options, 0.5), vec![(1, 0.8), (3, 0.8)]);
+        assert_eq!(find_matches_above("fight", &options, 0.4), vec![(0, 0.4), (1, 0.8), (3, 0.8)]);
+        assert!(find_matches_above("fight", &options, 0.9).is_empty());
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn match_serde() {
+        let best = find_best_match("fight", &["fill", "night"]).unwrap();
+        let json = serde_json::to_string(&best).unwrap();
+        assert_eq!(json, r#"{"index":1,"value":"night","score":0.8}"#);
+        // Owned results also deserialize from escaped strings and from values
+        let line = LineMatch { index: 0, line: "a\"b".to_string(), score: 1.0 };
+        let value = serde_json::to_value(&line).unwrap();
+        assert_eq!(serde_json::from_str::<LineMatch>(&value.to_string()).unwrap(), line);
+        assert_eq!(serde_json::from_value::<LineMatch>(value).unwrap(), line);
+    }
+}
@@ -0,0 +1,125 @@
+//! Vectorized LCS table rows, enabled with the `simd` feature
+//!
+//! The row by row computation of the LCS table can't be vectorized, because every cell
+//! depends on its left neighbour. Cells of one anti-diagonal only depend on the two
+//! previous anti-diagonals, so they are computed together with AVX2 when the CPU supports it.
+//!
+//! Only the rows of Hirschberg's algorithm use this, so it speeds up the functions that
+//! reconstruct the subsequence, such as [`lcs`](crate::lcs), [`lcs_indices`](crate::lcs_indices)
+//! and [`edit_ops`](crate::edit_ops). The LCS length behind [`compare_similarity`](crate::compare_similarity)
+//! and the searches is computed with the bit-parallel algorithm, which is faster than this and doesn't change.
+
+/// Computes `out[t] = if equal[t] { diagonal[t] + 1 } else { max(up[t], left[t]) }`
+type DiagonalStep = fn(&[u32], &[u32], &[u32], &[u32], &mut [u32]);
+
+fn scalar_step(equal: &[u32], diagonal: &[u32], up: &[u32], left: &[u32], out: &mut [u32]) {
+    for (index, cell) in out.iter_mut().enumerate() {
+        *cell = if equal[index] != 0 {
+            diagonal[index] + 1
+        } else {
+            up[index].max(left[index])
+        };
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_step(equal: &[u32], diagonal: &[u32], up: &[u32], left: &[u32], out: &mut [u32]) {
+    use std::arch::x86_64::*;
+
+    let lanes = out.len() / 8 * 8;
+    let one = _mm256_set1_epi32(1);
+    for start in (0..lanes).step_by(8) {
+        // All the slices are at least as long as the output, so the loads stay in bounds
+        let mask = _mm256_loadu_si256(equal.as_ptr().add(start) as *const __m256i);
+        let matched = _mm256_add_epi32(_mm256_loadu_si256(diagonal.as_ptr().add(start) as *const __m256i), one);
+        let skipped = _mm256_max_epu32(
+            _mm256_loadu_si256(up.as_ptr().add(start) as *const __m256i),
+            _mm256_loadu_si256(left.as_ptr().add(start) as *const __m256i)
+        );
+        let cells = _mm256_blendv_epi8(skipped, matched, mask);
+        _mm256_storeu_si256(out.as_mut_ptr().add(start) as *mut __m256i, cells);
+    }
+    scalar_step(&equal[lanes..], &diagonal[lanes..], &up[lanes..], &left[lanes..], &mut out[lanes..]);
+}
+
+/// Pick the fastest implementation supported by the CPU
+fn detect_step() -> DiagonalStep {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        // Safe to call, since the CPU supports AVX2
+        return |equal, diagonal, up, left, out| unsafe { avx2_step(equal, diagonal, up, left, out) };
+    }
+    scalar_step
+}
+
+/// Last row of the LCS table of `left` against all prefixes of `right`
+pub(crate) fn forward_row<T: PartialEq>(left: &[T], right: &[T]) -> Vec<usize> {
+    let (rows, cols) = (left.len(), right.len());
+    let mut result = vec![0; cols + 1];
+    if rows == 0 || cols == 0 {
+        return result;
+    }
+    let step = detect_step();
+    // Anti-diagonals are indexed by the row, cells outside of the table stay zero
+    let mut before = vec![0u32; rows + 1];
+    let mut previous = vec![0u32; rows + 1];
+    let mut current = vec![0u32; rows + 1];
+    let mut equal = vec![0u32; rows];
+
+    for diagonal in 2..=rows + cols {
+        let first = diagonal.saturating_sub(cols).max(1);
+        let last = rows.min(diagonal - 1);
+        let count = last - first + 1;
+        for (offset, cell) in equal[..count].iter_mut().enumerate() {
+            let row = first + offset;
+            *cell = if left[row - 1] == right[diagonal - row - 1] { u32::MAX } else { 0 };
+        }
+        step(
+            &equal[..count],
+            &before[first - 1..last],
+            &previous[first - 1..last],
+            &previous[first..=last],
+            &mut current[first..=last]
+        );
+        if last == rows {
+            result[diagonal - rows] = current[rows] as usize;
+        }
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    result
+}
+
+/// LCS lengths of `left` against all suffixes of `right`, indexed by the suffix start
+pub(crate) fn backward_row<T: PartialEq>(left: &[T], right: &[T]) -> Vec<usize> {
+    let left: Vec<&T> = left.iter().rev().collect();
+    let right: Vec<&T> = right.iter().rev().collect();
+    let mut row = forward_row(&left, &right);
+    row.reverse();
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lcs::lcs_table;
+
+    #[test]
+    fn diagonals_match_table() {
+        let mut seed: u64 = 11;
+        let mut random = |limit: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % limit
+        };
+        for _ in 0..200 {
+            let left: Vec<u8> = (0..random(60)).map(|_| random(4) as u8).collect();
+            let right: Vec<u8> = (0..random(60)).map(|_| random(4) as u8).collect();
+            let table = lcs_table(&left, &right);
+            assert_eq!(super::forward_row(&left, &right), table[left.len()]);
+            let backward: Vec<usize> = (0..=right.len())
+                .map(|col| lcs_table(&left, &right[col..])[left.len()][right.len() - col])
+                .collect();
+            assert_eq!(super::backward_row(&left, &right), backward);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::minhash::{fnv1a, mix};
+use crate::tokens::split_words;
+
+/// Get the 64-bit SimHash fingerprint of a text
+///
+/// Every word of the text is hashed and each bit of the fingerprint is set when more words,
+/// counted with their occurrences, have that bit set than unset. Similar texts get fingerprints
+/// that differ in only a few bits, so only the fingerprints need to be stored to find
+/// near-duplicates later. Words are found with [`split_words`](crate::split_words).
+/// # Example
+/// ```
+/// use similar_string::{simhash, simhash_distance};
+///
+/// let left = simhash("GET /index.html 200 from 10.0.0.1 in 35 ms");
+/// let right = simhash("GET /index.html 200 from 10.0.0.1 in 36 ms");
+///
+/// simhash_distance(left, right); // 7
+/// ```
+pub fn simhash(text: impl AsRef<str>) -> u64 {
+    let mut counts: HashMap<&str, i64> = HashMap::new();
+    for word in split_words(text.as_ref()) {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    let mut weights = [0i64; 64];
+    for (word, count) in counts {
+        let hash = mix(fnv1a(word.bytes()));
+        for (bit, weight) in weights.iter_mut().enumerate() {
+            if hash >> bit & 1 == 1 { *weight += count } else { *weight -= count }
+        }
+    }
+    weights.iter().enumerate()
+        .filter(|(_, weight)| **weight > 0)
+        .fold(0, |fingerprint, (bit, _)| fingerprint | 1 << bit)
+}
+
+/// Get the number of bits in which two SimHash fingerprints differ
+/// # Example
+/// ```
+/// use similar_string::{simhash, simhash_distance};
+///
+/// simhash_distance(simhash("the same text"), simhash("the same text")); // 0
+/// ```
+pub fn simhash_distance(left: u64, right: u64) -> u32 {
+    (left ^ right).count_ones()
+}
+
+/// Check whether two SimHash fingerprints differ in at most `max_bits` bits
+///
+/// A limit of 3 bits is common for near-duplicate detection of web pages.
+/// # Example
+/// ```
+/// use similar_string::{simhash, simhash_near_duplicate};
+///
+/// let left = simhash("the quick brown fox jumps over the lazy dog today");
+/// let right = simhash("the quick brown fox jumps over the lazy dog");
+///
+/// simhash_near_duplicate(left, right, 3); // true
+/// ```
+pub fn simhash_near_duplicate(left: u64, right: u64, max_bits: u32) -> bool {
+    simhash_distance(left, right) <= max_bits
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn simhash_works() {
+        let text = "the quick brown fox jumps over the lazy dog";
+        assert_eq!(simhash(text), simhash(text));
+        assert_eq!(simhash(text), simhash("The quick, brown fox... jumps over the lazy dog!".replace("The", "the")));
+        assert_eq!(simhash(""), 0);
+        let near = simhash("the quick brown fox jumps over the lazy dog today");
+        let far = simhash("lorem ipsum dolor sit amet consectetur adipiscing elit");
+        assert!(simhash_distance(simhash(text), near) < simhash_distance(simhash(text), far));
+        assert!(simhash_near_duplicate(simhash(text), near, 10));
+        assert!(!simhash_near_duplicate(simhash(text), far, 10));
+    }
+
+    #[test]
+    fn simhash_distance_works() {
+        assert_eq!(simhash_distance(0, 0), 0);
+        assert_eq!(simhash_distance(0b1011, 0b0110), 3);
+        assert_eq!(simhash_distance(0, u64::MAX), 64);
+        assert!(simhash_near_duplicate(0b1011, 0b0110, 3));
+        assert!(!simhash_near_duplicate(0b1011, 0b0110, 2));
+    }
+}
@@ -0,0 +1,262 @@
+/// Score of every matched character
+const SCORE_MATCH: i32 = 16;
+/// Penalty of the first skipped character between two matched ones
+const GAP_START: i32 = 3;
+/// Penalty of every further skipped character
+const GAP_EXTENSION: i32 = 1;
+/// Bonus of a word that starts after whitespace or at the start of the candidate
+const BONUS_BOUNDARY_WHITE: i32 = 10;
+/// Bonus of a word that starts after a delimiter, such as a slash or an underscore
+const BONUS_BOUNDARY_DELIMITER: i32 = 9;
+/// Bonus of a word that starts after any other punctuation
+const BONUS_BOUNDARY: i32 = 8;
+/// Bonus of a camelCase hump and of a number that follows letters
+const BONUS_CAMEL: i32 = 7;
+/// Bonus of a character matched right after the previous one
+const BONUS_CONSECUTIVE: i32 = 4;
+/// The bonus of the first character of the query counts this many times
+const FIRST_CHAR_MULTIPLIER: i32 = 2;
+
+/// Score of cells that can't be reached, low enough to never win and to never overflow
+const UNREACHABLE: i32 = i32::MIN / 2;
+
+/// Kind of a character that decides the bonus of the character after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    White,
+    Delimiter,
+    NonWord,
+    Lower,
+    Upper,
+    Letter,
+    Digit
+}
+
+fn char_class(letter: char) -> CharClass {
+    if letter.is_whitespace() {
+        CharClass::White
+    } else if "/\\,:;|_-.".contains(letter) {
+        CharClass::Delimiter
+    } else if letter.is_lowercase() {
+        CharClass::Lower
+    } else if letter.is_uppercase() {
+        CharClass::Upper
+    } else if letter.is_alphabetic() {
+        CharClass::Letter
+    } else if letter.is_numeric() {
+        CharClass::Digit
+    } else {
+        CharClass::NonWord
+    }
+}
+
+/// Bonus of matching a character of the given class that follows a character of the previous class
+fn bonus(previous: CharClass, current: CharClass) -> i32 {
+    use CharClass::*;
+    match (previous, current) {
+        (_, White | Delimiter | NonWord) => 0,
+        (White, _) => BONUS_BOUNDARY_WHITE,
+        (Delimiter, _) => BONUS_BOUNDARY_DELIMITER,
+        (NonWord, _) => BONUS_BOUNDARY,
+        (Lower, Upper) | (Lower | Upper | Letter, Digit) => BONUS_CAMEL,
+        _ => 0
+    }
+}
+
+/// Bonuses of all the characters of the candidate, the start counts as whitespace
+fn bonuses(candidate: &[char]) -> Vec<i32> {
+    let mut previous = CharClass::White;
+    candidate.iter().map(|letter| {
+        let current = char_class(*letter);
+        let bonus = bonus(previous, current);
+        previous = current;
+        bonus
+    }).collect()
+}
+
+/// Characters prepared for matching, lowercase unless the query has uppercase letters
+fn fold_case(text: &str, case_sensitive: bool) -> Vec<char> {
+    if case_sensitive {
+        text.chars().collect()
+    } else {
+        text.chars().map(|letter| letter.to_lowercase().next().unwrap_or(letter)).collect()
+    }
+}
+
+/// Query matched in a candidate by an interactive filter, see [`subsequence_match`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubsequenceMatch {
+    /// The [`subsequence_score`] of the candidate
+    pub score: i32,
+    /// Character indices of the matched characters of the candidate in increasing order
+    pub positions: Vec<usize>
+}
+
+/// Penalty of skipping the given number of characters between two matched ones
+fn gap_penalty(len: usize) -> i32 {
+    GAP_START + (len as i32 - 1) * GAP_EXTENSION
+}
+
+/// Get the score of a query typed into an interactive filter against a candidate
+///
+/// Works like the filters of fzf and the command palette of Sublime Text.
+/// The query has to be a subsequence of the candidate, otherwise `None` is returned.
+/// Every matched character scores, characters that start a word, follow a delimiter or
+/// start a camelCase hump get a bonus and so do runs of consecutive characters, while
+/// skipped characters between the matched ones are penalized. The best placement of the
+/// query is found, so `"of"` prefers the initials of `"Open File"` over the `"of"` in `"profile"`.
+/// Letter case is ignored unless the query has uppercase letters.
+///
+/// The score is not normalized, it only ranks the candidates of the same query.
+/// An empty query matches every candidate with the score 0.
+/// # Example
+/// ```
+/// use similar_string::subsequence_score;
+///
+/// subsequence_score("of", "Open File"); // Some(56)
+/// subsequence_score("of", "profile"); // Some(36)
+/// subsequence_score("fo", "profile"); // None
+/// ```
+pub fn subsequence_score(query: impl AsRef<str>, candidate: impl AsRef<str>) -> Option<i32> {
+    subsequence_match(query, candidate).map(|found| found.score)
+}
+
+/// Get the score of a query in an interactive filter together with the positions it matched
+///
+/// Works like [`subsequence_score`] and also returns the character indices of the candidate
+/// that the best placement of the query matched, so that they can be highlighted.
+/// # Example
+/// ```
+/// use similar_string::subsequence_match;
+///
+/// let found = subsequence_match("of", "Open File").unwrap();
+/// (found.score, found.positions); // (56, [0, 5])
+/// ```
+pub fn subsequence_match(query: impl AsRef<str>, candidate: impl AsRef<str>) -> Option<SubsequenceMatch> {
+    let query = query.as_ref();
+    let case_sensitive = query.chars().any(char::is_uppercase);
+    let query = fold_case(query, case_sensitive);
+    let candidate = candidate.as_ref();
+    let bonuses = bonuses(&candidate.chars().collect::<Vec<char>>());
+    let candidate = fold_case(candidate, case_sensitive);
+    if query.is_empty() {
+        return Some(SubsequenceMatch { score: 0, positions: vec![] });
+    }
+    if query.len() > candidate.len() {
+        return None;
+    }
+    // Best score of the query prefix with its last character matched at every position
+    let mut table = vec![vec![UNREACHABLE; candidate.len()]; query.len()];
+    for (row, letter) in query.iter().enumerate() {
+        // Best score of a previous row cell followed by a gap before the current column
+        let mut gapped = UNREACHABLE;
+        for col in 0..candidate.len() {
+            if row > 0 && col >= 2 {
+                gapped = (gapped - GAP_EXTENSION).max(table[row - 1][col - 2] - GAP_START);
+            }
+            if candidate[col] != *letter {
+                continue;
+            }
+            table[row][col] = if row == 0 {
+                SCORE_MATCH + bonuses[col] * FIRST_CHAR_MULTIPLIER
+            } else {
+                let consecutive = if col > 0 { table[row - 1][col - 1] + BONUS_CONSECUTIVE } else { UNREACHABLE };
+                let best = consecutive.max(gapped);
+                if best <= UNREACHABLE / 2 { UNREACHABLE } else { best + SCORE_MATCH + bonuses[col] }
+            };
+        }
+    }
+    let last = &table[query.len() - 1];
+    let (mut col, score) = last.iter().copied().enumerate()
+        .filter(|(_, score)| *score > UNREACHABLE / 2)
+        .max_by(|(left_col, left), (right_col, right)| left.cmp(right).then(right_col.cmp(left_col)))?;
+    // Follow the cells that the best score came from back to the first character of the query
+    let mut positions = vec![col];
+    for row in (1..query.len()).rev() {
+        let before = table[row][col] - SCORE_MATCH - bonuses[col];
+        col = if col > 0 && table[row - 1][col - 1] + BONUS_CONSECUTIVE == before {
+            col - 1
+        } else {
+            (0..col.saturating_sub(1)).rev()
+                .find(|previous| table[row - 1][*previous] - gap_penalty(col - 1 - previous) == before)
+                .expect("every reachable cell has a predecessor")
+        };
+        positions.push(col);
+    }
+    positions.reverse();
+    Some(SubsequenceMatch { score, positions })
+}
+
+/// Rank the options that match the query in an interactive filter
+///
+/// Only the options that contain the query as a subsequence are returned, as their indices
+/// with the [`subsequence_score`], from the best one. Options with equal scores keep their order.
+/// # Example
+/// ```
+/// use similar_string::rank_subsequence_matches;
+///
+/// let commands = vec!["Copy Profile", "Open File", "Close Folder"];
+///
+/// rank_subsequence_matches("of", &commands); // [(1, 56), (2, 37), (0, 36)]
+/// ```
+pub fn rank_subsequence_matches(query: impl AsRef<str>, options: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<(usize, i32)> {
+    let query = query.as_ref();
+    let mut result: Vec<(usize, i32)> = options.into_iter()
+        .enumerate()
+        .filter_map(|(index, option)| subsequence_score(query, option).map(|score| (index, score)))
+        .collect();
+    result.sort_by(|(_, left), (_, right)| right.cmp(left));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn subsequence_bonuses() {
+        assert_eq!(subsequence_score("of", "Open File"), Some(56));
+        assert_eq!(subsequence_score("of", "profile"), Some(36));
+        assert_eq!(subsequence_score("fo", "profile"), None);
+        assert_eq!(subsequence_score("abcd", "abc"), None);
+        assert_eq!(subsequence_score("", "profile"), Some(0));
+        // camelCase humps and delimiters start words
+        assert!(subsequence_score("fb", "foo_bar") > subsequence_score("fb", "fooxbar"));
+        assert!(subsequence_score("fb", "fooBar") > subsequence_score("fb", "foobar"));
+        // Consecutive characters beat scattered ones
+        assert!(subsequence_score("abc", "xabcx") > subsequence_score("abc", "xaxbxcx"));
+    }
+
+    #[test]
+    fn subsequence_case() {
+        assert_eq!(subsequence_score("OF", "Open File"), Some(56));
+        assert_eq!(subsequence_score("OF", "open file"), None);
+        assert_eq!(subsequence_score("żw", "Żółw"), Some(subsequence_score("ab", "Axxb").unwrap()));
+    }
+
+    #[test]
+    fn subsequence_positions() {
+        assert_eq!(subsequence_match("of", "Open File"), Some(SubsequenceMatch { score: 56, positions: vec![0, 5] }));
+        assert_eq!(subsequence_match("of", "profile").unwrap().positions, vec![2, 3]);
+        assert_eq!(subsequence_match("fb", "foo_bar").unwrap().positions, vec![0, 4]);
+        assert_eq!(subsequence_match("żw", "Żółw").unwrap().positions, vec![0, 3]);
+        assert_eq!(subsequence_match("", "profile").unwrap().positions, Vec::<usize>::new());
+        assert_eq!(subsequence_match("fo", "profile"), None);
+        // The positions always increase and spell the query
+        for (query, candidate) in [("abc", "xaxbxcabc"), ("gsr", "get_string_result"), ("aa", "aaaa")] {
+            let positions = subsequence_match(query, candidate).unwrap().positions;
+            assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+            let spelled: String = positions.iter().map(|position| candidate.chars().nth(*position).unwrap()).collect();
+            assert_eq!(spelled, query);
+        }
+    }
+
+    #[test]
+    fn subsequence_ranking() {
+        let commands = vec!["Copy Profile", "Open File", "Close Folder", "Settings"];
+        assert_eq!(rank_subsequence_matches("of", &commands), vec![(1, 56), (2, 37), (0, 36)]);
+        assert_eq!(rank_subsequence_matches("", &commands).len(), 4);
+        assert!(rank_subsequence_matches("xyz", &commands).is_empty());
+    }
+}
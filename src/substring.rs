@@ -0,0 +1,96 @@
+/// The longest contiguous fragment shared by two strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CommonSubstring<'a> {
+    /// The shared fragment borrowed from the left string
+    pub value: &'a str,
+    /// Number of characters in the fragment
+    pub length: usize,
+    /// Byte offset of the fragment in the left string
+    pub left_offset: usize,
+    /// Byte offset of the fragment in the right string
+    pub right_offset: usize
+}
+
+/// Find the longest common substring of two strings
+///
+/// Unlike the subsequence returned by [`lcs_length`](crate::lcs_length), the substring is contiguous
+/// in both strings. When several substrings are equally long, the one that appears
+/// first in the left string is returned. If the strings share no characters,
+/// the returned substring is empty.
+/// # Example
+/// ```
+/// use similar_string::longest_common_substring;
+///
+/// let common = longest_common_substring("connection reset", "reset by peer");
+/// common.value; // "reset"
+/// common.length; // 5
+/// common.left_offset; // 11
+/// common.right_offset; // 0
+/// ```
+pub fn longest_common_substring<'a>(left: &'a str, right: &str) -> CommonSubstring<'a> {
+    let right: Vec<(usize, char)> = right.char_indices().collect();
+    let mut previous = vec![0; right.len() + 1];
+    let mut current = vec![0; right.len() + 1];
+    // Position of the last character of the best match in both strings
+    let (mut best_length, mut best_left, mut best_right) = (0, 0, 0);
+
+    for (lindex, (_, lletter)) in left.char_indices().enumerate() {
+        for (rindex, (_, rletter)) in right.iter().enumerate() {
+            current[rindex + 1] = if lletter == *rletter { previous[rindex] + 1 } else { 0 };
+            if current[rindex + 1] > best_length {
+                best_length = current[rindex + 1];
+                best_left = lindex;
+                best_right = rindex;
+            }
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    if best_length == 0 {
+        return CommonSubstring { value: &left[..0], length: 0, left_offset: 0, right_offset: 0 };
+    }
+
+    let left_offset = left.char_indices().nth(best_left + 1 - best_length).unwrap().0;
+    let left_end = left[left_offset..].char_indices()
+        .nth(best_length)
+        .map_or(left.len(), |(offset, _)| left_offset + offset);
+    CommonSubstring {
+        value: &left[left_offset..left_end],
+        length: best_length,
+        left_offset,
+        right_offset: right[best_right + 1 - best_length].0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn common_substring_works() {
+        let common = longest_common_substring("connection reset", "reset by peer");
+        assert_eq!(common.value, "reset");
+        assert_eq!(common.length, 5);
+        assert_eq!(common.left_offset, 11);
+        assert_eq!(common.right_offset, 0);
+    }
+
+    #[test]
+    fn common_substring_unicode() {
+        let common = longest_common_substring("żółw idzie", "mały żółw");
+        assert_eq!(common.value, "żółw");
+        assert_eq!(common.length, 4);
+        assert_eq!(&"mały żółw"[common.right_offset..], "żółw");
+        let common = longest_common_substring("ąę", "ę");
+        assert_eq!(common.value, "ę");
+        assert_eq!(common.left_offset, 2);
+    }
+
+    #[test]
+    fn common_substring_empty() {
+        let common = longest_common_substring("abc", "xyz");
+        assert_eq!(common.value, "");
+        assert_eq!(common.length, 0);
+        assert_eq!(longest_common_substring("", "").length, 0);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::search::get_close_matches;
+
+/// Quotes around the suggestions of [`DidYouMean`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// No quotes: did you mean night?
+    None,
+    /// Single quotes: did you mean 'night'?
+    Single,
+    /// Double quotes: did you mean "night"?
+    Double,
+    /// Backticks: did you mean `night`?
+    #[default]
+    Backtick
+}
+
+impl QuoteStyle {
+    /// Wrap the text in the quotes
+    fn quote(self, text: &str) -> String {
+        match self {
+            QuoteStyle::None => text.to_string(),
+            QuoteStyle::Single => format!("'{}'", text),
+            QuoteStyle::Double => format!("\"{}\"", text),
+            QuoteStyle::Backtick => format!("`{}`", text)
+        }
+    }
+}
+
+/// Builder of "Did you mean …?" messages for inputs that match none of the candidates
+///
+/// Candidates are rated with [`compare_similarity`](crate::compare_similarity). Only the ones rated at
+/// least the minimal score are suggested, the best ones first. By default up to 3 candidates
+/// rated at least 0.6 are suggested in backticks.
+/// # Example
+/// ```
+/// use similar_string::{DidYouMean, QuoteStyle};
+///
+/// let commands = vec!["build", "bench", "check", "clean"];
+///
+/// DidYouMean::new().message("biuld", &commands); // Some("Did you mean `build`?")
+/// DidYouMean::new().quote(QuoteStyle::Single).message("chek", &commands); // Some("Did you mean 'check'?")
+/// DidYouMean::new().message("cech", &commands); // Some("Did you mean `bench` or `check`?")
+/// DidYouMean::new().message("publish", &commands); // None
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DidYouMean {
+    min_score: f64,
+    max_suggestions: usize,
+    quote: QuoteStyle
+}
+
+impl Default for DidYouMean {
+    fn default() -> Self {
+        DidYouMean { min_score: 0.6, max_suggestions: 3, quote: QuoteStyle::Backtick }
+    }
+}
+
+impl DidYouMean {
+    /// Create the builder with the default options
+    pub fn new() -> Self {
+        DidYouMean::default()
+    }
+
+    /// Set the minimal rating of the suggested candidates
+    pub fn min_score(mut self, min_score: f64) -> Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Set the maximal number of suggested candidates
+    pub fn max_suggestions(mut self, max_suggestions: usize) -> Self {
+        self.max_suggestions = max_suggestions;
+        self
+    }
+
+    /// Set the quotes around the suggested candidates
+    pub fn quote(mut self, quote: QuoteStyle) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Get the candidates that would be suggested for the input, the best ones first
+    pub fn suggestions(&self, input: impl AsRef<str>, candidates: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+        get_close_matches(input, candidates, self.max_suggestions, self.min_score).into_iter()
+            .map(|(candidate, _)| candidate)
+            .collect()
+    }
+
+    /// Get the message that suggests the candidates, `None` if no candidate is similar enough
+    pub fn message(&self, input: impl AsRef<str>, candidates: impl IntoIterator<Item = impl AsRef<str>>) -> Option<String> {
+        let quoted: Vec<String> = self.suggestions(input, candidates).iter()
+            .map(|candidate| self.quote.quote(candidate))
+            .collect();
+        match quoted.as_slice() {
+            [] => None,
+            [single] => Some(format!("Did you mean {}?", single)),
+            [rest @ .., last] => Some(format!("Did you mean {} or {}?", rest.join(", "), last))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn did_you_mean_messages() {
+        let commands = vec!["build", "bench", "check", "clean"];
+        assert_eq!(DidYouMean::new().message("biuld", &commands), Some("Did you mean `build`?".to_string()));
+        assert_eq!(DidYouMean::new().message("publish", &commands), None);
+        assert_eq!(DidYouMean::new().message("cech", &commands), Some("Did you mean `bench` or `check`?".to_string()));
+        let message = DidYouMean::new().min_score(0.0).quote(QuoteStyle::Double).message("b", &commands);
+        assert_eq!(message, Some("Did you mean \"build\", \"bench\" or \"check\"?".to_string()));
+        let message = DidYouMean::new().max_suggestions(1).quote(QuoteStyle::None).message("cech", &commands);
+        assert_eq!(message, Some("Did you mean bench?".to_string()));
+        assert_eq!(DidYouMean::new().max_suggestions(0).message("build", &commands), None);
+    }
+}
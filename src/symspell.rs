@@ -0,0 +1,292 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Read, Write};
+
+use crate::damerau::osa_distance;
+use crate::persist::{invalid_data, Reader, Writer};
+
+/// Magic bytes of a saved [`SymSpell`] index
+const SYMSPELL_MAGIC: &[u8; 4] = b"SSSY";
+/// Largest maximal distance a loaded index may have
+const MAX_LOADED_DISTANCE: usize = 255;
+
+/// Default maximal edit distance of a [`SymSpell`] index
+pub const DEFAULT_SYMSPELL_DISTANCE: usize = 2;
+
+/// Word of a [`SymSpell`] index suggested as the correction of a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Suggestion<'a> {
+    /// The suggested word
+    pub term: &'a str,
+    /// Optimal string alignment distance of the word from the query
+    pub distance: usize,
+    /// Frequency of the word in the index
+    pub frequency: u64
+}
+
+/// Spelling correction index of precomputed deletions, after the SymSpell algorithm
+///
+/// Every word is stored under all the strings that remain after deleting up to `max_dist`
+/// of its characters. A query only generates its own deletions and looks them up, so the
+/// candidates are found without comparing the query against the whole dictionary.
+/// The candidates are verified with the [`osa_distance`](crate::osa_distance), which also
+/// counts swapped neighbouring characters as one edit, and the closest and then most frequent
+/// words are suggested first.
+/// # Example
+/// ```
+/// use similar_string::SymSpell;
+///
+/// let mut index = SymSpell::new(2);
+/// index.insert("the", 500);
+/// index.insert("then", 100);
+/// index.insert("they", 200);
+///
+/// index.correct("tehy").unwrap().term; // "they"
+/// // Both words are one edit away, "the" is more frequent
+/// index.correct("thy").unwrap().term; // "the"
+/// index.lookup("teh", 1).iter().map(|suggestion| suggestion.term).collect::<Vec<_>>(); // ["the"]
+/// ```
+pub struct SymSpell {
+    max_dist: usize,
+    words: Vec<(String, u64)>,
+    ids: HashMap<String, usize>,
+    deletes: HashMap<String, Vec<usize>>
+}
+
+/// Strings that remain after deleting up to `max_dist` characters of the word, including the word
+fn deletions(word: &str, max_dist: usize) -> HashSet<String> {
+    let mut result = HashSet::from([word.to_string()]);
+    let mut layer = vec![word.to_string()];
+    for _ in 0..max_dist {
+        let mut next = vec![];
+        for text in &layer {
+            for (index, letter) in text.char_indices() {
+                let mut deleted = text.clone();
+                deleted.replace_range(index..index + letter.len_utf8(), "");
+                if result.insert(deleted.clone()) {
+                    next.push(deleted);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        layer = next;
+    }
+    result
+}
+
+impl SymSpell {
+    /// Create an empty index that finds words within `max_dist` edits of a query
+    ///
+    /// Every word takes space for its deletions, whose number grows quickly with `max_dist`.
+    pub fn new(max_dist: usize) -> Self {
+        SymSpell { max_dist, words: vec![], ids: HashMap::new(), deletes: HashMap::new() }
+    }
+
+    /// Get the maximal edit distance of the index
+    pub fn max_dist(&self) -> usize {
+        self.max_dist
+    }
+
+    /// Get the number of words in the index
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Check whether the index has no words
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Add a word with the given frequency to the index
+    ///
+    /// Inserting a word that is already there adds up its frequencies.
+    pub fn insert(&mut self, word: impl AsRef<str>, frequency: u64) {
+        let word = word.as_ref();
+        if let Some(&id) = self.ids.get(word) {
+            self.words[id].1 += frequency;
+            return;
+        }
+        let id = self.words.len();
+        self.words.push((word.to_string(), frequency));
+        self.ids.insert(word.to_string(), id);
+        for deleted in deletions(word, self.max_dist) {
+            self.deletes.entry(deleted).or_default().push(id);
+        }
+    }
+
+    /// Get the frequency of the word, if it is in the index
+    pub fn frequency(&self, word: impl AsRef<str>) -> Option<u64> {
+        self.ids.get(word.as_ref()).map(|id| self.words[*id].1)
+    }
+
+    /// Get all the words within `max_dist` edits of the query
+    ///
+    /// The suggestions are sorted from the closest word, then from the most frequent one.
+    /// A distance above the maximal distance of the index is lowered to it.
+    pub fn lookup(&self, query: impl AsRef<str>, max_dist: usize) -> Vec<Suggestion<'_>> {
+        let query = query.as_ref();
+        let max_dist = max_dist.min(self.max_dist);
+        let mut seen = HashSet::new();
+        let mut result = vec![];
+        for deleted in deletions(query, max_dist) {
+            for &id in self.deletes.get(&deleted).into_iter().flatten() {
+                if !seen.insert(id) {
+                    continue;
+                }
+                let (term, frequency) = &self.words[id];
+                let distance = osa_distance(query, term);
+                if distance <= max_dist {
+                    result.push(Suggestion { term, distance, frequency: *frequency });
+                }
+            }
+        }
+        result.sort_by(|left, right| left.distance.cmp(&right.distance)
+            .then(right.frequency.cmp(&left.frequency))
+            .then(left.term.cmp(right.term)));
+        result
+    }
+
+    /// Save the index with all the precomputed deletions in a compact binary format
+    ///
+    /// Wrap files in a [`BufWriter`](std::io::BufWriter), the index is written in many small pieces.
+    /// # Example
+    /// ```
+    /// use similar_string::SymSpell;
+    ///
+    /// let mut index = SymSpell::new(2);
+    /// index.insert("they", 200);
+    /// let mut bytes = vec![];
+    /// index.save(&mut bytes).unwrap();
+    ///
+    /// let index = SymSpell::load(bytes.as_slice()).unwrap();
+    /// index.correct("tehy").unwrap().term; // "they"
+    /// ```
+    pub fn save(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = Writer::new(writer, SYMSPELL_MAGIC)?;
+        writer.usize(self.max_dist)?;
+        writer.usize(self.words.len())?;
+        for (word, frequency) in &self.words {
+            writer.str(word)?;
+            writer.u64(*frequency)?;
+        }
+        writer.usize(self.deletes.len())?;
+        for (deleted, ids) in &self.deletes {
+            writer.str(deleted)?;
+            writer.usize(ids.len())?;
+            for id in ids {
+                writer.usize(*id)?;
+            }
+        }
+        writer.finish()
+    }
+
+    /// Load an index saved with [`save`](SymSpell::save) without generating the deletions again
+    ///
+    /// Returns an error of the [`InvalidData`](std::io::ErrorKind::InvalidData) kind if the data
+    /// is not a saved index or its maximal distance is above 255. Wrap files in a [`BufReader`](std::io::BufReader).
+    pub fn load(reader: impl Read) -> io::Result<Self> {
+        let mut reader = Reader::new(reader, SYMSPELL_MAGIC)?;
+        let max_dist = reader.usize()?;
+        if max_dist > MAX_LOADED_DISTANCE {
+            return Err(invalid_data("maximal distance is too large"));
+        }
+        let mut index = SymSpell::new(max_dist);
+        for id in 0..reader.usize()? {
+            let word = reader.string()?;
+            index.words.push((word.clone(), reader.u64()?));
+            index.ids.insert(word, id);
+        }
+        for _ in 0..reader.usize()? {
+            let deleted = reader.string()?;
+            let mut ids = vec![];
+            for _ in 0..reader.usize()? {
+                ids.push(reader.id(index.words.len())?);
+            }
+            index.deletes.insert(deleted, ids);
+        }
+        Ok(index)
+    }
+
+    /// Get the best correction of the query within the maximal distance of the index
+    ///
+    /// A query that is in the index is its own correction.
+    pub fn correct(&self, query: impl AsRef<str>) -> Option<Suggestion<'_>> {
+        self.lookup(query, self.max_dist).into_iter().next()
+    }
+}
+
+impl Default for SymSpell {
+    fn default() -> Self {
+        SymSpell::new(DEFAULT_SYMSPELL_DISTANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn symspell_suggests_corrections() {
+        let mut index = SymSpell::default();
+        for (word, frequency) in [("the", 500), ("then", 100), ("they", 200), ("them", 200), ("żółw", 5)] {
+            index.insert(word, frequency);
+        }
+        let terms = |query, max_dist| index.lookup(query, max_dist).iter().map(|suggestion| suggestion.term).collect::<Vec<_>>();
+        assert_eq!(terms("teh", 1), vec!["the"]);
+        assert_eq!(terms("thex", 1), vec!["the", "them", "they", "then"]);
+        assert_eq!(terms("zółw", 5), vec!["żółw"]);
+        assert!(terms("xyz", 2).is_empty());
+        assert_eq!(index.correct("tehy"), Some(Suggestion { term: "they", distance: 1, frequency: 200 }));
+        assert_eq!(index.correct("thy").unwrap().term, "the");
+        assert_eq!(index.correct("then").unwrap().distance, 0);
+    }
+
+    #[test]
+    fn symspell_save_and_load() {
+        let mut index = SymSpell::new(1);
+        for (word, frequency) in [("the", 500), ("they", 200), ("żółw", 5)] {
+            index.insert(word, frequency);
+        }
+        let mut bytes = vec![];
+        index.save(&mut bytes).unwrap();
+        let loaded = SymSpell::load(bytes.as_slice()).unwrap();
+        assert_eq!((loaded.max_dist(), loaded.len(), loaded.frequency("they")), (1, 3, Some(200)));
+        for query in ["thy", "zółw", "xyz"] {
+            assert_eq!(loaded.lookup(query, 1), index.lookup(query, 1));
+        }
+        assert!(SymSpell::load(&bytes[..10]).is_err());
+        assert_eq!(SymSpell::load(&b"SSCO\x01"[..]).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+        bytes[5..13].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(SymSpell::load(bytes.as_slice()).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn symspell_large_distance() {
+        // Deleting stops once the words are used up
+        let mut index = SymSpell::new(usize::MAX);
+        index.insert("cat", 1);
+        assert_eq!(index.correct("dog").unwrap().term, "cat");
+    }
+
+    #[test]
+    fn symspell_agrees_with_linear_scan() {
+        let words = ["", "a", "ab", "ba", "abc", "acb", "book", "books", "cook", "kitten", "sitting"];
+        let mut index = SymSpell::new(3);
+        for word in words {
+            index.insert(word, 1);
+        }
+        index.insert("a", 2);
+        assert_eq!((index.len(), index.frequency("a"), index.frequency("x")), (words.len(), Some(3), None));
+        for query in ["", "ab", "bac", "bokos", "kiten"] {
+            for max_dist in 0..4 {
+                let mut found: Vec<&str> = index.lookup(query, max_dist).iter().map(|suggestion| suggestion.term).collect();
+                let mut expected: Vec<&str> = words.into_iter().filter(|word| osa_distance(query, word) <= max_dist).collect();
+                found.sort();
+                expected.sort();
+                assert_eq!(found, expected, "{} {}", query, max_dist);
+            }
+        }
+    }
+}
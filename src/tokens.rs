@@ -0,0 +1,129 @@
+use std::cmp::max;
+
+use crate::compare_similarity;
+use crate::lcs::{lcs_length_slices, lcs_length_slices_by};
+
+/// Split the string into words
+///
+/// With the `unicode` feature the words are found on Unicode word boundaries,
+/// otherwise every run of alphanumeric characters is a word. Punctuation and whitespace
+/// between the words are skipped in both cases.
+/// # Example
+/// ```
+/// use similar_string::split_words;
+///
+/// split_words("The quick, brown fox!"); // ["The", "quick", "brown", "fox"]
+/// ```
+pub fn split_words(text: &str) -> Vec<&str> {
+    #[cfg(feature = "unicode")]
+    {
+        use unicode_segmentation::UnicodeSegmentation;
+        text.unicode_words().collect()
+    }
+    #[cfg(not(feature = "unicode"))]
+    {
+        text.split(|letter: char| !letter.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect()
+    }
+}
+
+/// Rating of two token sequences, normalized by the length of the longer one
+pub(crate) fn tokens_rating<T: PartialEq>(left: &[T], right: &[T]) -> f64 {
+    let size = max(left.len(), right.len());
+    // Empty sequences should match
+    if size == 0 { 1.0 } else { lcs_length_slices(left, right) as f64 / size as f64 }
+}
+
+/// Get score of similarity of two strings based on the longest common subsequence of words
+///
+/// Words match only when they are equal, so letters that two different words happen
+/// to share don't raise the rating. The length of the longest common subsequence of words
+/// is divided by the word count of the longer string, see [`split_words`].
+/// # Example
+/// ```
+/// use similar_string::compare_similarity_words;
+///
+/// compare_similarity_words("the quick brown fox", "the brown dog"); // 0.5
+/// compare_similarity_words("The end.", "the end"); // 0.5
+/// ```
+pub fn compare_similarity_words(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    tokens_rating(&split_words(left.as_ref()), &split_words(right.as_ref()))
+}
+
+/// Get score of similarity of two multi-line texts based on the longest common subsequence of lines
+///
+/// The length of the longest common subsequence of lines is divided by the line count
+/// of the longer text. Only equal lines match, see [`compare_similarity_lines_with`] to also
+/// match lines that are just similar.
+/// # Example
+/// ```
+/// use similar_string::compare_similarity_lines;
+///
+/// let before = "name = \"app\"\nversion = \"1.0\"\nedition = \"2021\"";
+/// let after = "name = \"app\"\nversion = \"1.1\"\nedition = \"2021\"";
+/// compare_similarity_lines(before, after); // 0.6666666666666666
+/// ```
+pub fn compare_similarity_lines(left: impl AsRef<str>, right: impl AsRef<str>) -> f64 {
+    let left: Vec<&str> = left.as_ref().lines().collect();
+    let right: Vec<&str> = right.as_ref().lines().collect();
+    tokens_rating(&left, &right)
+}
+
+/// Get score of similarity of two multi-line texts, matching lines that are similar enough
+///
+/// Two lines match when their [`compare_similarity`] rating is at least `min_line_similarity`.
+/// The length of the longest common subsequence of matching lines is divided by
+/// the line count of the longer text.
+/// # Example
+/// ```
+/// use similar_string::compare_similarity_lines_with;
+///
+/// let before = "name = \"app\"\nversion = \"1.0\"\nedition = \"2021\"";
+/// let after = "name = \"app\"\nversion = \"1.1\"\nedition = \"2021\"";
+/// compare_similarity_lines_with(before, after, 0.9); // 1.0
+/// ```
+pub fn compare_similarity_lines_with(left: impl AsRef<str>, right: impl AsRef<str>, min_line_similarity: f64) -> f64 {
+    let left: Vec<&str> = left.as_ref().lines().collect();
+    let right: Vec<&str> = right.as_ref().lines().collect();
+    let size = max(left.len(), right.len());
+    // Empty texts should match
+    if size == 0 {
+        return 1.0;
+    }
+    let lcs_len = lcs_length_slices_by(&left, &right, |lline, rline| {
+        lline == rline || compare_similarity(lline, rline) >= min_line_similarity
+    });
+    lcs_len as f64 / size as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn words_are_split() {
+        assert_eq!(split_words("The quick, brown fox!"), vec!["The", "quick", "brown", "fox"]);
+        assert_eq!(split_words("  ... "), Vec::<&str>::new());
+        assert_eq!(split_words("zażółć gęślą"), vec!["zażółć", "gęślą"]);
+    }
+
+    #[test]
+    fn words_similarity_works() {
+        assert_eq!(compare_similarity_words("the quick brown fox", "the brown dog"), 0.5);
+        assert_eq!(compare_similarity_words("", "..."), 1.0);
+        assert_eq!(compare_similarity_words("cat", ""), 0.0);
+        // Shared letters of different words don't count
+        assert_eq!(compare_similarity_words("listen", "silent"), 0.0);
+    }
+
+    #[test]
+    fn lines_similarity_works() {
+        let before = "a = 1\nb = 2\nc = 3\n";
+        assert_eq!(compare_similarity_lines(before, "a = 1\nc = 3"), 2.0 / 3.0);
+        assert_eq!(compare_similarity_lines("", ""), 1.0);
+        assert_eq!(compare_similarity_lines(before, before), 1.0);
+        assert_eq!(compare_similarity_lines_with(before, "a = 1\nb = 5\nc = 3", 0.8), 1.0);
+        assert_eq!(compare_similarity_lines_with(before, "a = 1\nb = 5\nc = 3", 0.9), 2.0 / 3.0);
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+/// Node of the trie, holding the word that ends at it, if any
+#[derive(Default)]
+struct Node {
+    children: BTreeMap<char, usize>,
+    word: Option<String>
+}
+
+/// Trie of words that finds all the words within a Levenshtein distance of a query
+///
+/// Every row of the Levenshtein table is computed once per trie node and shared by all
+/// the words with that prefix. Branches whose row has no value within the distance are
+/// skipped, which makes this the classic data structure of spell checkers.
+/// # Example
+/// ```
+/// use similar_string::Trie;
+///
+/// let trie: Trie = ["book", "books", "cake", "boo", "cape", "cart"].into_iter().collect();
+///
+/// trie.find_within("bool", 1); // [("boo", 1), ("book", 1)]
+/// ```
+pub struct Trie {
+    nodes: Vec<Node>,
+    len: usize
+}
+
+impl Trie {
+    /// Create an empty trie
+    pub fn new() -> Self {
+        Trie { nodes: vec![Node::default()], len: 0 }
+    }
+
+    /// Get the number of words in the trie
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the trie has no words
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Add a word to the trie
+    ///
+    /// Returns `false` and leaves the trie unchanged if the word is already there.
+    pub fn insert(&mut self, word: impl AsRef<str>) -> bool {
+        let word = word.as_ref();
+        let mut current = 0;
+        for letter in word.chars() {
+            current = match self.nodes[current].children.get(&letter) {
+                Some(&child) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[current].children.insert(letter, child);
+                    child
+                }
+            };
+        }
+        let node = &mut self.nodes[current];
+        if node.word.is_some() {
+            return false;
+        }
+        node.word = Some(word.to_string());
+        self.len += 1;
+        true
+    }
+
+    /// Find the node at the end of the path of the word
+    fn node(&self, word: &str) -> Option<usize> {
+        let mut current = 0;
+        for letter in word.chars() {
+            current = *self.nodes[current].children.get(&letter)?;
+        }
+        Some(current)
+    }
+
+    /// Remove a word from the trie
+    ///
+    /// Returns `false` if the word is not in the trie. The nodes of the word are kept
+    /// for the words that may be inserted later.
+    pub fn remove(&mut self, word: impl AsRef<str>) -> bool {
+        let Some(node) = self.node(word.as_ref()) else {
+            return false;
+        };
+        if self.nodes[node].word.take().is_none() {
+            return false;
+        }
+        self.len -= 1;
+        true
+    }
+
+    /// Check whether the trie holds the word
+    pub fn contains(&self, word: impl AsRef<str>) -> bool {
+        self.node(word.as_ref()).is_some_and(|node| self.nodes[node].word.is_some())
+    }
+
+    /// Get all the words within `max_dist` edits of the query together with their distances
+    ///
+    /// The results are sorted from the closest word and words with equal distances
+    /// are sorted alphabetically by their characters.
+    pub fn find_within(&self, query: impl AsRef<str>, max_dist: usize) -> Vec<(&str, usize)> {
+        let query: Vec<char> = query.as_ref().chars().collect();
+        let first_row: Vec<usize> = (0..=query.len()).collect();
+        let mut found = vec![];
+        self.search(0, &query, &first_row, max_dist, &mut found);
+        // The search visits words alphabetically, so the stable sort keeps that order
+        found.sort_by_key(|(_, distance)| *distance);
+        found
+    }
+
+    /// Visit the node whose Levenshtein row against the query is `row`
+    fn search<'a>(&'a self, current: usize, query: &[char], row: &[usize], max_dist: usize, found: &mut Vec<(&'a str, usize)>) {
+        let node = &self.nodes[current];
+        if let Some(word) = &node.word {
+            if row[query.len()] <= max_dist {
+                found.push((word, row[query.len()]));
+            }
+        }
+        for (letter, child) in &node.children {
+            let mut next = Vec::with_capacity(row.len());
+            next.push(row[0] + 1);
+            for (col, qletter) in query.iter().enumerate() {
+                let cost = if qletter == letter { 0 } else { 1 };
+                next.push((row[col] + cost).min(row[col + 1] + 1).min(next[col] + 1));
+            }
+            // The distance can only grow further down the branch
+            if next.iter().min().is_some_and(|least| *least <= max_dist) {
+                self.search(*child, query, &next, max_dist, found);
+            }
+        }
+    }
+
+    /// Iterate over the words of the trie in alphabetical order of their characters
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        let mut pending = vec![0];
+        std::iter::from_fn(move || {
+            while let Some(current) = pending.pop() {
+                let node = &self.nodes[current];
+                pending.extend(node.children.values().rev());
+                if let Some(word) = &node.word {
+                    return Some(word.as_str());
+                }
+            }
+            None
+        })
+    }
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Trie::new()
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for Trie {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, words: I) {
+        for word in words {
+            self.insert(word);
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for Trie {
+    fn from_iter<I: IntoIterator<Item = S>>(words: I) -> Self {
+        let mut trie = Trie::new();
+        trie.extend(words);
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn trie_finds_close_words() {
+        let words = ["book", "books", "cake", "boo", "cape", "cart", "boon", "cook", "żółw"];
+        let trie: Trie = words.into_iter().collect();
+        assert_eq!(trie.find_within("bool", 1), vec![("boo", 1), ("book", 1), ("boon", 1)]);
+        assert_eq!(trie.find_within("zółw", 1), vec![("żółw", 1)]);
+        // The trie agrees with the BK-tree and with a linear scan
+        let tree: BkTree = words.into_iter().collect();
+        for query in ["bake", "cooks", "", "carts", "bo"] {
+            for max_dist in 0..4 {
+                let mut found: Vec<&str> = trie.find_within(query, max_dist).into_iter().map(|(word, _)| word).collect();
+                let mut expected: Vec<&str> = tree.find_within(query, max_dist as f64).into_iter().map(|(word, _)| word).collect();
+                found.sort();
+                expected.sort();
+                assert_eq!(found, expected, "{} {}", query, max_dist);
+            }
+        }
+    }
+
+    #[test]
+    fn trie_insert() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+        assert!(trie.find_within("ab", 2).is_empty());
+        assert!(trie.insert("ab"));
+        assert!(!trie.insert("ab"));
+        trie.extend(["b", "abc", ""]);
+        assert_eq!(trie.len(), 4);
+        assert!(trie.contains("abc") && trie.contains("") && !trie.contains("a"));
+        assert_eq!(trie.words().collect::<Vec<_>>(), vec!["", "ab", "abc", "b"]);
+        assert_eq!(trie.find_within("a", 1), vec![("", 1), ("ab", 1), ("b", 1)]);
+        assert!(trie.remove("ab"));
+        assert!(!trie.remove("ab"));
+        assert!(!trie.remove("a") && !trie.remove("xyz"));
+        assert_eq!(trie.len(), 3);
+        assert_eq!(trie.words().collect::<Vec<_>>(), vec!["", "abc", "b"]);
+        assert_eq!(trie.find_within("a", 1), vec![("", 1), ("b", 1)]);
+    }
+}
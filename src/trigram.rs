@@ -0,0 +1,278 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Read, Write};
+
+use crate::ngram::char_ngrams;
+use crate::persist::{invalid_data, Reader, Writer};
+use crate::search::{rated_options, Match};
+
+/// Magic bytes of a saved [`TrigramIndex`]
+const TRIGRAM_MAGIC: &[u8; 4] = b"SSTR";
+
+/// Inverted index from character trigrams to the options that contain them
+///
+/// The index finds the candidates that share enough trigrams with the query and only
+/// those are rated with [`compare_similarity`](crate::compare_similarity). In large lists
+/// of options most of them don't share a single trigram with the query and are never compared.
+/// Options shorter than three characters are indexed under the whole option.
+///
+/// Options can be inserted and removed later without rebuilding the index. Every option
+/// keeps its id, which is the number of options inserted before it, also after others are removed.
+/// # Example
+/// ```
+/// use similar_string::TrigramIndex;
+///
+/// let index = TrigramIndex::new(["apple", "application", "banana", "pineapple"]);
+///
+/// index.candidates("applet", 2); // [0, 1, 3]
+/// let best = index.find_best("applet", 2).unwrap();
+/// (best.index, best.value, best.score); // (0, "apple", 0.8333333333333334)
+/// ```
+pub struct TrigramIndex {
+    options: Vec<Option<String>>,
+    postings: HashMap<String, Vec<usize>>,
+    len: usize
+}
+
+/// Distinct trigrams of the text
+fn trigrams(text: &str) -> HashSet<&str> {
+    char_ngrams(text, 3).into_iter().collect()
+}
+
+impl TrigramIndex {
+    /// Build the index of the options
+    pub fn new(options: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        let mut index = TrigramIndex { options: vec![], postings: HashMap::new(), len: 0 };
+        for option in options {
+            index.insert(option);
+        }
+        index
+    }
+
+    /// Get the number of indexed options
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the index has no options
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the option with the given id, if it wasn't removed
+    pub fn get(&self, id: usize) -> Option<&str> {
+        self.options.get(id)?.as_deref()
+    }
+
+    /// Add an option to the index and get its id
+    pub fn insert(&mut self, option: impl AsRef<str>) -> usize {
+        let option = option.as_ref();
+        let id = self.options.len();
+        for trigram in trigrams(option) {
+            self.postings.entry(trigram.to_string()).or_default().push(id);
+        }
+        self.options.push(Some(option.to_string()));
+        self.len += 1;
+        id
+    }
+
+    /// Remove the option with the given id and get it back
+    ///
+    /// Returns `None` if there is no such option or it was already removed.
+    pub fn remove(&mut self, id: usize) -> Option<String> {
+        let option = self.options.get_mut(id)?.take()?;
+        for trigram in trigrams(&option) {
+            // Postings of a loaded index are not checked against the options
+            let Some(posting) = self.postings.get_mut(trigram) else { continue };
+            posting.retain(|other| *other != id);
+            if posting.is_empty() {
+                self.postings.remove(trigram);
+            }
+        }
+        self.len -= 1;
+        Some(option)
+    }
+
+    /// Save the index in a compact binary format
+    ///
+    /// Wrap files in a [`BufWriter`](std::io::BufWriter), the index is written in many small pieces.
+    /// # Example
+    /// ```
+    /// use similar_string::TrigramIndex;
+    ///
+    /// let mut bytes = vec![];
+    /// TrigramIndex::new(["apple", "banana"]).save(&mut bytes).unwrap();
+    ///
+    /// let index = TrigramIndex::load(bytes.as_slice()).unwrap();
+    /// index.find_best("applet", 2).unwrap().value; // "apple"
+    /// ```
+    pub fn save(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = Writer::new(writer, TRIGRAM_MAGIC)?;
+        writer.usize(self.options.len())?;
+        for option in &self.options {
+            match option {
+                Some(option) => {
+                    writer.u8(1)?;
+                    writer.str(option)?;
+                }
+                None => writer.u8(0)?
+            }
+        }
+        writer.usize(self.postings.len())?;
+        for (trigram, ids) in &self.postings {
+            writer.str(trigram)?;
+            writer.usize(ids.len())?;
+            for id in ids {
+                writer.usize(*id)?;
+            }
+        }
+        writer.finish()
+    }
+
+    /// Load an index saved with [`save`](TrigramIndex::save) without indexing the options again
+    ///
+    /// Returns an error of the [`InvalidData`](std::io::ErrorKind::InvalidData) kind if the data
+    /// is not a saved index. Wrap files in a [`BufReader`](std::io::BufReader).
+    pub fn load(reader: impl Read) -> io::Result<Self> {
+        let mut reader = Reader::new(reader, TRIGRAM_MAGIC)?;
+        let mut index = TrigramIndex { options: vec![], postings: HashMap::new(), len: 0 };
+        for _ in 0..reader.usize()? {
+            let option = if reader.u8()? == 0 { None } else { Some(reader.string()?) };
+            index.len += option.is_some() as usize;
+            index.options.push(option);
+        }
+        for _ in 0..reader.usize()? {
+            let trigram = reader.string()?;
+            let mut ids = vec![];
+            for _ in 0..reader.usize()? {
+                let id = reader.id(index.options.len())?;
+                if index.options[id].is_none() {
+                    return Err(invalid_data("removed option is indexed"));
+                }
+                ids.push(id);
+            }
+            index.postings.insert(trigram, ids);
+        }
+        Ok(index)
+    }
+
+    /// Option that is in the index
+    fn option(&self, id: usize) -> &str {
+        self.options[id].as_deref().expect("candidates are options of the index")
+    }
+
+    /// Get ids of the options that share at least `min_shared` distinct trigrams with the query
+    ///
+    /// The ids are sorted. With `min_shared` of zero all the options are candidates.
+    pub fn candidates(&self, query: impl AsRef<str>, min_shared: usize) -> Vec<usize> {
+        if min_shared == 0 {
+            return (0..self.options.len()).filter(|id| self.options[*id].is_some()).collect();
+        }
+        let mut shared: HashMap<usize, usize> = HashMap::new();
+        for trigram in trigrams(query.as_ref()) {
+            for &id in self.postings.get(trigram).into_iter().flatten() {
+                *shared.entry(id).or_insert(0) += 1;
+            }
+        }
+        let mut result: Vec<usize> = shared.into_iter()
+            .filter(|(_, count)| *count >= min_shared)
+            .map(|(id, _)| id)
+            .collect();
+        result.sort_unstable();
+        result
+    }
+
+    /// Rate the candidates that share at least `min_shared` trigrams with the query
+    ///
+    /// The matches are sorted from the best one and matches with equal ratings
+    /// keep the order of the options.
+    pub fn search(&self, query: impl AsRef<str>, min_shared: usize) -> Vec<Match<'_>> {
+        let query = query.as_ref();
+        let candidates = self.candidates(query, min_shared);
+        let mut result: Vec<Match<'_>> = rated_options(query, candidates.iter().map(|id| self.option(*id)))
+            .map(|(position, value, score)| Match { index: candidates[position], value, score })
+            .collect();
+        result.sort_by(|left, right| right.score.total_cmp(&left.score));
+        result
+    }
+
+    /// Find the best rated candidate that shares at least `min_shared` trigrams with the query
+    ///
+    /// The first option wins a tie and `None` is returned if there are no candidates.
+    pub fn find_best(&self, query: impl AsRef<str>, min_shared: usize) -> Option<Match<'_>> {
+        self.search(query, min_shared).into_iter().next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn trigram_candidates() {
+        let index = TrigramIndex::new(["apple", "application", "banana", "pineapple", "ap"]);
+        assert_eq!(index.len(), 5);
+        assert_eq!(index.candidates("applet", 2), vec![0, 1, 3]);
+        assert_eq!(index.candidates("applet", 3), vec![0, 3]);
+        assert!(index.candidates("applet", 4).is_empty());
+        assert_eq!(index.candidates("nan", 1), vec![2]);
+        assert_eq!(index.candidates("ap", 1), vec![4]);
+        assert_eq!(index.candidates("xyz", 0).len(), 5);
+        assert_eq!(index.get(2), Some("banana"));
+    }
+
+    #[test]
+    fn trigram_search_rescores() {
+        let options = ["apple", "application", "banana", "pineapple"];
+        let index = TrigramIndex::new(options);
+        let found: Vec<(usize, f64)> = index.search("appel", 1).iter().map(|found| (found.index, found.score)).collect();
+        let expected: Vec<(usize, f64)> = rank_options("appel", options).into_iter().filter(|(id, _)| *id != 2).collect();
+        assert_eq!(found, expected);
+        assert_eq!(index.find_best("applet", 2).unwrap(), Match { index: 0, value: "apple", score: 5.0 / 6.0 });
+        assert_eq!(index.find_best("xyz", 1), None);
+        assert!(TrigramIndex::new(Vec::<String>::new()).is_empty());
+    }
+
+    #[test]
+    fn trigram_save_and_load() {
+        let mut index = TrigramIndex::new(["apple", "application", "banana", "pineapple"]);
+        index.remove(1);
+        let mut bytes = vec![];
+        index.save(&mut bytes).unwrap();
+        let loaded = TrigramIndex::load(bytes.as_slice()).unwrap();
+        assert_eq!((loaded.len(), loaded.get(1), loaded.get(3)), (3, None, Some("pineapple")));
+        for query in ["applet", "nan", "xyz"] {
+            assert_eq!(loaded.search(query, 1), index.search(query, 1));
+            assert_eq!(loaded.candidates(query, 0), index.candidates(query, 0));
+        }
+        assert!(TrigramIndex::load(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn trigram_remove_without_postings() {
+        let mut bytes = vec![];
+        let mut writer = crate::persist::Writer::new(&mut bytes, super::TRIGRAM_MAGIC).unwrap();
+        writer.usize(1).unwrap();
+        writer.u8(1).unwrap();
+        writer.str("apple").unwrap();
+        writer.usize(0).unwrap();
+        writer.finish().unwrap();
+        let mut loaded = TrigramIndex::load(bytes.as_slice()).unwrap();
+        assert_eq!(loaded.remove(0), Some("apple".to_string()));
+        assert!(loaded.is_empty());
+    }
+
+    #[test]
+    fn trigram_insert_and_remove() {
+        let mut index = TrigramIndex::new(["apple", "application", "banana"]);
+        assert_eq!(index.insert("pineapple"), 3);
+        assert_eq!(index.remove(0), Some("apple".to_string()));
+        assert_eq!(index.remove(0), None);
+        assert_eq!(index.remove(7), None);
+        assert_eq!((index.len(), index.get(0), index.get(3)), (3, None, Some("pineapple")));
+        assert_eq!(index.candidates("applet", 2), vec![1, 3]);
+        assert_eq!(index.candidates("applet", 0), vec![1, 2, 3]);
+        assert_eq!(index.find_best("applet", 2).unwrap().value, "pineapple");
+        index.remove(2);
+        assert!(index.candidates("nan", 1).is_empty());
+    }
+}
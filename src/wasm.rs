@@ -0,0 +1,129 @@
+//! JavaScript bindings generated with wasm-bindgen
+//!
+//! The functions and classes keep the ratings of the Rust API, so the scores computed in the browser
+//! are identical to the ones computed on the server. Names follow the JavaScript conventions.
+//!
+//! This module is available with the `wasm` feature. The bindings are built as a dynamic library
+//! for the `wasm32-unknown-unknown` target and then processed with the `wasm-bindgen` tool:
+//! ```sh
+//! cargo rustc --lib --release --features wasm --target wasm32-unknown-unknown --crate-type cdylib
+//! wasm-bindgen --target web target/wasm32-unknown-unknown/release/similar_string.wasm --out-dir pkg
+//! ```
+//! # Example
+//! ```js
+//! import { compareSimilarity, findBestSimilarity, Corpus } from "similar-string";
+//!
+//! compareSimilarity("age", "page"); // 0.75
+//! findBestSimilarity("fight", ["fill", "night", "ride"]).value; // "night"
+//!
+//! const corpus = new Corpus(["fill", "night", "ride"]);
+//! corpus.bestMatch("fight").score; // 0.8
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::search::Match;
+
+/// Option that matched the target string, see [`Match`]
+#[wasm_bindgen(js_name = Match, getter_with_clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmMatch {
+    /// Position of the option amongst the searched options
+    #[wasm_bindgen(readonly)]
+    pub index: usize,
+    /// The option itself
+    #[wasm_bindgen(readonly)]
+    pub value: String,
+    /// Similarity rating of the option
+    #[wasm_bindgen(readonly)]
+    pub score: f64
+}
+
+impl From<Match<'_>> for WasmMatch {
+    fn from(found: Match<'_>) -> Self {
+        WasmMatch { index: found.index, value: found.value.to_string(), score: found.score }
+    }
+}
+
+/// Get score of similarity of two strings, see [`compare_similarity`](crate::compare_similarity)
+#[wasm_bindgen(js_name = compareSimilarity)]
+pub fn compare_similarity(left: &str, right: &str) -> f64 {
+    crate::compare_similarity(left, right)
+}
+
+/// Find the option that is the most similar to the target string, see [`find_best_match`](crate::find_best_match)
+///
+/// Returns `undefined` if there are no options.
+#[wasm_bindgen(js_name = findBestSimilarity)]
+pub fn find_best_similarity(target: &str, options: Vec<String>) -> Option<WasmMatch> {
+    crate::find_best_match(target, &options).map(WasmMatch::from)
+}
+
+/// Get all similarity scores against the target string, see [`get_similarity_ratings`](crate::get_similarity_ratings)
+///
+/// Returns an empty array if there are no options.
+#[wasm_bindgen(js_name = getSimilarityRatings)]
+pub fn get_similarity_ratings(target: &str, options: Vec<String>) -> Vec<f64> {
+    crate::get_similarity_ratings(target, &options).unwrap_or_default()
+}
+
+/// Options prepared to be compared against many queries, see [`Corpus`](crate::Corpus)
+#[wasm_bindgen(js_name = Corpus)]
+pub struct WasmCorpus(crate::Corpus);
+
+#[wasm_bindgen(js_class = Corpus)]
+impl WasmCorpus {
+    /// Prepare the options
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: Vec<String>) -> WasmCorpus {
+        WasmCorpus(crate::Corpus::new(options))
+    }
+
+    /// Number of options
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Add an option and get its id
+    pub fn insert(&mut self, option: &str) -> usize {
+        self.0.insert(option)
+    }
+
+    /// Remove the option with the id and get it back, `undefined` if there is no such option
+    pub fn remove(&mut self, id: usize) -> Option<String> {
+        self.0.remove(id)
+    }
+
+    /// Find the option that is the most similar to the query
+    #[wasm_bindgen(js_name = bestMatch)]
+    pub fn best_match(&self, query: &str) -> Option<WasmMatch> {
+        self.0.best_match(query).map(WasmMatch::from)
+    }
+
+    /// Find all the options rated at least `min_score`, in the order of the options
+    #[wasm_bindgen(js_name = matchesAbove)]
+    pub fn matches_above(&self, query: &str, min_score: f64) -> Vec<WasmMatch> {
+        self.0.matches_above(query, min_score).into_iter().map(WasmMatch::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wasm::*;
+
+    #[test]
+    fn wasm_bindings() {
+        assert_eq!(compare_similarity("age", "page"), 0.75);
+        let options = vec!["fill".to_string(), "night".to_string(), "ride".to_string()];
+        let best = find_best_similarity("fight", options.clone()).unwrap();
+        assert_eq!(best, WasmMatch { index: 1, value: "night".to_string(), score: 0.8 });
+        assert_eq!(get_similarity_ratings("fight", options.clone()), vec![0.4, 0.8, 0.2]);
+        assert!(get_similarity_ratings("fight", vec![]).is_empty());
+        let mut corpus = WasmCorpus::new(options);
+        assert_eq!(corpus.insert("light"), 3);
+        assert_eq!(corpus.best_match("fight"), Some(best));
+        assert_eq!(corpus.matches_above("fight", 0.5).len(), 2);
+        assert_eq!((corpus.remove(1), corpus.length()), (Some("night".to_string()), 3));
+    }
+}
@@ -0,0 +1,163 @@
+use std::cmp::max;
+
+/// Costs of the edits of [`weighted_levenshtein`]
+///
+/// Every method has a default cost of 1.0, so implementing only some of them
+/// changes just those edits. The substitution cost is only asked for different characters.
+/// # Example
+/// ```
+/// use similar_string::{EditCosts, weighted_levenshtein};
+///
+/// // Swapping a vowel for another vowel is half an edit
+/// struct Vowels;
+/// impl EditCosts for Vowels {
+///     fn substitution(&self, from: char, to: char) -> f64 {
+///         if "aeiou".contains(from) && "aeiou".contains(to) { 0.5 } else { 1.0 }
+///     }
+/// }
+///
+/// weighted_levenshtein("color", "culler", &Vowels); // 2.0
+/// ```
+pub trait EditCosts {
+    /// Cost of inserting the character into the left string
+    fn insertion(&self, _letter: char) -> f64 {
+        1.0
+    }
+
+    /// Cost of deleting the character from the left string
+    fn deletion(&self, _letter: char) -> f64 {
+        1.0
+    }
+
+    /// Cost of replacing a character of the left string with a different one
+    fn substitution(&self, _from: char, _to: char) -> f64 {
+        1.0
+    }
+
+    /// Length of the longest sequence that [`EditCosts::sequence_substitution`] can replace
+    fn max_sequence_len(&self) -> usize {
+        1
+    }
+
+    /// Cost of replacing a sequence of the left string with a sequence of the right one at once
+    ///
+    /// This allows edits such as reading "rn" as "m". It is only asked when one of the sequences
+    /// is longer than a single character, `None` means that they can't be replaced at once.
+    fn sequence_substitution(&self, _from: &[char], _to: &[char]) -> Option<f64> {
+        None
+    }
+}
+
+/// Get the Levenshtein edit distance of two strings with custom edit costs
+///
+/// The distance is the minimal total cost of insertions, deletions and substitutions
+/// of characters or sequences that turn `left` into `right`. With the default costs
+/// it is equal to [`levenshtein_distance`](crate::levenshtein_distance).
+/// The table is computed row by row in O(n * m) time, sequence substitutions
+/// of up to `k` characters multiply it by k².
+pub fn weighted_levenshtein(left: impl AsRef<str>, right: impl AsRef<str>, costs: &(impl EditCosts + ?Sized)) -> f64 {
+    let left: Vec<char> = left.as_ref().chars().collect();
+    let right: Vec<char> = right.as_ref().chars().collect();
+    let longest = costs.max_sequence_len();
+    if longest <= 1 {
+        return weighted_rows(&left, &right, costs);
+    }
+    // Sequence substitutions reach back several rows, so the whole table is kept
+    let mut table = vec![vec![0.0; right.len() + 1]; left.len() + 1];
+    for (col, rletter) in right.iter().enumerate() {
+        table[0][col + 1] = table[0][col] + costs.insertion(*rletter);
+    }
+    for (row, lletter) in left.iter().enumerate() {
+        let row = row + 1;
+        table[row][0] = table[row - 1][0] + costs.deletion(*lletter);
+        for (col, rletter) in right.iter().enumerate() {
+            let col = col + 1;
+            let substitution = if lletter == rletter { 0.0 } else { costs.substitution(*lletter, *rletter) };
+            let mut best = (table[row - 1][col - 1] + substitution)
+                .min(table[row - 1][col] + costs.deletion(*lletter))
+                .min(table[row][col - 1] + costs.insertion(*rletter));
+            for from in 1..=longest.min(row) {
+                for to in 1..=longest.min(col) {
+                    if from == 1 && to == 1 {
+                        continue;
+                    }
+                    if let Some(cost) = costs.sequence_substitution(&left[row - from..row], &right[col - to..col]) {
+                        best = best.min(table[row - from][col - to] + cost);
+                    }
+                }
+            }
+            table[row][col] = best;
+        }
+    }
+    table[left.len()][right.len()]
+}
+
+/// Weighted distance of single character edits, computed with two rows of the table
+fn weighted_rows(left: &[char], right: &[char], costs: &(impl EditCosts + ?Sized)) -> f64 {
+    let mut previous: Vec<f64> = Vec::with_capacity(right.len() + 1);
+    previous.push(0.0);
+    for (col, rletter) in right.iter().enumerate() {
+        previous.push(previous[col] + costs.insertion(*rletter));
+    }
+    let mut current = vec![0.0; right.len() + 1];
+
+    for lletter in left {
+        current[0] = previous[0] + costs.deletion(*lletter);
+        for (col, rletter) in right.iter().enumerate() {
+            let substitution = if lletter == rletter { 0.0 } else { costs.substitution(*lletter, *rletter) };
+            current[col + 1] = (previous[col] + substitution)
+                .min(previous[col + 1] + costs.deletion(*lletter))
+                .min(current[col] + costs.insertion(*rletter));
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[right.len()]
+}
+
+/// Get score of similarity of two strings based on the weighted Levenshtein distance
+///
+/// The distance is normalized by the character count of the longer string and
+/// the rating is clamped to the range from 0.0 to 1.0, so edits that cost more than 1.0
+/// can bring it down to zero faster.
+pub fn normalized_weighted_levenshtein(left: impl AsRef<str>, right: impl AsRef<str>, costs: &(impl EditCosts + ?Sized)) -> f64 {
+    let (left, right) = (left.as_ref(), right.as_ref());
+    let size = max(left.chars().count(), right.chars().count());
+    // Empty strings should match
+    if size == 0 {
+        return 1.0;
+    }
+    (1.0 - weighted_levenshtein(left, right, costs) / size as f64).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    struct Unit;
+    impl EditCosts for Unit {}
+
+    #[test]
+    fn default_costs_match_levenshtein() {
+        for (left, right) in [("kitten", "sitting"), ("", "abc"), ("flaw", "lawn"), ("żółw", "zolw")] {
+            assert_eq!(weighted_levenshtein(left, right, &Unit), levenshtein_distance(left, right) as f64);
+            assert_eq!(normalized_weighted_levenshtein(left, right, &Unit), normalized_levenshtein(left, right));
+        }
+    }
+
+    #[test]
+    fn custom_costs() {
+        struct Cheap;
+        impl EditCosts for Cheap {
+            fn insertion(&self, _: char) -> f64 {
+                0.25
+            }
+            fn substitution(&self, _: char, _: char) -> f64 {
+                3.0
+            }
+        }
+        assert_eq!(weighted_levenshtein("ab", "abcd", &Cheap), 0.5);
+        // Deleting and inserting is cheaper than substituting
+        assert_eq!(weighted_levenshtein("a", "b", &Cheap), 1.25);
+        assert_eq!(normalized_weighted_levenshtein("", "", &Cheap), 1.0);
+    }
+}